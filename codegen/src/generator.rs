@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
+
 use inflector::Inflector;
+use serde_json;
 
 use json_schema::*;
 
@@ -14,6 +17,8 @@ impl Module {
         format!(
             "{docs}
 
+            #[allow(unused_imports)]
+            use std::borrow::Cow;
             #[allow(unused_imports)]
             use std::collections::HashMap;
             use std::convert::From;
@@ -24,8 +29,15 @@ impl Module {
 
             use ::requests::SlackWebRequestSender;
 
+            {enums}
+
             {methods}",
             docs = self.description.as_ref().map(|d| format_docs("//!", d)).unwrap_or_default(),
+            enums = self.get_param_enums()
+                .iter()
+                .map(|e| e.to_code())
+                .collect::<Vec<String>>()
+                .join("\n"),
             methods = self.methods
                 .iter()
                 .map(Method::generate)
@@ -34,8 +46,588 @@ impl Module {
         )
     }
 
+    /// The snake_case name of the module, e.g. `chat_scheduled_messages` for
+    /// `chat.scheduledMessages`.
     pub fn get_safe_name(&self) -> String {
-        self.name.replace('.', "_")
+        // not `to_snake_case`, which would also split `oauth.v2` into `oauth_v_2`
+        let mut name = String::with_capacity(self.name.len());
+        for c in self.name.chars() {
+            if c == '.' {
+                name.push('_');
+            } else if c.is_uppercase() {
+                name.push('_');
+                name.extend(c.to_lowercase());
+            } else {
+                name.push(c);
+            }
+        }
+        name
+    }
+
+    /// Adds the optional params and response fields that the schemas do not describe yet, and the
+    /// `response_metadata` that every cursor-paginated method responds with.
+    pub fn add_missing_params(&mut self) {
+        for method in &mut self.methods {
+            for &(m, name, ty, description) in MISSING_PARAMS {
+                if m == method.name && !method.params.iter().any(|p| p.name == name) {
+                    method.params.push(Param::optional(name, ty, description));
+                }
+            }
+
+            for &(m, name, ty, description) in PARAM_ITEMS {
+                if m != method.name {
+                    continue;
+                }
+                match method.params.iter().position(|p| p.name == name) {
+                    Some(i) => {
+                        method.params[i].ty = ty.into();
+                        method.params[i].description = description.into();
+                    }
+                    None => {
+                        // kept next to the other structured content of the message
+                        let i = method.params.iter()
+                            .position(|p| p.name == "attachments")
+                            .map_or(method.params.len(), |i| i + 1);
+                        method.params.insert(i, Param::optional(name, ty, description));
+                    }
+                }
+            }
+
+            if method.is_cursor_paginated() {
+                let properties = method.response.schema.properties.get_or_insert_with(Default::default);
+                if !properties.contains_key("response_metadata") {
+                    let response_metadata = serde_json::from_str(r#"{"$ref": "../objects/response_metadata.json"}"#)
+                        .expect("response_metadata reference is valid JSON schema");
+                    properties.insert("response_metadata".into(), response_metadata);
+                }
+            }
+
+            for &(m, name, schema) in REPLACED_RESPONSE_FIELDS {
+                if m == method.name {
+                    let field = serde_json::from_str(schema).expect("replaced response field is valid JSON schema");
+                    method.response.schema.properties.get_or_insert_with(Default::default).insert(name.into(), field);
+                }
+            }
+
+            for &(m, name, schema) in MISSING_RESPONSE_FIELDS {
+                if m == method.name {
+                    let response = &mut method.response.schema;
+                    if !response.properties.as_ref().map_or(false, |p| p.contains_key(name)) {
+                        let field = serde_json::from_str(schema).expect("missing response field is valid JSON schema");
+                        response.properties.get_or_insert_with(Default::default).insert(name.into(), field);
+                    }
+                    // `ok` is always sent, and being required makes it a plain `bool`
+                    if name == "ok" {
+                        response.required.get_or_insert_with(Default::default).push(name.into());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Looks up which params of this module only accept a fixed set of values or take a list.
+    pub fn resolve_param_types(&mut self) {
+        let module_name = self.get_safe_name();
+        for method in &mut self.methods {
+            let method_name = &method.name;
+            for param in &mut method.params {
+                param.enum_ty = PARAM_ENUMS.iter()
+                    .find(|&&(m, p, _)| m == method_name && p == param.name)
+                    .map(|&(_, _, e)| e);
+                if let Some(description) = param.enum_ty.and_then(|e| e.list_description) {
+                    param.description = description.into();
+                }
+                param.enum_path = match param.enum_ty {
+                    Some(e) if e.module == module_name => e.name.into(),
+                    Some(e) => format!("::{}::{}", e.module, e.name),
+                    None => String::new(),
+                };
+                param.list = PARAM_LISTS.iter()
+                    .find(|&&(m, p, _)| m == method_name && p == param.name)
+                    .map(|&(_, _, ref l)| l);
+                if let Some(l) = param.list {
+                    param.description = l.description.into();
+                }
+                param.json_ty = PARAM_JSON.iter()
+                    .find(|&&(m, p, _)| m == method_name && p == param.name)
+                    .map(|&(_, _, ref j)| j);
+            }
+
+            // Lists that are too long are rejected before sending, with the error Slack would return.
+            let max_errors = method.params.iter()
+                .filter_map(|p| p.list.and_then(|l| l.max).map(|max| (&p.name, max)))
+                .map(|(name, (max, error))| ApiError {
+                    name: error.into(),
+                    description: format!("Needs at most {} {}", max, name),
+                })
+                .collect::<Vec<_>>();
+            for error in max_errors {
+                if !method.response.errors.iter().any(|e| e.name == error.name) {
+                    method.response.errors.push(error);
+                }
+            }
+        }
+    }
+
+    /// The enums generated in this module for its params, each listed once.
+    fn get_param_enums(&self) -> Vec<&'static ParamEnum> {
+        let mut enums: Vec<&'static ParamEnum> = vec![];
+        for param in self.methods.iter().flat_map(|m| m.params.iter()) {
+            if let Some(e) = param.enum_ty {
+                if e.module == self.get_safe_name() && !enums.iter().any(|known| known.name == e.name) {
+                    enums.push(e);
+                }
+            }
+        }
+        enums
+    }
+}
+
+/// Adds the objects, variants and fields that the object schemas do not describe yet. Objects are
+/// keyed by the name of their schema file.
+pub fn add_missing_objects(objects: &mut BTreeMap<String, JsonSchema>) {
+    for &(name, schema) in MISSING_OBJECTS {
+        if !objects.contains_key(name) {
+            let object = serde_json::from_str(schema).expect("missing object is valid JSON schema");
+            objects.insert(name.into(), object);
+        }
+    }
+
+    for &(object, schema) in MISSING_OBJECT_VARIANTS {
+        let variant = serde_json::from_str(schema).expect("missing object variant is valid JSON schema");
+        objects.get_mut(object)
+            .and_then(|o| o.one_of.as_mut())
+            .expect(&format!("{} is not an object with variants", object))
+            .push(variant);
+    }
+
+    for &(path, name, schema) in MISSING_OBJECT_FIELDS {
+        let mut path = path.split('.');
+        let object = path.next().unwrap();
+        let object = objects.get_mut(object).expect(&format!("{} is not an object", object));
+        let object = path.fold(object, nested_schema);
+        let properties = object.properties.get_or_insert_with(Default::default);
+        if !properties.contains_key(name) {
+            let field = serde_json::from_str(schema).expect("missing object field is valid JSON schema");
+            properties.insert(name.into(), field);
+        }
+    }
+}
+
+/// The schema of the field or variant of `schema` with the given name.
+fn nested_schema<'a>(schema: &'a mut JsonSchema, name: &str) -> &'a mut JsonSchema {
+    if schema.properties.as_ref().map_or(false, |p| p.contains_key(name)) {
+        return schema.properties.as_mut().and_then(|p| p.get_mut(name)).unwrap();
+    }
+    // variants are named the same way `PropType::from_schema` names them
+    schema.one_of.as_mut()
+        .and_then(|one_of| one_of.iter_mut().find(|o| {
+            o.title.as_ref().or_else(|| o.id.as_ref()).map(|t| t.to_pascal_case()) == Some(name.to_pascal_case())
+        }))
+        .expect(&format!("{} is not a field or variant", name))
+}
+
+/// Optional params missing from the schemas, by method: `(method, name, type, description)`.
+static MISSING_PARAMS: &'static [(&'static str, &'static str, &'static str, &'static str)] = &[
+    ("channels.list", "team_id", "string", "Encoded team ID to list channels in. Required when using an org-level token on Enterprise Grid."),
+    ("search.all", "team_id", "string", "Encoded team ID to search in. Required when using an org-level token on Enterprise Grid."),
+    ("search.files", "team_id", "string", "Encoded team ID to search in. Required when using an org-level token on Enterprise Grid."),
+    ("search.messages", "team_id", "string", "Encoded team ID to search in. Required when using an org-level token on Enterprise Grid."),
+    ("usergroups.list", "team_id", "string", "Encoded team ID to list User Groups in. Required when using an org-level token on Enterprise Grid."),
+    ("users.list", "team_id", "string", "Encoded team ID to list users in. Required when using an org-level token on Enterprise Grid."),
+    ("channels.list", "cursor", "string", "Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first \"page\" of the collection."),
+    ("channels.list", "limit", "integer", "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached."),
+    ("files.info", "cursor", "string", "Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first \"page\" of the collection."),
+    ("files.info", "limit", "integer", "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached."),
+    ("groups.list", "cursor", "string", "Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first \"page\" of the collection."),
+    ("groups.list", "limit", "integer", "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached."),
+    ("im.list", "cursor", "string", "Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first \"page\" of the collection."),
+    ("im.list", "limit", "integer", "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached."),
+    ("mpim.list", "cursor", "string", "Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first \"page\" of the collection."),
+    ("mpim.list", "limit", "integer", "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached."),
+    ("reactions.list", "cursor", "string", "Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first \"page\" of the collection."),
+    ("reactions.list", "limit", "integer", "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached."),
+    ("stars.list", "cursor", "string", "Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first \"page\" of the collection."),
+    ("stars.list", "limit", "integer", "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached."),
+    ("users.list", "cursor", "string", "Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first \"page\" of the collection."),
+    ("users.list", "limit", "integer", "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached."),
+];
+
+/// Params that take an array of typed items, sent to Slack encoded as JSON, by method: `(method,
+/// name, type, description)`. `type` is `blocks` or `attachments`. Params the schemas lack are
+/// added after `attachments`.
+static PARAM_ITEMS: &'static [(&'static str, &'static str, &'static str, &'static str)] = &[
+    ("chat.postEphemeral", "attachments", "attachments", "Structured message attachments."),
+    ("chat.postEphemeral", "blocks", "blocks", "Structured Block Kit layout blocks."),
+    ("chat.postMessage", "attachments", "attachments", "Structured message attachments."),
+    ("chat.postMessage", "blocks", "blocks", "Structured Block Kit layout blocks."),
+    ("chat.scheduleMessage", "attachments", "attachments", "Structured message attachments."),
+    ("chat.scheduleMessage", "blocks", "blocks", "Structured Block Kit layout blocks."),
+    ("chat.update", "attachments", "attachments", "Structured message attachments."),
+    ("chat.update", "blocks", "blocks", "Structured Block Kit layout blocks."),
+];
+
+/// Response fields missing from the schemas, by method: `(method, name, JSON schema)`.
+static MISSING_RESPONSE_FIELDS: &'static [(&'static str, &'static str, &'static str)] = &[
+    ("oauth.access", "ok", r#"{"type": "boolean"}"#),
+    ("oauth.access", "error", r#"{"type": "string"}"#),
+    ("oauth.access", "team_id", r#"{"type": "string"}"#),
+    ("oauth.access", "team_name", r#"{"type": "string"}"#),
+    ("oauth.access", "user_id", r#"{"type": "string"}"#),
+    ("oauth.access", "enterprise_id", r#"{"type": "string"}"#),
+    ("oauth.access", "bot", r#"{"type": "object", "properties": {"bot_user_id": {"type": "string"}, "bot_access_token": {"type": "string"}}}"#),
+    ("oauth.access", "incoming_webhook", r#"{"type": "object", "properties": {"url": {"type": "string"}, "channel": {"type": "string"}, "channel_id": {"type": "string"}, "configuration_url": {"type": "string"}}}"#),
+];
+
+/// Objects missing from the schemas: `(name, JSON schema)`, named like their schema file would be.
+static MISSING_OBJECTS: &'static [(&'static str, &'static str)] = &[
+    // anything that can be pinned, starred or reacted to
+    ("item", r#"{"oneOf": [
+        {"title": "message", "type": "object", "required": ["type", "channel", "message"], "properties": {"type": {"type": "string"}, "channel": {"type": "string"}, "message": {"$ref": "message.json"}, "created": {"type": "number"}, "created_by": {"type": "string"}}},
+        {"title": "file", "type": "object", "required": ["type", "file"], "properties": {"type": {"type": "string"}, "file": {"$ref": "file.json"}, "created": {"type": "number"}, "created_by": {"type": "string"}}},
+        {"title": "file_comment", "type": "object", "required": ["type", "file", "comment"], "properties": {"type": {"type": "string"}, "file": {"$ref": "file.json"}, "comment": {"$ref": "file_comment.json"}, "created": {"type": "number"}, "created_by": {"type": "string"}}},
+        {"title": "channel", "type": "object", "required": ["type", "channel"], "properties": {"type": {"type": "string"}, "channel": {"type": "string"}, "created": {"type": "number"}, "created_by": {"type": "string"}}},
+        {"title": "im", "type": "object", "required": ["type", "channel"], "properties": {"type": {"type": "string"}, "channel": {"type": "string"}, "created": {"type": "number"}, "created_by": {"type": "string"}}},
+        {"title": "group", "type": "object", "required": ["type", "group"], "properties": {"type": {"type": "string"}, "group": {"type": "string"}, "created": {"type": "number"}, "created_by": {"type": "string"}}}
+    ]}"#),
+];
+
+/// Variants missing from the object schemas, by object: `(object, JSON schema)`. The title of each
+/// schema names its variant.
+static MISSING_OBJECT_VARIANTS: &'static [(&'static str, &'static str)] = &[
+    ("message", r#"{"title": "bot_add", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "bot_id": {"type": "string"}, "bot_link": {"type": "string"}, "channel": {"type": "string"}, "text": {"type": "string"}}}"#),
+    ("message", r#"{"title": "bot_remove", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "bot_id": {"type": "string"}, "bot_link": {"type": "string"}, "channel": {"type": "string"}, "text": {"type": "string"}}}"#),
+    ("message", r#"{"title": "channel_convert_to_private", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "text": {"type": "string"}}}"#),
+    ("message", r#"{"title": "channel_posting_permissions", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "text": {"type": "string"}}}"#),
+    ("message", r#"{"title": "ekm_access_denied", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "text": {"type": "string"}}}"#),
+    ("message", r#"{"title": "file_upload", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "text": {"type": "string"}, "files": {"type": "array", "items": {"$ref": "file.json"}}, "upload": {"type": "boolean"}}}"#),
+    ("message", r#"{"title": "reminder_add", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "text": {"type": "string"}}}"#),
+    ("message", r#"{"title": "thread_broadcast", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "channel": {"type": "string"}, "event_ts": {"type": "string"}, "text": {"type": "string"}, "thread_ts": {"type": "string"},
+        "root": {"type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "bot_id": {"type": "string"}, "text": {"type": "string"}, "thread_ts": {"type": "string"}, "latest_reply": {"type": "string"}, "reply_count": {"type": "integer"}, "reply_users": {"type": "array", "items": {"type": "string"}}}},
+        "attachments": {"type": "array", "items": {"type": "object", "properties": {"id": {"type": "integer"}, "fallback": {"type": "string"}, "text": {"type": "string"}, "ts": {"type": "string"}, "author_icon": {"type": "string"}, "author_link": {"type": "string"}, "author_subname": {"type": "string"}, "channel_id": {"type": "string"}, "channel_name": {"type": "string"}, "footer": {"type": "string"}, "from_url": {"type": "string"}, "mrkdwn_in": {"type": "array", "items": {"type": "string"}}}}}}}"#),
+    ("message", r#"{"title": "tombstone", "type": "object", "properties": {"type": {"type": "string"}, "subtype": {"type": "string"}, "ts": {"type": "string"}, "user": {"type": "string"}, "text": {"type": "string"}, "hidden": {"type": "boolean"}, "thread_ts": {"type": "string"}, "reply_count": {"type": "integer"}, "replies": {"type": "array", "items": {"type": "object", "properties": {"ts": {"type": "string"}, "user": {"type": "string"}}}}}}"#),
+];
+
+/// Fields missing from the object schemas, by object: `(object, name, JSON schema)`. `object` is
+/// the name of the schema file, followed by the fields or variants leading to a nested object. A
+/// `$ref` starting with `::` refers to a type written by hand.
+static MISSING_OBJECT_FIELDS: &'static [(&'static str, &'static str, &'static str)] = &[
+    ("channel", "is_private", r#"{"type": "boolean"}"#),
+    ("channel", "is_shared", r#"{"type": "boolean"}"#),
+    ("channel", "is_ext_shared", r#"{"type": "boolean"}"#),
+    ("channel", "is_org_shared", r#"{"type": "boolean"}"#),
+    ("channel", "shared_team_ids", r#"{"type": "array", "items": {"type": "string"}}"#),
+    ("channel", "num_members", r#"{"type": "integer"}"#),
+    ("channel", "previous_names", r#"{"type": "array", "items": {"type": "string"}}"#),
+    ("file", "external_id", r#"{"type": "string"}"#),
+    ("file", "external_url", r#"{"type": "string"}"#),
+    ("file", "has_rich_preview", r#"{"type": "boolean"}"#),
+    ("message.standard", "blocks", r#"{"type": "array", "items": {"$ref": "::blocks::Block"}}"#),
+    ("message.bot_message", "blocks", r#"{"type": "array", "items": {"$ref": "::blocks::Block"}}"#),
+    ("message.message_changed.message", "blocks", r#"{"type": "array", "items": {"$ref": "::blocks::Block"}}"#),
+    ("message.message_changed.previous_message", "blocks", r#"{"type": "array", "items": {"$ref": "::blocks::Block"}}"#),
+    ("message.message_deleted.previous_message", "blocks", r#"{"type": "array", "items": {"$ref": "::blocks::Block"}}"#),
+    ("message.message_replied.message", "blocks", r#"{"type": "array", "items": {"$ref": "::blocks::Block"}}"#),
+    ("message.thread_broadcast", "blocks", r#"{"type": "array", "items": {"$ref": "::blocks::Block"}}"#),
+    ("message.bot_message", "thread_ts", r#"{"type": "string"}"#),
+    ("message.file_share", "thread_ts", r#"{"type": "string"}"#),
+    ("message.me_message", "thread_ts", r#"{"type": "string"}"#),
+    ("message.pinned_item.item", "type", r#"{"type": "string"}"#),
+    ("message.pinned_item.item", "ts", r#"{"type": "string"}"#),
+    ("message.pinned_item.item", "user", r#"{"type": "string"}"#),
+    ("message.pinned_item.item", "username", r#"{"type": "string"}"#),
+    ("message.pinned_item.item", "bot_id", r#"{"type": "string"}"#),
+    ("message.pinned_item.item", "text", r#"{"type": "string"}"#),
+    ("message.pinned_item.item", "thread_ts", r#"{"type": "string"}"#),
+    ("message.pinned_item.item", "file", r#"{"$ref": "file.json"}"#),
+    ("message.pinned_item.item", "comment", r#"{"$ref": "file_comment.json"}"#),
+    ("message.unpinned_item.item", "type", r#"{"type": "string"}"#),
+    ("message.unpinned_item.item", "ts", r#"{"type": "string"}"#),
+    ("message.unpinned_item.item", "user", r#"{"type": "string"}"#),
+    ("message.unpinned_item.item", "username", r#"{"type": "string"}"#),
+    ("message.unpinned_item.item", "bot_id", r#"{"type": "string"}"#),
+    ("message.unpinned_item.item", "text", r#"{"type": "string"}"#),
+    ("message.unpinned_item.item", "thread_ts", r#"{"type": "string"}"#),
+    ("message.unpinned_item.item", "file", r#"{"$ref": "file.json"}"#),
+    ("message.unpinned_item.item", "comment", r#"{"$ref": "file_comment.json"}"#),
+    ("team", "enterprise_id", r#"{"type": "string"}"#),
+    ("team", "enterprise_name", r#"{"type": "string"}"#),
+    ("user", "real_name", r#"{"type": "string"}"#),
+    ("user", "team_id", r#"{"type": "string"}"#),
+    ("user", "tz", r#"{"type": "string"}"#),
+    ("user", "tz_offset", r#"{"type": "integer"}"#),
+    ("user", "is_bot", r#"{"type": "boolean"}"#),
+    ("user", "is_app_user", r#"{"type": "boolean"}"#),
+    ("user", "updated", r#"{"type": "integer"}"#),
+    ("user", "enterprise_user", r#"{"type": "object", "properties": {"id": {"type": "string"}, "enterprise_id": {"type": "string"}, "enterprise_name": {"type": "string"}, "is_admin": {"type": "boolean"}, "is_owner": {"type": "boolean"}, "teams": {"type": "array", "items": {"type": "string"}}}}"#),
+    ("user_profile", "display_name", r#"{"type": "string"}"#),
+    ("user_profile", "title", r#"{"type": "string"}"#),
+    ("user_profile", "status_text", r#"{"type": "string"}"#),
+    ("user_profile", "status_emoji", r#"{"type": "string"}"#),
+    ("user_profile", "status_expiration", r#"{"type": "integer"}"#),
+];
+
+/// Response fields whose schemas are replaced, by method: `(method, name, JSON schema)`. Their
+/// schemas describe a type that the objects already describe.
+static REPLACED_RESPONSE_FIELDS: &'static [(&'static str, &'static str, &'static str)] = &[
+    ("pins.list", "items", r#"{"type": "array", "items": {"$ref": "../objects/item.json"}}"#),
+    ("reactions.list", "items", r#"{"type": "array", "items": {"$ref": "../objects/item.json"}}"#),
+    ("stars.list", "items", r#"{"type": "array", "items": {"$ref": "../objects/item.json"}}"#),
+];
+
+/// Methods that respond with an `Item`, whose fields are sent next to `ok` and `error` instead of
+/// in a field of their own: `(method, description of the item)`.
+static ITEM_RESPONSES: &'static [(&'static str, &'static str)] = &[
+    ("reactions.get", "The item that was reacted to"),
+];
+
+/// Methods that page with `page` and respond with `Paging`, by method: `(method, items, item
+/// type)`. `items` is the response field holding each page's items, or `outer.inner` when they are
+/// nested in an object along with the `Paging`.
+static PAGED_ITEMS: &'static [(&'static str, &'static str, &'static str)] = &[
+    ("files.list", "files", "::File"),
+    ("reactions.list", "items", "::Item"),
+    ("search.files", "files.matches", "::File"),
+    ("search.messages", "messages.matches", "::Message"),
+    ("stars.list", "items", "::Item"),
+    ("team.accessLogs", "logins", "AccessLogsResponseLogin"),
+];
+
+/// Methods whose history is paged back through by moving `latest` to the oldest message seen.
+static HISTORY_METHODS: &'static [&'static str] = &[
+    "channels.history",
+    "groups.history",
+    "im.history",
+    "mpim.history",
+];
+
+/// A param that only accepts a fixed set of values, generated as an enum instead of a string.
+#[derive(Debug)]
+pub struct ParamEnum {
+    pub name: &'static str,
+    /// The module the enum is generated in. Params of other modules refer to it by path.
+    pub module: &'static str,
+    pub description: &'static str,
+    /// Whether the param takes a comma-separated list of values.
+    pub list: bool,
+    /// Replaces the schema's description of list params, which describes a comma-separated
+    /// string.
+    pub list_description: Option<&'static str>,
+    /// The accepted values and their docs. The first one is what Slack uses when the param is
+    /// omitted.
+    pub values: &'static [(&'static str, &'static str)],
+}
+
+static SEARCH_SORT: ParamEnum = ParamEnum {
+    name: "SortBy",
+    module: "search",
+    description: "How search results are ordered.",
+    list: false,
+    list_description: None,
+    values: &[("score", "By relevance to the query."), ("timestamp", "By the time of the match.")],
+};
+
+static SEARCH_SORT_DIR: ParamEnum = ParamEnum {
+    name: "SortDirection",
+    module: "search",
+    description: "The direction search results are sorted in.",
+    list: false,
+    list_description: None,
+    values: &[("desc", "Descending."), ("asc", "Ascending.")],
+};
+
+static CHAT_PARSE: ParamEnum = ParamEnum {
+    name: "Parse",
+    module: "chat",
+    description: "How Slack parses the text of a message.",
+    list: false,
+    list_description: None,
+    values: &[
+        ("none", "Only URLs and the names in `<@U123>`-style markup are linked."),
+        ("full", "Names of users and channels, and URLs, are linked as a client would."),
+    ],
+};
+
+static USERS_PRESENCE: ParamEnum = ParamEnum {
+    name: "Presence",
+    module: "users",
+    description: "The presence a user can set for themselves.",
+    list: false,
+    list_description: None,
+    values: &[
+        ("auto", "Let Slack determine presence from the user's activity."),
+        ("away", "Always appear away."),
+    ],
+};
+
+static FILES_TYPE: ParamEnum = ParamEnum {
+    name: "FileType",
+    module: "files",
+    description: "A type of file to filter a file listing by.",
+    list: true,
+    list_description: Some("The types of file to include. Defaults to all, which does not filter the list."),
+    values: &[
+        ("all", "Files of any type."),
+        ("spaces", "Posts."),
+        ("snippets", "Snippets."),
+        ("images", "Image files."),
+        ("gdocs", "Google docs."),
+        ("zips", "Zip files."),
+        ("pdfs", "PDF files."),
+    ],
+};
+
+static CONVERSATION_TYPE: ParamEnum = ParamEnum {
+    name: "ConversationType",
+    module: "conversations",
+    description: "A kind of conversation to include in a conversation listing.",
+    list: true,
+    list_description: Some("The kinds of conversation to include. Defaults to public channels."),
+    values: &[
+        ("public_channel", "Public channels."),
+        ("private_channel", "Private channels."),
+        ("mpim", "Multi-person direct messages."),
+        ("im", "Direct messages."),
+    ],
+};
+
+static OAUTH_GRANT_TYPE: ParamEnum = ParamEnum {
+    name: "GrantType",
+    module: "oauth_v2",
+    description: "How an OAuth access token is granted.",
+    list: false,
+    list_description: None,
+    values: &[
+        ("authorization_code", "Exchange the code returned to the redirect URI."),
+        ("refresh_token", "Exchange a refresh token for a new access token."),
+    ],
+};
+
+/// Params generated as enums, by method and param name.
+static PARAM_ENUMS: &'static [(&'static str, &'static str, &'static ParamEnum)] = &[
+    ("chat.postEphemeral", "parse", &CHAT_PARSE),
+    ("chat.postMessage", "parse", &CHAT_PARSE),
+    ("chat.scheduleMessage", "parse", &CHAT_PARSE),
+    ("chat.update", "parse", &CHAT_PARSE),
+    ("conversations.list", "types", &CONVERSATION_TYPE),
+    ("files.list", "types", &FILES_TYPE),
+    ("oauth.v2.access", "grant_type", &OAUTH_GRANT_TYPE),
+    ("search.all", "sort", &SEARCH_SORT),
+    ("search.all", "sort_dir", &SEARCH_SORT_DIR),
+    ("search.files", "sort", &SEARCH_SORT),
+    ("search.files", "sort_dir", &SEARCH_SORT_DIR),
+    ("search.messages", "sort", &SEARCH_SORT),
+    ("search.messages", "sort_dir", &SEARCH_SORT_DIR),
+    ("users.conversations", "types", &CONVERSATION_TYPE),
+    ("users.setPresence", "presence", &USERS_PRESENCE),
+];
+
+/// A param that takes a list of values, sent to Slack comma-separated.
+#[derive(Debug)]
+pub struct ParamList {
+    /// The most values Slack accepts, and the error returned for more.
+    pub max: Option<(usize, &'static str)>,
+    /// Replaces the schema's description, which describes a comma-separated string.
+    pub description: &'static str,
+}
+
+/// Params generated as lists, by method and param name.
+static PARAM_LISTS: &'static [(&'static str, &'static str, ParamList)] = &[
+    ("conversations.invite", "users", ParamList {
+        max: Some((1000, "too_many_users")),
+        description: "The IDs of the users to invite. Up to 1000 users may be listed.",
+    }),
+    ("conversations.open", "users", ParamList {
+        max: Some((8, "too_many_users")),
+        description: "The users to open a conversation with. If only one user is included, this creates a 1:1 DM. The ordering of the users is preserved whenever a multi-person direct message is returned. Supply a channel when not supplying users.",
+    }),
+    ("dnd.teamInfo", "users", ParamList {
+        max: None,
+        description: "The users to fetch Do Not Disturb status for.",
+    }),
+    ("files.remote.share", "channels", ParamList {
+        max: None,
+        description: "The IDs of the channels where the file will be shared.",
+    }),
+    ("mpim.open", "users", ParamList {
+        max: Some((8, "too_many_users")),
+        description: "The users to open a multi-person direct message with. The ordering of the users is preserved whenever a MPIM group is returned.",
+    }),
+    ("usergroups.create", "channels", ParamList {
+        max: None,
+        description: "The encoded IDs of the channels that the User Group uses as a default.",
+    }),
+    ("usergroups.update", "channels", ParamList {
+        max: None,
+        description: "The encoded IDs of the channels that the User Group uses as a default.",
+    }),
+    ("usergroups.users.update", "users", ParamList {
+        max: None,
+        description: "The encoded IDs of the users that make up the entire list of users for the User Group.",
+    }),
+];
+
+/// A param that takes a typed value, sent to Slack encoded as JSON.
+#[derive(Debug)]
+pub struct ParamJson {
+    pub ty: &'static str,
+    /// Whether the value is a map keyed by `Url`, which is sent keyed by the URL string.
+    pub url_keys: bool,
+}
+
+/// Params generated as typed JSON values, by method and param name.
+static PARAM_JSON: &'static [(&'static str, &'static str, ParamJson)] = &[
+    ("chat.unfurl", "unfurls", ParamJson { ty: "HashMap<::Url, ::attachments::Attachment>", url_keys: true }),
+    ("users.profile.set", "profile", ParamJson { ty: "::profile::ProfileUpdate", url_keys: false }),
+    ("views.open", "view", ParamJson { ty: "::view::View", url_keys: false }),
+    ("views.publish", "view", ParamJson { ty: "::view::View", url_keys: false }),
+    ("views.push", "view", ParamJson { ty: "::view::View", url_keys: false }),
+    ("views.update", "view", ParamJson { ty: "::view::View", url_keys: false }),
+];
+
+impl ParamEnum {
+    pub fn to_code(&self) -> String {
+        format!("\
+            {documentation}#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub enum {name} {{
+                {variants}
+            }}
+
+            impl {name} {{
+                /// The value sent to Slack for this variant.
+                pub fn as_str(&self) -> &'static str {{
+                    match *self {{
+                        {as_str_matches}
+                    }}
+                }}
+            }}
+
+            impl Default for {name} {{
+                fn default() -> Self {{
+                    {name}::{default}
+                }}
+            }}
+
+            impl fmt::Display for {name} {{
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
+                    f.write_str(self.as_str())
+                }}
+            }}
+            ",
+            documentation = format_docs("///", self.description),
+            name = self.name,
+            variants = self.values
+                .iter()
+                .map(|&(value, docs)| format!("{}{},", format_docs("///", docs), value.to_pascal_case()))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            as_str_matches = self.values
+                .iter()
+                .map(|&(value, _)| format!("{}::{} => \"{}\",", self.name, value.to_pascal_case(), value))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            default = self.values[0].0.to_pascal_case()
+        )
     }
 }
 
@@ -61,7 +653,12 @@ impl Method {
         let request_struct_name = type_prefix.clone() + "Request";
         let response_struct_name = type_prefix.clone() + "Response";
         let error_enum_name = type_prefix.clone() + "Error";
-        let response = self.response.generate(&response_struct_name, &error_enum_name);
+        let response = match ITEM_RESPONSES.iter().find(|&&(m, _)| m == self.name) {
+            Some(&(_, item_description)) => {
+                self.response.generate_item(item_description, &response_struct_name, &error_enum_name)
+            }
+            None => self.response.generate(&response_struct_name, &error_enum_name),
+        };
         let response_type = self.response.get_response_type(&response_struct_name);
 
         let send_call = {
@@ -145,6 +742,7 @@ impl Method {
                 pub fn {method_name}<R>({method_params}) -> Result<{response_type}, {error_type}<R::Error>>
                     where R: SlackWebRequestSender
                 {{
+                    {checks}
                     {local_vars}
                     let params = vec![
                         {token}
@@ -156,6 +754,8 @@ impl Method {
 
                 {request}
 
+                {pagination}
+
                 {response}
                 ",
                 documentation = format_docs("///", &[
@@ -168,8 +768,13 @@ impl Method {
                 error_type = error_enum_name,
                 response = response,
                 request = self.get_request_struct(&request_struct_name),
+                pagination = self.get_pagination_impls(&request_struct_name, &response_struct_name),
                 method_params = method_params,
                 token = if has_token { "Some((\"token\", token))," } else { "" },
+                checks = self.params.iter()
+                    .filter_map(|p| p.get_check(&error_enum_name))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
                 local_vars = self.params.iter()
                     .filter(|p| p.ty != "auth_token") // passed in method params instead
                     .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
@@ -187,20 +792,172 @@ impl Method {
         }
     }
 
+    fn is_cursor_paginated(&self) -> bool {
+        self.params.iter().any(|p| p.name == "cursor")
+    }
+
+    fn get_pagination_impls(&self, request_ty: &str, response_ty: &str) -> String {
+        // some methods page both ways while Slack moves them over to cursors
+        vec![
+            self.get_cursor_impls(request_ty, response_ty),
+            self.get_page_impls(request_ty, response_ty),
+            self.get_history_impls(request_ty, response_ty),
+        ].into_iter()
+            .filter_map(|x| x)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Lets `pagination::cursor_pages` walk through the pages of cursor-paginated methods.
+    fn get_cursor_impls(&self, request_ty: &str, response_ty: &str) -> Option<String> {
+        if !self.is_cursor_paginated() {
+            return None;
+        }
+        Some(format!("\
+            impl<'a> ::pagination::CursorRequest for {request_type}<'a> {{
+                fn set_cursor(&mut self, cursor: String) {{
+                    self.cursor = Some(cursor.into());
+                }}
+            }}
+
+            impl ::pagination::CursorResponse for {response_type} {{
+                fn next_cursor(&self) -> Option<&str> {{
+                    self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+                }}
+            }}",
+            request_type = request_ty,
+            response_type = response_ty
+        ))
+    }
+
+    /// Lets `pagination::page_items` walk through the items of methods paged by page number.
+    fn get_page_impls(&self, request_ty: &str, response_ty: &str) -> Option<String> {
+        let &(_, items, item_ty) = PAGED_ITEMS.iter().find(|&&(m, _, _)| m == self.name)?;
+        let (paging, items) = match items.find('.') {
+            Some(i) => (
+                format!("self.{}.as_ref().and_then(|o| o.paging.as_ref())", &items[..i]),
+                format!("self.{}.and_then(|o| o.{})", &items[..i], &items[i + 1..]),
+            ),
+            None => ("self.paging.as_ref()".to_owned(), format!("self.{}", items)),
+        };
+        Some(format!("\
+            impl{lifetime} ::pagination::PageRequest for {request_type}{lifetime} {{
+                fn set_page(&mut self, page: u32) {{
+                    self.page = Some(page);
+                }}
+            }}
+
+            impl ::pagination::PageResponse for {response_type} {{
+                type Item = {item_type};
+
+                fn paging(&self) -> Option<&::Paging> {{
+                    {paging}
+                }}
+
+                fn into_items(self) -> Vec<{item_type}> {{
+                    {items}.unwrap_or_default()
+                }}
+            }}",
+            lifetime = self.request_lifetime(),
+            request_type = request_ty,
+            response_type = response_ty,
+            item_type = item_ty,
+            paging = paging,
+            items = items
+        ))
+    }
+
+    /// Lets `pagination::history_messages` walk back through the history of a conversation.
+    fn get_history_impls(&self, request_ty: &str, response_ty: &str) -> Option<String> {
+        if !HISTORY_METHODS.contains(&&self.name[..]) {
+            return None;
+        }
+        Some(format!("\
+            impl<'a> ::pagination::HistoryRequest for {request_type}<'a> {{
+                fn set_latest(&mut self, latest: String) {{
+                    self.latest = Some(latest.into());
+                }}
+            }}
+
+            impl ::pagination::HistoryResponse for {response_type} {{
+                fn has_more(&self) -> bool {{
+                    self.has_more.unwrap_or(false)
+                }}
+
+                fn into_messages(self) -> Vec<::Message> {{
+                    self.messages.unwrap_or_default()
+                }}
+            }}",
+            request_type = request_ty,
+            response_type = response_ty
+        ))
+    }
+
+    /// The lifetime of the request struct, which it only has when some field borrows.
+    fn request_lifetime(&self) -> &'static str {
+        let borrows = self.params.iter()
+            .filter(|p| p.ty != "auth_token") // passed in method params instead
+            .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
+            .any(|p| p.borrows());
+        if borrows { "<'a>" } else { "" }
+    }
+
     fn get_request_struct(&self, ty_name: &str) -> String {
+        let params = self.params.iter()
+            .filter(|p| p.ty != "auth_token") // passed in method params instead
+            .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
+            .collect::<Vec<_>>();
+        let required = params.iter().filter(|p| !p.optional).collect::<Vec<_>>();
+        let optional = params.iter().filter(|p| p.optional).collect::<Vec<_>>();
+        let lifetime = self.request_lifetime();
+
         format!("\
             #[derive(Clone, Default, Debug)]
             pub struct {request_type}{lifetime} {{
                 {request_params}
+            }}
+
+            impl{lifetime} {request_type}{lifetime} {{
+                /// Starts building a request, taking the parameters that Slack requires.
+                pub fn builder({builder_args}) -> {request_type}Builder{lifetime} {{
+                    {request_type}Builder {{
+                        request: {request_type} {{
+                            {builder_fields}
+                            {default}
+                        }},
+                    }}
+                }}
+            }}
+
+            /// Builder for [`{request_type}`](struct.{request_type}.html), created by `{request_type}::builder`.
+            #[derive(Clone, Debug)]
+            pub struct {request_type}Builder{lifetime} {{
+                request: {request_type}{lifetime},
+            }}
+
+            impl{lifetime} {request_type}Builder{lifetime} {{
+                {setters}
+
+                pub fn build(self) -> {request_type}{lifetime} {{
+                    self.request
+                }}
             }}",
             request_type = ty_name,
-            request_params = self.params.iter()
-                .filter(|p| p.ty != "auth_token") // passed in method params instead
-                .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
-                .map(Param::generate).collect::<Vec<String>>().join("\n"),
-            lifetime = if self.params.iter()
-                .filter(|p| p.ty != "auth_token")
-                .all(|p| p.ty == "integer" || p.ty == "boolean") { "" } else { "<'a>" }
+            request_params = params.iter().map(|p| p.generate()).collect::<Vec<String>>().join("\n"),
+            lifetime = lifetime,
+            builder_args = required.iter()
+                .map(|p| format!("{}: {}", p.name, p.get_builder_arg_type()))
+                .collect::<Vec<_>>()
+                .join(", "),
+            builder_fields = required.iter()
+                .map(|p| match p.get_builder_value() {
+                    ref value if *value == p.name => format!("{},", value),
+                    value => format!("{}: {},", p.name, value),
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            default = if optional.is_empty() { "" } else { "..Default::default()" },
+            setters = optional.iter().map(|p| p.get_builder_setter()).collect::<Vec<_>>().join("\n\n")
         )
     }
 }
@@ -268,6 +1025,9 @@ fn get_enum_to_response_impl(enm: &JsonEnum, error_type: &str) -> Option<String>
                 fn into(self) -> Result<{name}, {error_ty}<E>> {{
                     match self {{
                         {matches}
+                        {name}::Unknown(value) => {{
+                            Err(value.get(\"error\").and_then(|e| e.as_str()).unwrap_or(\"\").into())
+                        }}
                     }}
                 }}
             }}
@@ -326,6 +1086,64 @@ impl Response {
         )
     }
 
+    /// A response holding an `Item` whose fields Slack sends at the top level, which is decoded
+    /// from the fields other than `ok` and `error`.
+    pub fn generate_item(&self, item_description: &str, ty_name: &str, error_ty: &str) -> String {
+        format!("\
+            #[derive(Clone, Debug)]
+            pub struct {name} {{
+                error: Option<String>,
+                /// {item_description}, whose fields Slack sends at the top level of the response.
+                pub item: Option<::Item>,
+                ok: bool,
+                /// Every field of the response other than `ok` and `error`, including those of the item.
+                pub extra: HashMap<String, ::serde_json::Value>,
+            }}
+
+            impl<'de> ::serde::Deserialize<'de> for {name} {{
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where D: ::serde::Deserializer<'de>
+                {{
+                    use serde::de::Error as SerdeError;
+
+                    let mut extra = HashMap::<String, ::serde_json::Value>::deserialize(deserializer)?;
+                    let ok = match extra.remove(\"ok\") {{
+                        Some(ok) => serde_json::from_value(ok).map_err(|e| D::Error::custom(&format!(\"{{}}\", e)))?,
+                        None => false,
+                    }};
+                    let error = match extra.remove(\"error\") {{
+                        Some(error) => serde_json::from_value(error).map_err(|e| D::Error::custom(&format!(\"{{}}\", e)))?,
+                        None => None,
+                    }};
+                    let item = if extra.contains_key(\"type\") {{
+                        let item = serde_json::to_value(&extra)
+                            .and_then(serde_json::from_value)
+                            .map_err(|e| D::Error::custom(&format!(\"{{}}\", e)))?;
+                        Some(item)
+                    }} else {{
+                        None
+                    }};
+                    Ok({name} {{ error, item, ok, extra }})
+                }}
+            }}
+
+            impl<E: Error> Into<Result<{name}, {error_ty}<E>>> for {name} {{
+                fn into(self) -> Result<{name}, {error_ty}<E>> {{
+                    if self.ok {{
+                        Ok(self)
+                    }} else {{
+                        Err(self.error.as_ref().map(String::as_ref).unwrap_or(\"\").into())
+                    }}
+                }}
+            }}
+            {errors}",
+            name = ty_name,
+            item_description = item_description,
+            error_ty = error_ty,
+            errors = self.get_error_enum(error_ty),
+        )
+    }
+
     pub fn get_response_type(&self, ty_name: &str) -> PropType {
         PropType::from_schema(&self.schema, ty_name)
     }
@@ -423,9 +1241,32 @@ pub struct Param {
     #[serde(rename = "type")]
     pub ty: String,
     pub optional: bool,
+    #[serde(skip)]
+    pub enum_ty: Option<&'static ParamEnum>,
+    /// How the enum type is named from the param's module.
+    #[serde(skip)]
+    pub enum_path: String,
+    #[serde(skip)]
+    pub list: Option<&'static ParamList>,
+    #[serde(skip)]
+    pub json_ty: Option<&'static ParamJson>,
 }
 
 impl Param {
+    /// An optional param of the given schema type that the schemas do not describe.
+    fn optional(name: &str, ty: &str, description: &str) -> Param {
+        Param {
+            name: name.into(),
+            description: description.into(),
+            ty: ty.into(),
+            optional: true,
+            enum_ty: None,
+            enum_path: String::new(),
+            list: None,
+            json_ty: None,
+        }
+    }
+
     fn generate(&self) -> String {
         format!(
             "{documentation}\npub {name}: {ty},",
@@ -435,15 +1276,92 @@ impl Param {
         )
     }
 
+    /// Rejects lists that are longer than Slack accepts.
+    pub fn get_check(&self, error_ty: &str) -> Option<String> {
+        let (max, error) = match self.list.and_then(|l| l.max) {
+            Some(max) => max,
+            None => return None,
+        };
+        let too_long = if self.optional {
+            format!("request.{name}.as_ref().map_or(false, |{name}| {name}.len() > {max})", name = self.name, max = max)
+        } else {
+            format!("request.{name}.len() > {max}", name = self.name, max = max)
+        };
+        Some(format!("\
+            if {too_long} {{
+                return Err({error_ty}::{variant});
+            }}",
+            too_long = too_long,
+            error_ty = error_ty,
+            variant = error.to_pascal_case()
+        ))
+    }
+
     pub fn lifted(&self) -> Option<String> {
+        if let Some(j) = self.json_ty {
+            let expect = if j.url_keys { "are" } else { "is" };
+            return Some(match (j.url_keys, self.optional) {
+                (true, true) => format!("let {name} = request.{name}.as_ref().map(|{name}| {{
+                        let {name} = {name}.iter().map(|(url, value)| (url.as_str(), value)).collect::<HashMap<_, _>>();
+                        serde_json::to_string(&{name}).expect(\"{name} {expect} always serializable to JSON\")
+                    }});", name = self.name, expect = expect),
+                (true, false) => format!("\
+                    let {name} = request.{name}.iter().map(|(url, value)| (url.as_str(), value)).collect::<HashMap<_, _>>();
+                    let {name} = serde_json::to_string(&{name}).expect(\"{name} {expect} always serializable to JSON\");", name = self.name, expect = expect),
+                (false, true) => format!("let {name} = request.{name}.as_ref().map(|{name}| serde_json::to_string({name}).expect(\"{name} {expect} always serializable to JSON\"));", name = self.name, expect = expect),
+                (false, false) => format!("let {name} = serde_json::to_string(&request.{name}).expect(\"{name} {expect} always serializable to JSON\");", name = self.name, expect = expect),
+            });
+        }
+        if self.list.is_some() {
+            return if self.optional {
+                Some(format!("let {name} = request.{name}.as_ref().map(|{name}| {name}.join(\",\"));", name = self.name))
+            } else {
+                Some(format!("let {name} = request.{name}.join(\",\");", name = self.name))
+            };
+        }
+        if let Some(e) = self.enum_ty {
+            return match (e.list, self.optional) {
+                (true, true) => Some(format!("let {name} = request.{name}.as_ref().map(|{name}| {name}.iter().map({enum_ty}::as_str).collect::<Vec<_>>().join(\",\"));", name = self.name, enum_ty = self.enum_path)),
+                (true, false) => Some(format!("let {name} = request.{name}.iter().map({enum_ty}::as_str).collect::<Vec<_>>().join(\",\");", name = self.name, enum_ty = self.enum_path)),
+                (false, _) => None,
+            };
+        }
         match (&self.ty[..], self.optional) {
             ("integer", true) => Some(format!("let {name} = request.{name}.map(|{name}| {name}.to_string());", name = self.name)),
             ("integer", false) => Some(format!("let {name} = request.{name}.to_string();", name = self.name)),
+            (_, true) if self.json_item_type().is_some() => Some(format!("let {name} = request.{name}.as_ref().map(|{name}| serde_json::to_string({name}).expect(\"{name} are always serializable to JSON\"));", name = self.name)),
+            (_, false) if self.json_item_type().is_some() => Some(format!("let {name} = serde_json::to_string(&request.{name}).expect(\"{name} are always serializable to JSON\");", name = self.name)),
             _ => None
         }
     }
 
     pub fn get_pair(&self) -> String {
+        if self.list.is_some() || self.json_ty.is_some() {
+            // lifted into local variable, using {name} instead of request.{name}
+            return if self.optional {
+                format!("{name}.as_ref().map(|{name}| (\"{name}\", &{name}[..]))", name = self.name)
+            } else {
+                format!("Some((\"{name}\", &{name}[..]))", name = self.name)
+            };
+        }
+        if let Some(e) = self.enum_ty {
+            return match (e.list, self.optional) {
+                (true, true) => {
+                    // lifted into local variable, using {name} instead of request.{name}
+                    format!("{name}.as_ref().map(|{name}| (\"{name}\", &{name}[..]))", name = self.name)
+                },
+                (true, false) => {
+                    // lifted into local variable, using {name} instead of request.{name}
+                    format!("Some((\"{name}\", &{name}[..]))", name = self.name)
+                },
+                (false, true) => {
+                    format!("request.{name}.map(|{name}| (\"{name}\", {name}.as_str()))", name = self.name)
+                },
+                (false, false) => {
+                    format!("Some((\"{name}\", request.{name}.as_str()))", name = self.name)
+                },
+            };
+        }
         match (&self.ty[..], self.optional) {
             ("boolean", true) => {
                 format!("request.{name}.map(|{name}| (\"{name}\", if {name} {{ \"1\" }} else {{ \"0\" }}))", name = self.name)
@@ -451,35 +1369,106 @@ impl Param {
             ("boolean", false) => {
                 format!("Some((\"{name}\", if request.{name} {{ \"1\" }} else {{ \"0\" }}))", name = self.name)
             },
-            ("integer", true) => {
+            ("integer", true) | ("blocks", true) | ("attachments", true) => {
                 // lifted into local variable, using {name} instead of request.{name}
                 format!("{name}.as_ref().map(|{name}| (\"{name}\", &{name}[..]))", name = self.name)
             },
-            ("integer", false) => {
+            ("integer", false) | ("blocks", false) | ("attachments", false) => {
                 // lifted into local variable, using {name} instead of request.{name}
                 format!("Some((\"{name}\", &{name}[..]))", name = self.name)
             },
             (_, true) => {
-                format!("request.{name}.map(|{name}| (\"{name}\", {name}))", name = self.name)
+                format!("request.{name}.as_ref().map(|{name}| (\"{name}\", &{name}[..]))", name = self.name)
             },
             (_, false) => {
-                format!("Some((\"{name}\", request.{name}))", name = self.name)
+                format!("Some((\"{name}\", &request.{name}[..]))", name = self.name)
             }
         }
     }
 
+    /// Parameters that are sent as a JSON-encoded array of the returned type.
+    fn json_item_type(&self) -> Option<&'static str> {
+        match &self.ty[..] {
+            "blocks" => Some("::blocks::Block"),
+            "attachments" => Some("::attachments::Attachment"),
+            _ => None,
+        }
+    }
+
+    /// Whether the request field borrows, requiring a lifetime on the request struct.
+    fn borrows(&self) -> bool {
+        if self.json_ty.is_some() {
+            return false;
+        }
+        match self.enum_ty {
+            Some(e) => e.list,
+            None => self.ty != "integer" && self.ty != "boolean",
+        }
+    }
+
+    /// The type of the request field, without the `Option` wrapper of optional params.
+    fn get_inner_rust_type(&self) -> String {
+        if let Some(j) = self.json_ty {
+            return j.ty.into();
+        }
+        if self.list.is_some() {
+            return "Vec<Cow<'a, str>>".into();
+        }
+        if let Some(e) = self.enum_ty {
+            return if e.list { format!("Cow<'a, [{}]>", self.enum_path) } else { self.enum_path.clone() };
+        }
+        match &self.ty[..] {
+            "boolean" => "bool".into(),
+            "integer" => "u32".into(),
+            _ => match self.json_item_type() {
+                Some(item_ty) => format!("Cow<'a, [{}]>", item_ty),
+                None => "Cow<'a, str>".into(),
+            },
+        }
+    }
+
     fn get_rust_type(&self) -> String {
-        let ty = match &self.ty[..] {
-            "boolean" => "bool",
-            "integer" => "u32",
-            _ => "&'a str",
-        };
         if self.optional {
-            format!("Option<{}>", ty)
+            format!("Option<{}>", self.get_inner_rust_type())
         } else {
-            ty.to_owned()
+            self.get_inner_rust_type()
         }
     }
+
+    /// Borrowed params are taken by the builder as anything convertible into the field type.
+    fn get_builder_arg_type(&self) -> String {
+        if self.list.is_some() {
+            "impl IntoIterator<Item = impl Into<Cow<'a, str>>>".into()
+        } else if self.borrows() {
+            format!("impl Into<{}>", self.get_inner_rust_type())
+        } else {
+            self.get_inner_rust_type()
+        }
+    }
+
+    fn get_builder_value(&self) -> String {
+        if self.list.is_some() {
+            format!("{}.into_iter().map(Into::into).collect()", self.name)
+        } else if self.borrows() {
+            format!("{}.into()", self.name)
+        } else {
+            self.name.clone()
+        }
+    }
+
+    fn get_builder_setter(&self) -> String {
+        format!("\
+            {documentation}
+            pub fn {name}(mut self, {name}: {ty}) -> Self {{
+                self.request.{name} = Some({value});
+                self
+            }}",
+            documentation = format_docs("///", &self.description),
+            name = self.name,
+            ty = self.get_builder_arg_type(),
+            value = self.get_builder_value()
+        )
+    }
 }
 
 impl JsonObjectFieldInfo {
@@ -548,6 +1537,8 @@ impl JsonEnum {
             #[derive(Clone, Debug)]
             pub enum {name} {{
                 {variants}
+                /// A {variant_field} not modelled by this library, kept as raw JSON.
+                Unknown(::serde_json::Value),
             }}
 
             impl<'de> ::serde::Deserialize<'de> for {name} {{
@@ -556,14 +1547,12 @@ impl JsonEnum {
                 {{
                     use ::serde::de::Error as SerdeError;
 
-                    const VARIANTS: &'static [&'static str] = &[{variant_names}];
-
                     let value = ::serde_json::Value::deserialize(deserializer)?;
                     if let Some(ty_val) = value.get(\"{variant_field}\") {{
                         if let Some(ty) = ty_val.as_str() {{
                             match ty {{
                                 {variant_matches}
-                                _ => Err(D::Error::unknown_variant(ty, VARIANTS))
+                                _ => Ok({name}::Unknown(value.clone()))
                             }}
                         }} else {{
                             Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &\"a string\"))
@@ -581,11 +1570,6 @@ impl JsonEnum {
                 .map(|v| v.to_code())
                 .collect::<Vec<_>>()
                 .join("\n"),
-            variant_names = self.variants
-                .iter()
-                .map(|v| format!("\"{}\"", v.name.to_snake_case()))
-                .collect::<Vec<_>>()
-                .join(","),
             variant_matches = self.variants
                 .iter()
                 .map(|v| format!("\
@@ -636,6 +1620,8 @@ impl JsonObject {
             #[derive(Clone, Debug, Deserialize)]
             pub struct {name} {{
                 {fields}
+                #[serde(flatten)]
+                pub extra: HashMap<String, ::serde_json::Value>,
             }}
 
             {subobjs}",
@@ -73,6 +73,10 @@ pub enum PropType {
 impl PropType {
     pub fn from_schema(schema: &JsonSchema, name: &str) -> Self {
         if let Some(ref def) = schema.definition_ref {
+            // a type written by hand rather than generated from a schema, e.g. `::blocks::Block`
+            if def.starts_with("::") {
+                return PropType::Ref(def[2..].to_owned());
+            }
             // TODO: This ignores `#/` and assumes filenames refer to an existing struct with that
             //       name.
             return PropType::Ref(Path::new(def)
@@ -6,6 +6,7 @@ extern crate inflector;
 extern crate clap;
 extern crate rustfmt;
 
+use std::collections::BTreeMap;
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::Path;
@@ -35,6 +36,7 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
 
     let schema_path = Path::new(SCHEMA_DIR);
 
+    let mut objects = BTreeMap::new();
     for entry in fs::read_dir(schema_path.join("objects"))? {
         if let Ok(e) = entry {
             let path = e.path();
@@ -46,19 +48,26 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
                 let schema = serde_json::from_str::<JsonSchema>(&schema_contents)
                     .expect(&format!("Could not parse object schema for {}", path.display()));
 
-                let ty_name = path.file_stem().unwrap().to_str().unwrap().to_pascal_case();
-
-                let ty = match PropType::from_schema(&schema, &ty_name) {
-                    PropType::Obj(ref o) => o.to_code(),
-                    PropType::Enum(ref e) => e.to_code(),
-                    _ => panic!("Object schema is not an object."),
-                };
-
-                types_file.write_all(ty.as_bytes())?;
+                let name = path.file_stem().unwrap().to_str().unwrap().to_owned();
+                objects.insert(name, schema);
             }
         }
     }
 
+    add_missing_objects(&mut objects);
+
+    for (name, schema) in &objects {
+        let ty_name = name.to_pascal_case();
+
+        let ty = match PropType::from_schema(schema, &ty_name) {
+            PropType::Obj(ref o) => o.to_code(),
+            PropType::Enum(ref e) => e.to_code(),
+            _ => panic!("Object schema is not an object."),
+        };
+
+        types_file.write_all(ty.as_bytes())?;
+    }
+
     {
         let mut rustfmt_config = rustfmt::config::Config::default();
         rustfmt_config.set().write_mode(rustfmt::config::WriteMode::Overwrite);
@@ -81,8 +90,10 @@ fn generate_modules(output_path: &Path) -> io::Result<()> {
                 let mut schema_contents = String::new();
                 schema_file.read_to_string(&mut schema_contents)?;
 
-                let module = serde_json::from_str::<Module>(&schema_contents)
+                let mut module = serde_json::from_str::<Module>(&schema_contents)
                     .expect(&format!("Could not parse module schema for {}", path.display()));
+                module.add_missing_params();
+                module.resolve_param_types();
                 mods.push(module.get_safe_name());
 
                 let out_filepath = output_path.join(format!("{}.rs", module.get_safe_name()));
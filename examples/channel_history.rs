@@ -10,7 +10,7 @@ fn main() {
     let response = slack::channels::history(&client,
                                             &token,
                                             &slack::channels::HistoryRequest {
-                                                channel: &env::args().nth(1).unwrap(),
+                                                channel: env::args().nth(1).unwrap().into(),
                                                 ..slack::channels::HistoryRequest::default()
                                             });
 
@@ -0,0 +1,336 @@
+//! Builders for [message attachments](https://api.slack.com/docs/message-attachments).
+//!
+//! `Attachment`s are passed to `chat::PostMessageRequest` and `chat::UpdateRequest` through their
+//! `attachments` field and are serialized into the request for you.
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_api::attachments::{Attachment, AttachmentField};
+//!
+//! let attachment = Attachment::new("Deploy finished")
+//!     .color("good")
+//!     .title("Deploy #42")
+//!     .field(AttachmentField::new("Environment", "production").short(true))
+//!     .footer("deploybot")
+//!     .ts(1503435956);
+//! ```
+
+/// A legacy secondary message attachment.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Attachment {
+    pub fallback: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<AttachmentField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mrkdwn_in: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<AttachmentAction>,
+}
+
+impl Attachment {
+    /// Creates an attachment with the plain-text summary shown by clients that cannot display
+    /// attachments.
+    pub fn new<S: Into<String>>(fallback: S) -> Self {
+        Attachment {
+            fallback: fallback.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the colour of the attachment border: `good`, `warning`, `danger` or a hex code such
+    /// as `#439FE0`.
+    pub fn color<S: Into<String>>(mut self, color: S) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the text shown above the attachment block.
+    pub fn pretext<S: Into<String>>(mut self, pretext: S) -> Self {
+        self.pretext = Some(pretext.into());
+        self
+    }
+
+    pub fn author_name<S: Into<String>>(mut self, author_name: S) -> Self {
+        self.author_name = Some(author_name.into());
+        self
+    }
+
+    pub fn author_link<S: Into<String>>(mut self, author_link: S) -> Self {
+        self.author_link = Some(author_link.into());
+        self
+    }
+
+    pub fn author_icon<S: Into<String>>(mut self, author_icon: S) -> Self {
+        self.author_icon = Some(author_icon.into());
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn title_link<S: Into<String>>(mut self, title_link: S) -> Self {
+        self.title_link = Some(title_link.into());
+        self
+    }
+
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Appends a field to the table shown in the attachment.
+    pub fn field(mut self, field: AttachmentField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn image_url<S: Into<String>>(mut self, image_url: S) -> Self {
+        self.image_url = Some(image_url.into());
+        self
+    }
+
+    pub fn thumb_url<S: Into<String>>(mut self, thumb_url: S) -> Self {
+        self.thumb_url = Some(thumb_url.into());
+        self
+    }
+
+    pub fn footer<S: Into<String>>(mut self, footer: S) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    pub fn footer_icon<S: Into<String>>(mut self, footer_icon: S) -> Self {
+        self.footer_icon = Some(footer_icon.into());
+        self
+    }
+
+    /// Sets the timestamp shown in the footer, in seconds since the Unix epoch.
+    pub fn ts(mut self, ts: i64) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+
+    /// Enables `mrkdwn` formatting for the named field (`pretext`, `text` or `fields`).
+    pub fn mrkdwn_in<S: Into<String>>(mut self, field: S) -> Self {
+        self.mrkdwn_in.push(field.into());
+        self
+    }
+
+    /// Sets the identifier sent back to your app when one of the attachment's actions is used.
+    pub fn callback_id<S: Into<String>>(mut self, callback_id: S) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    /// Appends an interactive button or menu. Requires a `callback_id`.
+    pub fn action(mut self, action: AttachmentAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+}
+
+/// A row of the table shown in an attachment.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AttachmentField {
+    pub title: String,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short: Option<bool>,
+}
+
+impl AttachmentField {
+    pub fn new<T: Into<String>, V: Into<String>>(title: T, value: V) -> Self {
+        AttachmentField {
+            title: title.into(),
+            value: value.into(),
+            short: None,
+        }
+    }
+
+    /// Marks the field as short enough to be displayed side-by-side with other fields.
+    pub fn short(mut self, short: bool) -> Self {
+        self.short = Some(short);
+        self
+    }
+}
+
+/// An interactive button or menu in an attachment.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AttachmentAction {
+    pub name: String,
+    pub text: String,
+    #[serde(rename = "type")]
+    pub ty: AttachmentActionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_source: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<AttachmentActionOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<AttachmentActionConfirm>,
+}
+
+impl AttachmentAction {
+    /// Creates a button with the given action name and label.
+    pub fn button<N: Into<String>, T: Into<String>>(name: N, text: T) -> Self {
+        AttachmentAction::new(name, text, AttachmentActionType::Button)
+    }
+
+    /// Creates a message menu with the given action name and placeholder text.
+    pub fn select<N: Into<String>, T: Into<String>>(name: N, text: T) -> Self {
+        AttachmentAction::new(name, text, AttachmentActionType::Select)
+    }
+
+    fn new<N: Into<String>, T: Into<String>>(name: N, text: T, ty: AttachmentActionType) -> Self {
+        AttachmentAction {
+            name: name.into(),
+            text: text.into(),
+            ty,
+            value: None,
+            url: None,
+            style: None,
+            data_source: None,
+            options: Vec::new(),
+            confirm: None,
+        }
+    }
+
+    pub fn value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Makes the button open the given URL instead of sending an action to your app.
+    pub fn url<S: Into<String>>(mut self, url: S) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Sets the button style: `default`, `primary` or `danger`.
+    pub fn style<S: Into<String>>(mut self, style: S) -> Self {
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Populates a menu from `users`, `channels`, `conversations` or `external`.
+    pub fn data_source<S: Into<String>>(mut self, data_source: S) -> Self {
+        self.data_source = Some(data_source.into());
+        self
+    }
+
+    /// Appends a static option to a menu.
+    pub fn option(mut self, option: AttachmentActionOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: AttachmentActionConfirm) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttachmentActionType {
+    Button,
+    Select,
+}
+
+/// A static option of an attachment menu.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AttachmentActionOption {
+    pub text: String,
+    pub value: String,
+}
+
+impl AttachmentActionOption {
+    pub fn new<T: Into<String>, V: Into<String>>(text: T, value: V) -> Self {
+        AttachmentActionOption {
+            text: text.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// A confirmation dialog shown before an attachment action is sent.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AttachmentActionConfirm {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismiss_text: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_attachment_builder_serializes() {
+        let attachment = Attachment::new("fallback")
+            .color("danger")
+            .field(AttachmentField::new("Status", "down").short(true))
+            .callback_id("incident")
+            .action(AttachmentAction::button("ack", "Acknowledge").value("1").style("primary"))
+            .ts(1503435956);
+
+        assert_eq!(
+            serde_json::to_value(&attachment).unwrap(),
+            json!({
+                "fallback": "fallback",
+                "color": "danger",
+                "fields": [{"title": "Status", "value": "down", "short": true}],
+                "ts": 1503435956,
+                "callback_id": "incident",
+                "actions": [{
+                    "name": "ack",
+                    "text": "Acknowledge",
+                    "type": "button",
+                    "value": "1",
+                    "style": "primary"
+                }]
+            })
+        );
+    }
+}
@@ -0,0 +1,654 @@
+//! Typed model of [Block Kit](https://api.slack.com/block-kit) layout blocks.
+//!
+//! Blocks can be attached to outgoing messages via the `blocks` field of
+//! `chat::PostMessageRequest` and `chat::UpdateRequest`, and are deserialized from the `blocks`
+//! field of incoming messages. Block and element types that this library does not know about, and
+//! known ones that do not parse, are kept as raw JSON in the `Unknown` variants so that they survive
+//! a round trip and never fail the message they are in.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{self, Value};
+
+// Defines an enum whose variants are distinguished by the `type` field of the JSON object,
+// falling back to `Unknown` with the raw JSON value for any type that is not listed or does not
+// parse as its variant.
+macro_rules! type_tagged_enum {
+    ($(#[$meta:meta])* pub enum $name:ident {
+        $($(#[$variant_meta:meta])* $variant:ident($inner:ty) = $tag:expr,)*
+    }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant($inner),)*
+            /// A type not modelled by this library, or one that did not parse, kept as raw JSON.
+            Unknown(Value),
+        }
+
+        impl $name {
+            /// The value of the `type` field for this variant.
+            pub fn type_name(&self) -> Option<&str> {
+                match *self {
+                    $($name::$variant(_) => Some($tag),)*
+                    $name::Unknown(ref value) => value.get("type").and_then(Value::as_str),
+                }
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                use serde::ser::Error as SerdeError;
+
+                let (tag, value) = match *self {
+                    $($name::$variant(ref inner) => ($tag, serde_json::to_value(inner)),)*
+                    $name::Unknown(ref value) => return value.serialize(serializer),
+                };
+                let mut value = value.map_err(|e| S::Error::custom(&format!("{}", e)))?;
+                if let Value::Object(ref mut map) = value {
+                    map.insert("type".into(), Value::String(tag.into()));
+                }
+                value.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = Value::deserialize(deserializer)?;
+                let tag = value.get("type").and_then(Value::as_str).map(str::to_owned);
+                let parsed = match tag.as_ref().map(String::as_ref) {
+                    $(Some($tag) => serde_json::from_value::<$inner>(value.clone())
+                        .map($name::$variant)
+                        .ok(),)*
+                    _ => None,
+                };
+                Ok(parsed.unwrap_or($name::Unknown(value)))
+            }
+        }
+
+        $(
+            impl From<$inner> for $name {
+                fn from(inner: $inner) -> Self {
+                    $name::$variant(inner)
+                }
+            }
+        )*
+    }
+}
+
+type_tagged_enum! {
+    /// A layout block.
+    pub enum Block {
+        Actions(ActionsBlock) = "actions",
+        Context(ContextBlock) = "context",
+        Divider(DividerBlock) = "divider",
+        Header(HeaderBlock) = "header",
+        Image(ImageBlock) = "image",
+        Input(InputBlock) = "input",
+        Section(SectionBlock) = "section",
+    }
+}
+
+type_tagged_enum! {
+    /// An interactive element, used in `actions` and `input` blocks and as a section accessory.
+    pub enum BlockElement {
+        Button(ButtonElement) = "button",
+        ChannelsSelect(ChannelsSelectElement) = "channels_select",
+        Checkboxes(CheckboxesElement) = "checkboxes",
+        ConversationsSelect(ConversationsSelectElement) = "conversations_select",
+        Datepicker(DatepickerElement) = "datepicker",
+        ExternalSelect(ExternalSelectElement) = "external_select",
+        Image(ImageElement) = "image",
+        MultiChannelsSelect(MultiChannelsSelectElement) = "multi_channels_select",
+        MultiConversationsSelect(MultiConversationsSelectElement) = "multi_conversations_select",
+        MultiExternalSelect(MultiExternalSelectElement) = "multi_external_select",
+        MultiStaticSelect(MultiStaticSelectElement) = "multi_static_select",
+        MultiUsersSelect(MultiUsersSelectElement) = "multi_users_select",
+        Overflow(OverflowElement) = "overflow",
+        PlainTextInput(PlainTextInputElement) = "plain_text_input",
+        RadioButtons(RadioButtonsElement) = "radio_buttons",
+        StaticSelect(StaticSelectElement) = "static_select",
+        Timepicker(TimepickerElement) = "timepicker",
+        UsersSelect(UsersSelectElement) = "users_select",
+    }
+}
+
+type_tagged_enum! {
+    /// A text object, either plain text or `mrkdwn` formatted.
+    pub enum TextObject {
+        PlainText(PlainText) = "plain_text",
+        Mrkdwn(MrkdwnText) = "mrkdwn",
+    }
+}
+
+impl TextObject {
+    /// Creates a `plain_text` text object.
+    pub fn plain<S: Into<String>>(text: S) -> Self {
+        TextObject::PlainText(PlainText {
+            text: text.into(),
+            emoji: None,
+        })
+    }
+
+    /// Creates a `mrkdwn` text object.
+    pub fn mrkdwn<S: Into<String>>(text: S) -> Self {
+        TextObject::Mrkdwn(MrkdwnText {
+            text: text.into(),
+            verbatim: None,
+        })
+    }
+
+    /// The text content, regardless of formatting type, or `""` for an unknown type without
+    /// text.
+    pub fn text(&self) -> &str {
+        match *self {
+            TextObject::PlainText(ref t) => &t.text,
+            TextObject::Mrkdwn(ref t) => &t.text,
+            TextObject::Unknown(ref value) => {
+                value.get("text").and_then(Value::as_str).unwrap_or("")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlainText {
+    pub text: String,
+    /// Whether emoji should be escaped into the colon emoji format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MrkdwnText {
+    pub text: String,
+    /// When true, URLs, channel names and mentions are not automatically linked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbatim: Option<bool>,
+}
+
+type_tagged_enum! {
+    /// An element of a `context` block.
+    pub enum ContextElement {
+        Image(ImageElement) = "image",
+        PlainText(PlainText) = "plain_text",
+        Mrkdwn(MrkdwnText) = "mrkdwn",
+    }
+}
+
+/// A single option of a select menu, overflow menu, checkbox group or radio button group.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OptionObject {
+    pub text: TextObject,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl OptionObject {
+    pub fn new<S: Into<String>>(text: TextObject, value: S) -> Self {
+        OptionObject {
+            text,
+            value: value.into(),
+            description: None,
+            url: None,
+        }
+    }
+}
+
+/// A labelled group of options in a select menu.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OptionGroup {
+    pub label: TextObject,
+    pub options: Vec<OptionObject>,
+}
+
+/// A confirmation dialog shown before an interactive element's action is taken.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConfirmationDialog {
+    pub title: TextObject,
+    pub text: TextObject,
+    pub confirm: TextObject,
+    pub deny: TextObject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+/// The visual style of a button or confirmation dialog.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SectionBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<TextObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<BlockElement>,
+}
+
+impl SectionBlock {
+    pub fn new(text: TextObject) -> Self {
+        SectionBlock {
+            text: Some(text),
+            block_id: None,
+            fields: None,
+            accessory: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DividerBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HeaderBlock {
+    pub text: TextObject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImageBlock {
+    pub image_url: String,
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActionsBlock {
+    pub elements: Vec<BlockElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContextBlock {
+    pub elements: Vec<ContextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InputBlock {
+    pub label: TextObject,
+    pub element: BlockElement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ButtonElement {
+    pub text: TextObject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ButtonElement {
+    pub fn new<S: Into<String>>(text: TextObject, action_id: S) -> Self {
+        ButtonElement {
+            text,
+            action_id: Some(action_id.into()),
+            url: None,
+            value: None,
+            style: None,
+            confirm: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StaticSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiStaticSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExternalSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiExternalSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UsersSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiUsersSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConversationsSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiConversationsSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversations: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChannelsSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultiChannelsSelectElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OverflowElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DatepickerElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    /// The initially selected date, formatted as `YYYY-MM-DD`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TimepickerElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    /// The initially selected time, formatted as `HH:mm`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlainTextInputElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<TextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CheckboxesElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RadioButtonsElement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImageElement {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_section_block_serializes_with_type() {
+        let mut section = SectionBlock::new(TextObject::mrkdwn("*hello*"));
+        section.accessory = Some(ButtonElement::new(TextObject::plain("Click"), "click").into());
+        let blocks = vec![Block::from(section), Block::from(DividerBlock::default())];
+
+        let json = serde_json::to_value(&blocks).unwrap();
+        assert_eq!(
+            json,
+            json!([
+                {
+                    "type": "section",
+                    "text": {"type": "mrkdwn", "text": "*hello*"},
+                    "accessory": {
+                        "type": "button",
+                        "text": {"type": "plain_text", "text": "Click"},
+                        "action_id": "click"
+                    }
+                },
+                {"type": "divider"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_blocks_deserialize() {
+        let blocks: Vec<Block> = serde_json::from_str(r#"[
+            {"type": "actions", "block_id": "b1", "elements": [
+                {"type": "datepicker", "action_id": "d", "initial_date": "2019-01-01"},
+                {"type": "static_select", "options": [{"text": {"type": "plain_text", "text": "A"}, "value": "a"}]}
+            ]},
+            {"type": "context", "elements": [{"type": "image", "image_url": "http://x", "alt_text": "x"}]}
+        ]"#).unwrap();
+
+        match blocks[0] {
+            Block::Actions(ref actions) => {
+                assert_eq!(Some("b1"), actions.block_id.as_ref().map(String::as_ref));
+                assert_eq!(Some("datepicker"), actions.elements[0].type_name());
+                assert_eq!(Some("static_select"), actions.elements[1].type_name());
+            }
+            ref other => panic!("expected an actions block, got {:?}", other),
+        }
+        assert_eq!(Some("context"), blocks[1].type_name());
+    }
+
+    #[test]
+    fn test_unknown_block_round_trips() {
+        let raw = json!({"type": "rich_text", "block_id": "r", "elements": []});
+        let block: Block = serde_json::from_value(raw.clone()).unwrap();
+        assert_eq!(Some("rich_text"), block.type_name());
+        assert_eq!(raw, serde_json::to_value(&block).unwrap());
+    }
+
+    #[test]
+    fn test_malformed_known_types_are_kept_as_unknown() {
+        let block: Block = serde_json::from_value(json!({
+            "type": "actions",
+            "elements": [{
+                "type": "button",
+                "text": {"type": "plain_text", "text": "Go"},
+                "action_id": "go",
+                "style": "secondary"
+            }]
+        })).unwrap();
+        match block {
+            Block::Actions(ref actions) => match actions.elements[0] {
+                BlockElement::Unknown(ref value) => assert_eq!("secondary", value["style"]),
+                ref other => panic!("expected an unknown element, got {:?}", other),
+            },
+            ref other => panic!("expected an actions block, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_text_and_context_elements_deserialize() {
+        let block: Block = serde_json::from_value(json!({
+            "type": "context",
+            "elements": [
+                {"type": "mrkdwn", "text": "*known*"},
+                {"type": "rich_text_preview", "text": "new"}
+            ]
+        })).unwrap();
+        match block {
+            Block::Context(ref context) => {
+                assert_eq!(Some("mrkdwn"), context.elements[0].type_name());
+                assert_eq!(Some("rich_text_preview"), context.elements[1].type_name());
+            }
+            ref other => panic!("expected a context block, got {:?}", other),
+        }
+
+        let text: TextObject =
+            serde_json::from_value(json!({"type": "rich_text", "text": "hi"})).unwrap();
+        assert_eq!(Some("rich_text"), text.type_name());
+        assert_eq!("hi", text.text());
+    }
+}
@@ -0,0 +1,508 @@
+//! Calling the right module for a channel ID, whatever kind of conversation it is.
+//!
+//! Before the `conversations` methods, each kind of conversation had its own set of methods:
+//! `channels` for public channels, `groups` for private channels, `im` for direct messages and
+//! `mpim` for multi-person direct messages. The functions here work out the kind from the channel
+//! ID and call the matching method. Multi-person direct messages have `G` IDs like private
+//! channels, and the `groups` methods accept them where `mpim` has no method of its own. Direct
+//! messages only have `history`, `mark` and `replies`; the other functions return
+//! `DispatchError::NotSupported` for them.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R, token: &str) {
+//! use slack_api::dispatch;
+//!
+//! // e.g. from a `message` event, which says whether a `G` channel is a multi-person DM
+//! let (channel, is_mpim) = ("G0AKFJBEU", false);
+//! match dispatch::info(client, token, channel, is_mpim).unwrap() {
+//!     dispatch::Info::Channel(channel) => println!("#{:?}", channel.name),
+//!     dispatch::Info::Group(group) => println!("private {:?}", group.name),
+//! }
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use channels;
+use groups;
+use im;
+use mpim;
+use requests::SlackWebRequestSender;
+use thread::Thread;
+use types::{Channel, Group, Message};
+
+/// The kinds of conversation, each with its own module of methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConversationKind {
+    /// A public channel, handled by `channels`.
+    Channel,
+    /// A private channel, handled by `groups`.
+    Group,
+    /// A direct message, handled by `im`.
+    Im,
+    /// A multi-person direct message, handled by `mpim`.
+    Mpim,
+}
+
+impl ConversationKind {
+    /// Works out the kind of conversation from the prefix of its ID: `C` for a public channel, `G`
+    /// for a private channel, or a multi-person direct message when `is_mpim` is set, and `D` for
+    /// a direct message.
+    pub fn from_id(id: &str, is_mpim: bool) -> Option<ConversationKind> {
+        match id.chars().next() {
+            Some('C') => Some(ConversationKind::Channel),
+            Some('G') if is_mpim => Some(ConversationKind::Mpim),
+            Some('G') => Some(ConversationKind::Group),
+            Some('D') => Some(ConversationKind::Im),
+            _ => None,
+        }
+    }
+}
+
+/// The info of a public or private channel. A multi-person direct message is a `Group`.
+#[derive(Clone, Debug)]
+pub enum Info {
+    Channel(Channel),
+    Group(Group),
+}
+
+/// A page of the history of a conversation, newest message first.
+#[derive(Clone, Debug)]
+pub struct History {
+    pub messages: Vec<Message>,
+    /// Whether there are messages older than the ones on this page.
+    pub has_more: bool,
+    pub latest: Option<String>,
+}
+
+/// The history request shared by every kind of conversation. Its `channel` decides which module
+/// it is sent to.
+pub type HistoryRequest<'a> = channels::HistoryRequest<'a>;
+
+/// Gets the info of a public or private channel or a multi-person direct message.
+pub fn info<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    is_mpim: bool,
+) -> Result<Info, InfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    match kind_of(channel, is_mpim)? {
+        ConversationKind::Channel => {
+            let request = channels::InfoRequest::builder(channel).build();
+            let response =
+                channels::info(client, token, &request).map_err(DispatchError::Channel)?;
+            response
+                .channel
+                .map(Info::Channel)
+                .ok_or(DispatchError::MissingResult)
+        }
+        ConversationKind::Group | ConversationKind::Mpim => {
+            let request = groups::InfoRequest::builder(channel).build();
+            let response = groups::info(client, token, &request).map_err(DispatchError::Group)?;
+            response
+                .group
+                .map(Info::Group)
+                .ok_or(DispatchError::MissingResult)
+        }
+        kind => Err(DispatchError::NotSupported(kind)),
+    }
+}
+
+/// Fetches a page of the history of a conversation.
+///
+/// To walk through the whole history, see `pagination::history_messages`.
+pub fn history<R>(
+    client: &R,
+    token: &str,
+    request: &HistoryRequest,
+    is_mpim: bool,
+) -> Result<History, HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let (messages, has_more, latest) = match kind_of(&request.channel, is_mpim)? {
+        ConversationKind::Channel => {
+            let response =
+                channels::history(client, token, request).map_err(DispatchError::Channel)?;
+            (response.messages, response.has_more, response.latest)
+        }
+        ConversationKind::Group => {
+            let request = groups::HistoryRequest {
+                channel: request.channel.clone(),
+                latest: request.latest.clone(),
+                oldest: request.oldest.clone(),
+                inclusive: request.inclusive,
+                count: request.count,
+                unreads: request.unreads,
+            };
+            let response =
+                groups::history(client, token, &request).map_err(DispatchError::Group)?;
+            (response.messages, response.has_more, response.latest)
+        }
+        ConversationKind::Im => {
+            let request = im::HistoryRequest {
+                channel: request.channel.clone(),
+                latest: request.latest.clone(),
+                oldest: request.oldest.clone(),
+                inclusive: request.inclusive,
+                count: request.count,
+                unreads: request.unreads,
+            };
+            let response = im::history(client, token, &request).map_err(DispatchError::Im)?;
+            (response.messages, response.has_more, response.latest)
+        }
+        ConversationKind::Mpim => {
+            let request = mpim::HistoryRequest {
+                channel: request.channel.clone(),
+                latest: request.latest.clone(),
+                oldest: request.oldest.clone(),
+                inclusive: request.inclusive,
+                count: request.count,
+                unreads: request.unreads,
+            };
+            let response = mpim::history(client, token, &request).map_err(DispatchError::Mpim)?;
+            (response.messages, response.has_more, response.latest)
+        }
+    };
+    Ok(History {
+        messages: messages.unwrap_or_default(),
+        has_more: has_more.unwrap_or(false),
+        latest,
+    })
+}
+
+/// Moves the read cursor of a conversation to the message at `ts`.
+pub fn mark<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    is_mpim: bool,
+    ts: &str,
+) -> Result<(), MarkError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    match kind_of(channel, is_mpim)? {
+        ConversationKind::Channel => {
+            let request = channels::MarkRequest::builder(channel, ts).build();
+            channels::mark(client, token, &request).map_err(DispatchError::Channel)?;
+        }
+        ConversationKind::Group => {
+            let request = groups::MarkRequest::builder(channel, ts).build();
+            groups::mark(client, token, &request).map_err(DispatchError::Group)?;
+        }
+        ConversationKind::Im => {
+            let request = im::MarkRequest::builder(channel, ts).build();
+            im::mark(client, token, &request).map_err(DispatchError::Im)?;
+        }
+        ConversationKind::Mpim => {
+            let request = mpim::MarkRequest::builder(channel, ts).build();
+            mpim::mark(client, token, &request).map_err(DispatchError::Mpim)?;
+        }
+    }
+    Ok(())
+}
+
+/// Fetches the thread started by the message at `thread_ts`.
+///
+/// Unlike `thread::get`, this does not page through threads too long for one response.
+pub fn replies<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    is_mpim: bool,
+    thread_ts: &str,
+) -> Result<Thread, RepliesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let messages = match kind_of(channel, is_mpim)? {
+        ConversationKind::Channel => {
+            let request = channels::RepliesRequest::builder(channel, thread_ts).build();
+            let response =
+                channels::replies(client, token, &request).map_err(DispatchError::Channel)?;
+            response.messages
+        }
+        ConversationKind::Group => {
+            let request = groups::RepliesRequest::builder(channel, thread_ts).build();
+            let response =
+                groups::replies(client, token, &request).map_err(DispatchError::Group)?;
+            response.messages
+        }
+        ConversationKind::Im => {
+            let request = im::RepliesRequest::builder(channel, thread_ts).build();
+            let response = im::replies(client, token, &request).map_err(DispatchError::Im)?;
+            response.messages
+        }
+        ConversationKind::Mpim => {
+            let request = mpim::RepliesRequest::builder(channel, thread_ts).build();
+            let response = mpim::replies(client, token, &request).map_err(DispatchError::Mpim)?;
+            response.messages
+        }
+    };
+    Ok(Thread::from_messages(
+        thread_ts,
+        messages.unwrap_or_default(),
+    ))
+}
+
+/// Sets the topic of a channel or a multi-person direct message, returning the topic as Slack
+/// saved it.
+pub fn set_topic<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    is_mpim: bool,
+    topic: &str,
+) -> Result<Option<String>, SetTopicError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    match kind_of(channel, is_mpim)? {
+        ConversationKind::Channel => {
+            let request = channels::SetTopicRequest::builder(channel, topic).build();
+            let response =
+                channels::set_topic(client, token, &request).map_err(DispatchError::Channel)?;
+            Ok(response.topic)
+        }
+        ConversationKind::Group | ConversationKind::Mpim => {
+            let request = groups::SetTopicRequest::builder(channel, topic).build();
+            let response =
+                groups::set_topic(client, token, &request).map_err(DispatchError::Group)?;
+            Ok(response.topic)
+        }
+        kind => Err(DispatchError::NotSupported(kind)),
+    }
+}
+
+/// Sets the purpose of a channel or a multi-person direct message, returning the purpose as
+/// Slack saved it.
+pub fn set_purpose<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    is_mpim: bool,
+    purpose: &str,
+) -> Result<Option<String>, SetPurposeError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    match kind_of(channel, is_mpim)? {
+        ConversationKind::Channel => {
+            let request = channels::SetPurposeRequest::builder(channel, purpose).build();
+            let response =
+                channels::set_purpose(client, token, &request).map_err(DispatchError::Channel)?;
+            Ok(response.purpose)
+        }
+        ConversationKind::Group | ConversationKind::Mpim => {
+            let request = groups::SetPurposeRequest::builder(channel, purpose).build();
+            let response =
+                groups::set_purpose(client, token, &request).map_err(DispatchError::Group)?;
+            Ok(response.purpose)
+        }
+        kind => Err(DispatchError::NotSupported(kind)),
+    }
+}
+
+/// Archives a public or private channel or a multi-person direct message.
+pub fn archive<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    is_mpim: bool,
+) -> Result<(), ArchiveError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    match kind_of(channel, is_mpim)? {
+        ConversationKind::Channel => {
+            let request = channels::ArchiveRequest::builder(channel).build();
+            channels::archive(client, token, &request).map_err(DispatchError::Channel)?;
+        }
+        ConversationKind::Group | ConversationKind::Mpim => {
+            let request = groups::ArchiveRequest::builder(channel).build();
+            groups::archive(client, token, &request).map_err(DispatchError::Group)?;
+        }
+        kind => return Err(DispatchError::NotSupported(kind)),
+    }
+    Ok(())
+}
+
+/// Leaves a public or private channel or a multi-person direct message.
+pub fn leave<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    is_mpim: bool,
+) -> Result<(), LeaveError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    match kind_of(channel, is_mpim)? {
+        ConversationKind::Channel => {
+            let request = channels::LeaveRequest::builder(channel).build();
+            channels::leave(client, token, &request).map_err(DispatchError::Channel)?;
+        }
+        ConversationKind::Group | ConversationKind::Mpim => {
+            let request = groups::LeaveRequest::builder(channel).build();
+            groups::leave(client, token, &request).map_err(DispatchError::Group)?;
+        }
+        kind => return Err(DispatchError::NotSupported(kind)),
+    }
+    Ok(())
+}
+
+fn kind_of<C, G, I, M>(
+    channel: &str,
+    is_mpim: bool,
+) -> Result<ConversationKind, DispatchError<C, G, I, M>> {
+    ConversationKind::from_id(channel, is_mpim).ok_or(DispatchError::UnknownChannelKind)
+}
+
+/// An error from one of the functions in this module, holding the error of whichever method it
+/// called.
+#[derive(Debug)]
+pub enum DispatchError<C, G, I, M> {
+    /// The channel ID is not one of a public channel, private channel or direct message.
+    UnknownChannelKind,
+    /// This kind of conversation does not have the method.
+    NotSupported(ConversationKind),
+    /// Slack reported success but left out what was asked for.
+    MissingResult,
+    /// The `channels` method failed.
+    Channel(C),
+    /// The `groups` method failed.
+    Group(G),
+    /// The `im` method failed.
+    Im(I),
+    /// The `mpim` method failed.
+    Mpim(M),
+}
+
+pub type InfoError<E> =
+    DispatchError<channels::InfoError<E>, groups::InfoError<E>, Unsupported, Unsupported>;
+pub type HistoryError<E> = DispatchError<
+    channels::HistoryError<E>,
+    groups::HistoryError<E>,
+    im::HistoryError<E>,
+    mpim::HistoryError<E>,
+>;
+pub type MarkError<E> = DispatchError<
+    channels::MarkError<E>,
+    groups::MarkError<E>,
+    im::MarkError<E>,
+    mpim::MarkError<E>,
+>;
+pub type RepliesError<E> = DispatchError<
+    channels::RepliesError<E>,
+    groups::RepliesError<E>,
+    im::RepliesError<E>,
+    mpim::RepliesError<E>,
+>;
+pub type SetTopicError<E> =
+    DispatchError<channels::SetTopicError<E>, groups::SetTopicError<E>, Unsupported, Unsupported>;
+pub type SetPurposeError<E> = DispatchError<
+    channels::SetPurposeError<E>,
+    groups::SetPurposeError<E>,
+    Unsupported,
+    Unsupported,
+>;
+pub type ArchiveError<E> =
+    DispatchError<channels::ArchiveError<E>, groups::ArchiveError<E>, Unsupported, Unsupported>;
+pub type LeaveError<E> =
+    DispatchError<channels::LeaveError<E>, groups::LeaveError<E>, Unsupported, Unsupported>;
+
+impl<C: Error, G: Error, I: Error, M: Error> fmt::Display for DispatchError<C, G, I, M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<C: Error, G: Error, I: Error, M: Error> Error for DispatchError<C, G, I, M> {
+    fn description(&self) -> &str {
+        match *self {
+            DispatchError::UnknownChannelKind => {
+                "unknown_channel_kind: The channel ID does not start with C, G or D."
+            }
+            DispatchError::NotSupported(_) => {
+                "not_supported: This kind of conversation does not have the method."
+            }
+            DispatchError::MissingResult => {
+                "missing_result: Slack reported success but left out what was asked for."
+            }
+            DispatchError::Channel(ref inner) => inner.description(),
+            DispatchError::Group(ref inner) => inner.description(),
+            DispatchError::Im(ref inner) => inner.description(),
+            DispatchError::Mpim(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            DispatchError::Channel(ref inner) => Some(inner),
+            DispatchError::Group(ref inner) => Some(inner),
+            DispatchError::Im(ref inner) => Some(inner),
+            DispatchError::Mpim(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Stands in for the error of a method that a kind of conversation does not have, so it can never
+/// happen.
+#[derive(Debug)]
+pub enum Unsupported {}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl Error for Unsupported {
+    fn description(&self) -> &str {
+        match *self {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mock::MockSender;
+
+    #[test]
+    fn test_dispatch_routes_by_channel_kind() {
+        let client = MockSender::new(r#"{
+            "ok": true,
+            "messages": [{"type": "message", "user": "U1", "text": "hi", "ts": "1.000001"}],
+            "has_more": false
+        }"#).then(r#"{"ok": true}"#).then(r#"{"ok": true, "topic": "Lunch plans"}"#);
+
+        let request = HistoryRequest::builder("G0AKFJBEU").count(1).build();
+        let history = history(&client, "xoxp-token", &request, true).unwrap();
+        assert!(client.urls.borrow()[0].ends_with("/mpim.history"));
+        assert_eq!(Some("1".to_owned()), client.sent_param("count"));
+        assert_eq!(1, history.messages.len());
+        assert!(!history.has_more);
+
+        mark(&client, "xoxp-token", "C1H9RESGL", false, "1.000001").unwrap();
+        assert!(client.urls.borrow()[1].ends_with("/channels.mark"));
+
+        let topic =
+            set_topic(&client, "xoxp-token", "G0AKFJBEU", true, "Lunch plans").unwrap();
+        assert!(client.urls.borrow()[2].ends_with("/groups.setTopic"));
+        assert_eq!(Some("Lunch plans".to_owned()), topic);
+
+        match archive(&client, "xoxp-token", "D024BE91L", false) {
+            Err(DispatchError::NotSupported(ConversationKind::Im)) => {}
+            other => panic!("expected NotSupported, got {:?}", other),
+        }
+        assert_eq!(3, client.urls.borrow().len());
+    }
+}
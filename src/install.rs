@@ -0,0 +1,281 @@
+//! The "Add to Slack" OAuth flow: sending the user to Slack, checking the callback and exchanging
+//! the code for tokens.
+//!
+//! The flow sends the user to Slack's original authorize URL and exchanges the code with
+//! `oauth::access`. Apps that need Slack's v2 flow, which grants bot and user scopes separately,
+//! can build its URL and exchange the code with `oauth_v2::access` themselves; `verify_callback`
+//! works the same for both.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R, query: &str) {
+//! use slack_api::install::{self, Install};
+//!
+//! let install = Install::new("4b39e9-752c4", "33fea0113f5b1")
+//!     .scopes(vec!["commands", "chat:write"])
+//!     .redirect_uri("https://example.com/slack/callback");
+//!
+//! // Keep the state, e.g. in the user's session, and send the user to the URL.
+//! let state = install::generate_state().unwrap();
+//! println!("{}", install.authorize_url(&state));
+//!
+//! // Then, when Slack redirects back with `query`:
+//! let response = install.complete(client, query, &state).unwrap();
+//! println!("{:?}", response.access_token);
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use rand::{OsRng, Rng};
+use url::form_urlencoded;
+
+use oauth;
+use requests::SlackWebRequestSender;
+use Url;
+
+const AUTHORIZE_URL: &'static str = "https://slack.com/oauth/authorize";
+
+/// The settings of an app's installation flow.
+#[derive(Clone, Debug, Default)]
+pub struct Install {
+    pub client_id: String,
+    pub client_secret: String,
+    /// Scopes requested for the token that the code is exchanged for.
+    pub scopes: Vec<String>,
+    /// Where Slack redirects the user back to. Must match one configured for the app.
+    pub redirect_uri: Option<String>,
+}
+
+impl Install {
+    pub fn new<I: Into<String>, S: Into<String>>(client_id: I, client_secret: S) -> Self {
+        Install {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes = scopes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn redirect_uri<S: Into<String>>(mut self, redirect_uri: S) -> Self {
+        self.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// The URL to send the user to to install the app, carrying `state` to be checked in the
+    /// callback.
+    pub fn authorize_url(&self, state: &str) -> Url {
+        let scope = self.scopes.join(",");
+        let params = vec![
+            Some(("client_id", &self.client_id[..])),
+            if scope.is_empty() { None } else { Some(("scope", &scope[..])) },
+            self.redirect_uri.as_ref().map(|redirect_uri| ("redirect_uri", &redirect_uri[..])),
+            Some(("state", state)),
+        ];
+        Url::parse_with_params(AUTHORIZE_URL, params.into_iter().flatten())
+            .expect("the authorize URL is always valid")
+    }
+
+    /// Exchanges the code from the callback for tokens.
+    pub fn exchange<R>(
+        &self,
+        client: &R,
+        code: &str,
+    ) -> Result<oauth::AccessResponse, oauth::AccessError<R::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        let mut request =
+            oauth::AccessRequest::builder(&self.client_id[..], &self.client_secret[..], code);
+        if let Some(ref redirect_uri) = self.redirect_uri {
+            request = request.redirect_uri(&redirect_uri[..]);
+        }
+        oauth::access(client, &request.build())
+    }
+
+    /// Checks the query string of the callback against the `state` the user was sent with, then
+    /// exchanges its code for tokens.
+    pub fn complete<R>(
+        &self,
+        client: &R,
+        query: &str,
+        state: &str,
+    ) -> Result<oauth::AccessResponse, InstallError<R::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        let code = verify_callback(query, state).map_err(InstallError::Callback)?;
+        self.exchange(client, &code).map_err(InstallError::Access)
+    }
+}
+
+/// Generates a value for the `state` param that other parties cannot guess, from the operating
+/// system's random number generator.
+pub fn generate_state() -> io::Result<String> {
+    let mut bytes = [0u8; 16];
+    OsRng::new()?.fill_bytes(&mut bytes);
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+/// Checks the query string Slack redirected the user back with, returning the code to exchange.
+///
+/// The returned `state` must match the one the user was sent to Slack with, so that callbacks
+/// forged by other sites are rejected.
+pub fn verify_callback(query: &str, state: &str) -> Result<String, CallbackError> {
+    let query = query.trim_start_matches('?');
+    let (mut code, mut returned_state, mut error) = (None, None, None);
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        match &key[..] {
+            "code" => code = Some(value.into_owned()),
+            "state" => returned_state = Some(value.into_owned()),
+            "error" => error = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+
+    match returned_state {
+        Some(ref returned_state) if states_match(returned_state, state) => {}
+        _ => return Err(CallbackError::StateMismatch),
+    }
+    if let Some(error) = error {
+        return Err(CallbackError::Denied(error));
+    }
+    code.ok_or(CallbackError::MissingCode)
+}
+
+/// Compares in time that does not depend on where the states differ.
+fn states_match(a: &str, b: &str) -> bool {
+    a.len() == b.len() && a.bytes().zip(b.bytes()).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CallbackError {
+    /// The `state` is missing or is not the one the user was sent with.
+    StateMismatch,
+    /// Slack returned an error instead of a code, such as `access_denied` when the user cancelled.
+    Denied(String),
+    /// There is neither a code nor an error.
+    MissingCode,
+}
+
+impl fmt::Display for CallbackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for CallbackError {
+    fn description(&self) -> &str {
+        match *self {
+            CallbackError::StateMismatch => {
+                "state_mismatch: The state is not the one the user was sent to Slack with."
+            }
+            CallbackError::Denied(ref error) => error,
+            CallbackError::MissingCode => "missing_code: The callback has no code.",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InstallError<E: Error> {
+    /// The callback was forged or the user did not install the app.
+    Callback(CallbackError),
+    /// Exchanging the code failed.
+    Access(oauth::AccessError<E>),
+}
+
+impl<E: Error> fmt::Display for InstallError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InstallError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            InstallError::Callback(ref inner) => inner.description(),
+            InstallError::Access(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            InstallError::Callback(ref inner) => Some(inner),
+            InstallError::Access(ref inner) => Some(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mock::MockSender;
+
+    #[test]
+    fn test_authorize_url() {
+        let install = Install::new("4b39e9-752c4", "33fea0113f5b1")
+            .scopes(vec!["commands", "chat:write"])
+            .redirect_uri("https://example.com/slack/callback");
+
+        assert_eq!(
+            "https://slack.com/oauth/authorize?client_id=4b39e9-752c4&scope=commands%2Cchat%3Awrite\
+             &redirect_uri=https%3A%2F%2Fexample.com%2Fslack%2Fcallback&state=s%26t",
+            install.authorize_url("s&t").as_str()
+        );
+    }
+
+    #[test]
+    fn test_verify_callback() {
+        assert_eq!(Ok("ccdaa72ad".to_owned()), verify_callback("?code=ccdaa72ad&state=abc", "abc"));
+        assert_eq!(
+            Err(CallbackError::StateMismatch),
+            verify_callback("code=ccdaa72ad&state=abd", "abc")
+        );
+        assert_eq!(Err(CallbackError::StateMismatch), verify_callback("code=ccdaa72ad", "abc"));
+        assert_eq!(
+            Err(CallbackError::Denied("access_denied".to_owned())),
+            verify_callback("error=access_denied&state=abc", "abc")
+        );
+        assert_eq!(Err(CallbackError::MissingCode), verify_callback("state=abc", "abc"));
+    }
+
+    #[test]
+    fn test_generate_state() {
+        let state = generate_state().unwrap();
+        assert_eq!(32, state.len());
+        assert!(state != generate_state().unwrap());
+    }
+
+    #[test]
+    fn test_install_complete() {
+        let client = MockSender::new(r#"{"ok": true, "access_token": "xoxb-1234"}"#);
+        let install = Install::new("4b39e9-752c4", "33fea0113f5b1")
+            .redirect_uri("https://example.com/slack/callback");
+
+        match install.complete(&client, "code=ccdaa72ad&state=forged", "abc") {
+            Err(InstallError::Callback(CallbackError::StateMismatch)) => {}
+            other => panic!("expected StateMismatch, got {:?}", other),
+        }
+        assert!(client.urls.borrow().is_empty());
+
+        let response = install.complete(&client, "?code=ccdaa72ad&state=abc", "abc").unwrap();
+        assert_eq!(Some("xoxb-1234"), response.access_token.as_ref().map(String::as_ref));
+        assert!(client.urls.borrow()[0].ends_with("/oauth.access"));
+        assert_eq!(Some("ccdaa72ad".to_owned()), client.sent_param("code"));
+        assert_eq!(
+            Some("https://example.com/slack/callback".to_owned()),
+            client.sent_param("redirect_uri")
+        );
+    }
+}
@@ -15,10 +15,15 @@
 //! Low-level, direct interface for the [Slack Web
 //! API](https://api.slack.com/methods).
 
+extern crate rand;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[cfg_attr(test, macro_use)]
 extern crate serde_json;
+extern crate url;
+
+pub use url::Url;
 
 mod mods;
 pub use mods::*;
@@ -26,6 +31,18 @@ pub use mods::*;
 mod types;
 pub use types::*;
 
+mod message;
+
+pub mod attachments;
+pub mod blocks;
+pub mod dispatch;
+pub mod install;
+pub mod pagination;
+pub mod profile;
+pub mod rotation;
+pub mod thread;
+pub mod view;
+
 pub mod requests;
 
 #[cfg(feature = "reqwest")]
@@ -78,10 +95,15 @@ fn optional_struct_or_empty_array<'de, T, D>(deserializer: D) -> Result<Option<T
     deserializer.deserialize_any(StructOrEmptyArray(PhantomData))
 }
 
+#[cfg(test)]
+mod mock;
+
 #[cfg(test)]
 mod tests {
     use serde_json;
-    use super::UserProfile;
+    use super::{Channel, Item, Message, User, UserProfile};
+    use super::{channels, reactions};
+    use blocks::Block;
 
     #[test]
     fn test_user_profile_fields_empty_array_deserialize() {
@@ -112,4 +134,163 @@ mod tests {
         let user_profile: UserProfile = serde_json::from_str(r#"{}"#).unwrap();
         assert!(user_profile.fields.is_none());
     }
+
+    #[test]
+    fn test_unknown_fields_are_kept_in_extra() {
+        let user: User = serde_json::from_str(r#"{"id": "U1", "locale": "nb-NO", "is_invited_user": false}"#).unwrap();
+        assert_eq!(Some("U1"), user.id.as_ref().map(String::as_ref));
+        assert_eq!(Some("nb-NO"), user.extra.get("locale").and_then(|v| v.as_str()));
+        assert_eq!(Some(false), user.extra.get("is_invited_user").and_then(|v| v.as_bool()));
+        assert!(!user.extra.contains_key("id"));
+    }
+
+    #[test]
+    fn test_user_and_channel_fields_deserialize() {
+        let user: User = serde_json::from_str(r#"{
+            "id": "U1",
+            "real_name": "Egon Spengler",
+            "tz": "America/New_York",
+            "tz_offset": -14400,
+            "is_bot": false,
+            "is_app_user": false,
+            "updated": 1502138686,
+            "profile": {
+                "display_name": "spengler",
+                "title": "Ghostbuster",
+                "status_text": "Print is dead",
+                "status_emoji": ":books:",
+                "status_expiration": 0
+            }
+        }"#).unwrap();
+        assert_eq!(Some(-14400), user.tz_offset);
+        assert_eq!(Some(false), user.is_bot);
+        assert!(user.extra.is_empty());
+        let profile = user.profile.unwrap();
+        assert_eq!(Some(":books:"), profile.status_emoji.as_ref().map(String::as_ref));
+        assert!(profile.extra.is_empty());
+
+        let channel: Channel = serde_json::from_str(r#"{
+            "id": "C1",
+            "is_private": false,
+            "is_shared": true,
+            "is_ext_shared": true,
+            "num_members": 4,
+            "previous_names": ["old-name"]
+        }"#).unwrap();
+        assert_eq!(Some(true), channel.is_ext_shared);
+        assert_eq!(Some(4), channel.num_members);
+        assert_eq!(Some(vec!["old-name".to_owned()]), channel.previous_names);
+        assert!(channel.extra.is_empty());
+    }
+
+    #[test]
+    fn test_pinned_item_message_keeps_item() {
+        let msg: Message = serde_json::from_str(r#"{"type": "message", "subtype": "pinned_item", "item_type": "F", "ts": "1.0", "item": {"file": {"id": "F1", "name": "a.txt"}}}"#).unwrap();
+        match msg {
+            Message::PinnedItem(pinned) => {
+                let file = pinned.item.unwrap().file.unwrap();
+                assert_eq!(Some("F1"), file.id.as_ref().map(String::as_ref));
+            }
+            other => panic!("expected a pinned item, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_thread_broadcast_deserialize() {
+        let msg: Message = serde_json::from_str(r#"{"type": "message", "subtype": "thread_broadcast", "user": "U1", "text": "also", "ts": "2.0", "thread_ts": "1.0", "root": {"ts": "1.0", "text": "parent", "reply_count": 1}}"#).unwrap();
+        match msg {
+            Message::ThreadBroadcast(ref broadcast) => {
+                assert_eq!(Some(1), broadcast.root.as_ref().unwrap().reply_count);
+            }
+            ref other => panic!("expected a thread broadcast, got {:?}", other),
+        }
+        assert!(msg.is_thread_reply());
+    }
+
+    #[test]
+    fn test_history_keeps_unknown_subtypes() {
+        let response: channels::HistoryResponse = serde_json::from_str(r#"{
+            "ok": true,
+            "has_more": false,
+            "messages": [
+                {"type": "message", "user": "U1", "text": "hi", "ts": "2.0"},
+                {"type": "message", "subtype": "huddle_thread", "user": "U2", "text": "huddle", "ts": "1.0"}
+            ]
+        }"#).unwrap();
+        let messages = response.messages.unwrap();
+        assert_eq!(2, messages.len());
+        match messages[1] {
+            Message::Unknown(ref value) => assert_eq!("huddle_thread", value["subtype"]),
+            ref other => panic!("expected an unknown message, got {:?}", other),
+        }
+        assert_eq!(Some("huddle_thread"), messages[1].subtype());
+        assert_eq!(Some("1.0"), messages[1].ts());
+        assert_eq!(Some("U2"), messages[1].user());
+    }
+
+    #[test]
+    fn test_history_keeps_malformed_known_blocks() {
+        let response: channels::HistoryResponse = serde_json::from_str(r#"{
+            "ok": true,
+            "has_more": false,
+            "messages": [{
+                "type": "message",
+                "user": "U1",
+                "text": "chart",
+                "ts": "1.0",
+                "blocks": [
+                    {"type": "image", "slack_file": {"id": "F1"}, "alt_text": "chart"},
+                    {"type": "divider"}
+                ]
+            }]
+        }"#).unwrap();
+        let messages = response.messages.unwrap();
+        match messages[0] {
+            Message::Standard(ref message) => {
+                let blocks = message.blocks.as_ref().unwrap();
+                match blocks[0] {
+                    Block::Unknown(ref value) => assert_eq!("F1", value["slack_file"]["id"]),
+                    ref other => panic!("expected an unknown block, got {:?}", other),
+                }
+                assert_eq!(Some("divider"), blocks[1].type_name());
+            }
+            ref other => panic!("expected a standard message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_item_deserialize() {
+        let item: Item = serde_json::from_str(r#"{"type": "im", "channel": "D1", "created": 1.0}"#).unwrap();
+        match item {
+            Item::Im(im) => assert_eq!("D1", im.channel),
+            other => panic!("expected an im item, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_reactions_get_response_item() {
+        let response: reactions::GetResponse = serde_json::from_str(r#"{"ok": true, "type": "message", "channel": "C1", "message": {"type": "message", "text": "hi", "ts": "1.0"}}"#).unwrap();
+        match response.item {
+            Some(Item::Message(ref item)) => assert_eq!(Some("hi"), item.message.text()),
+            ref other => panic!("expected a message item, got {:?}", other),
+        }
+
+        assert_eq!(Some("C1"), response.extra.get("channel").and_then(|v| v.as_str()));
+        assert!(!response.extra.contains_key("ok"));
+
+        let response: reactions::GetResponse = serde_json::from_str(r#"{"ok": false, "error": "message_not_found"}"#).unwrap();
+        assert!(response.item.is_none());
+
+        let response = serde_json::from_str::<reactions::GetResponse>(r#"{"ok": true, "type": "message", "channel": "C1"}"#);
+        assert!(response.is_err());
+    }
+
+    #[test]
+    fn test_unknown_item_deserialize() {
+        let item: Item = serde_json::from_str(r#"{"type": "canvas", "canvas": {"id": "F1"}}"#).unwrap();
+        match item {
+            Item::Unknown(ref value) => assert_eq!("F1", value["canvas"]["id"]),
+            other => panic!("expected an unknown item, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,164 @@
+//! Accessors for the fields shared by the different `Message` subtypes.
+
+use serde_json::Value;
+
+use types::Message;
+
+// Expands to a match over `msg` that reads the optional string `field` from each listed variant,
+// or from the raw JSON of an unknown subtype, returning `None` for any variant that does not
+// carry it.
+macro_rules! message_field {
+    ($msg:expr, $field:ident, [$($variant:ident),*]) => {
+        match *$msg {
+            $(Message::$variant(ref m) => m.$field.as_ref().map(String::as_ref),)*
+            Message::Unknown(ref value) => value.get(stringify!($field)).and_then(Value::as_str),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+}
+
+impl Message {
+    /// The timestamp that identifies this message within its channel.
+    pub fn ts(&self) -> Option<&str> {
+        message_field!(self, ts, [
+            Standard, BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate,
+            ChannelJoin, ChannelLeave, ChannelName, ChannelPostingPermissions, ChannelPurpose,
+            ChannelTopic, ChannelUnarchive, EkmAccessDenied, FileComment, FileMention, FileShare,
+            FileUpload, GroupArchive, GroupJoin, GroupLeave, GroupName, GroupPurpose, GroupTopic,
+            GroupUnarchive, MeMessage, MessageChanged, MessageDeleted, MessageReplied, PinnedItem,
+            ReminderAdd, ReplyBroadcast, ThreadBroadcast, Tombstone, UnpinnedItem
+        ])
+    }
+
+    /// The ID of the user who sent the message, if the subtype carries one.
+    ///
+    /// Bot messages and the `message_changed`, `message_deleted` and `message_replied` wrappers
+    /// do not have a top-level user.
+    pub fn user(&self) -> Option<&str> {
+        message_field!(self, user, [
+            Standard, BotAdd, BotRemove, ChannelArchive, ChannelConvertToPrivate, ChannelJoin,
+            ChannelLeave, ChannelName, ChannelPostingPermissions, ChannelPurpose, ChannelTopic,
+            ChannelUnarchive, EkmAccessDenied, FileMention, FileShare, FileUpload, GroupArchive,
+            GroupJoin, GroupLeave, GroupName, GroupPurpose, GroupTopic, GroupUnarchive, MeMessage,
+            PinnedItem, ReminderAdd, ReplyBroadcast, ThreadBroadcast, Tombstone, UnpinnedItem
+        ])
+    }
+
+    /// The text of the message, if the subtype carries one.
+    pub fn text(&self) -> Option<&str> {
+        message_field!(self, text, [
+            Standard, BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate,
+            ChannelJoin, ChannelLeave, ChannelName, ChannelPostingPermissions, ChannelPurpose,
+            ChannelTopic, ChannelUnarchive, EkmAccessDenied, FileComment, FileMention, FileShare,
+            FileUpload, GroupArchive, GroupJoin, GroupLeave, GroupName, GroupPurpose, GroupTopic,
+            GroupUnarchive, MeMessage, PinnedItem, ReminderAdd, ThreadBroadcast, Tombstone,
+            UnpinnedItem
+        ])
+    }
+
+    /// The channel the message was posted in.
+    ///
+    /// Slack only includes this for some subtypes; messages returned from a history call
+    /// usually omit it.
+    pub fn channel(&self) -> Option<&str> {
+        message_field!(self, channel, [
+            Standard, BotAdd, BotRemove, MeMessage, MessageChanged, MessageDeleted, MessageReplied,
+            PinnedItem, ReplyBroadcast, ThreadBroadcast, UnpinnedItem
+        ])
+    }
+
+    /// The timestamp of the parent message of the thread this message belongs to.
+    pub fn thread_ts(&self) -> Option<&str> {
+        message_field!(self, thread_ts, [
+            Standard, BotMessage, FileShare, MeMessage, MessageReplied, ThreadBroadcast, Tombstone
+        ])
+    }
+
+    /// The `subtype` of the message, or `None` for a standard message.
+    pub fn subtype(&self) -> Option<&str> {
+        message_field!(self, subtype, [
+            BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate, ChannelJoin,
+            ChannelLeave, ChannelName, ChannelPostingPermissions, ChannelPurpose, ChannelTopic,
+            ChannelUnarchive, EkmAccessDenied, FileComment, FileMention, FileShare, FileUpload,
+            GroupArchive, GroupJoin, GroupLeave, GroupName, GroupPurpose, GroupTopic,
+            GroupUnarchive, MeMessage, MessageChanged, MessageDeleted, MessageReplied, PinnedItem,
+            ReminderAdd, ReplyBroadcast, ThreadBroadcast, Tombstone, UnpinnedItem
+        ])
+    }
+
+    /// Returns true if this message is a reply inside a thread, as opposed to the thread parent.
+    pub fn is_thread_reply(&self) -> bool {
+        match (self.thread_ts(), self.ts()) {
+            (Some(thread_ts), Some(ts)) => thread_ts != ts,
+            _ => false,
+        }
+    }
+
+    /// Returns true if this message is the parent message of a thread.
+    pub fn is_thread_parent(&self) -> bool {
+        match (self.thread_ts(), self.ts()) {
+            (Some(thread_ts), Some(ts)) => thread_ts == ts,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use types::Message;
+
+    #[test]
+    fn test_standard_message_accessors() {
+        let msg: Message = serde_json::from_str(
+            r#"{"type": "message", "user": "U1", "text": "hi", "ts": "1.000002", "thread_ts": "1.000001"}"#,
+        ).unwrap();
+        assert_eq!(Some("U1"), msg.user());
+        assert_eq!(Some("hi"), msg.text());
+        assert_eq!(Some("1.000002"), msg.ts());
+        assert_eq!(None, msg.subtype());
+        assert!(msg.is_thread_reply());
+        assert!(!msg.is_thread_parent());
+    }
+
+    #[test]
+    fn test_subtyped_message_accessors() {
+        let msg: Message = serde_json::from_str(
+            r#"{"type": "message", "subtype": "bot_message", "bot_id": "B1", "text": "beep", "ts": "1.0"}"#,
+        ).unwrap();
+        assert_eq!(Some("bot_message"), msg.subtype());
+        assert_eq!(Some("beep"), msg.text());
+        assert_eq!(None, msg.user());
+        assert!(!msg.is_thread_reply());
+        assert!(!msg.is_thread_parent());
+    }
+
+    #[test]
+    fn test_bot_thread_reply() {
+        let msg: Message = serde_json::from_str(
+            r#"{"type": "message", "subtype": "bot_message", "bot_id": "B1", "text": "beep", "ts": "1.000002", "thread_ts": "1.000001"}"#,
+        ).unwrap();
+        assert_eq!(Some("1.000001"), msg.thread_ts());
+        assert!(msg.is_thread_reply());
+        assert!(!msg.is_thread_parent());
+    }
+
+    #[test]
+    fn test_thread_reply_without_ts() {
+        let msg: Message = serde_json::from_str(
+            r#"{"type": "message", "user": "U1", "text": "hi", "thread_ts": "1.000001"}"#,
+        ).unwrap();
+        assert!(!msg.is_thread_reply());
+        assert!(!msg.is_thread_parent());
+    }
+
+    #[test]
+    fn test_thread_parent() {
+        let msg: Message = serde_json::from_str(
+            r#"{"type": "message", "user": "U1", "text": "hi", "ts": "1.0", "thread_ts": "1.0"}"#,
+        ).unwrap();
+        assert!(msg.is_thread_parent());
+        assert!(!msg.is_thread_reply());
+    }
+}
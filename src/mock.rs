@@ -0,0 +1,49 @@
+//! A request sender for tests, which answers with canned responses instead of calling Slack.
+
+use std::cell::RefCell;
+use std::io;
+
+use requests::SlackWebRequestSender;
+
+/// Records the URL and params of every request and answers them with the queued responses in
+/// turn, repeating the last one.
+pub struct MockSender {
+    responses: RefCell<Vec<&'static str>>,
+    pub urls: RefCell<Vec<String>>,
+    pub params: RefCell<Vec<Vec<(String, String)>>>,
+}
+
+impl MockSender {
+    pub fn new(response: &'static str) -> MockSender {
+        MockSender {
+            responses: RefCell::new(vec![response]),
+            urls: RefCell::new(vec![]),
+            params: RefCell::new(vec![]),
+        }
+    }
+
+    pub fn then(self, response: &'static str) -> MockSender {
+        self.responses.borrow_mut().push(response);
+        self
+    }
+
+    pub fn sent_param(&self, name: &str) -> Option<String> {
+        self.params.borrow().last().and_then(|params| {
+            params.iter().find(|param| param.0 == name).map(|param| param.1.clone())
+        })
+    }
+}
+
+impl SlackWebRequestSender for MockSender {
+    type Error = io::Error;
+
+    fn send(&self, method: &str, params: &[(&str, &str)]) -> Result<String, io::Error> {
+        self.urls.borrow_mut().push(method.to_owned());
+        self.params.borrow_mut().push(
+            params.iter().map(|&(k, v)| (k.to_owned(), v.to_owned())).collect(),
+        );
+        let mut responses = self.responses.borrow_mut();
+        let response = if responses.len() > 1 { responses.remove(0) } else { responses[0] };
+        Ok(response.to_owned())
+    }
+}
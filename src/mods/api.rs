@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -20,8 +22,8 @@ where
 {
 
     let params = vec![
-        request.error.map(|error| ("error", error)),
-        request.foo.map(|foo| ("foo", foo)),
+        request.error.as_ref().map(|error| ("error", &error[..])),
+        request.foo.as_ref().map(|foo| ("foo", &foo[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("api.test");
@@ -37,9 +39,44 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct TestRequest<'a> {
     /// Error response to return
-    pub error: Option<&'a str>,
+    pub error: Option<Cow<'a, str>>,
+    /// example property to return
+    pub foo: Option<Cow<'a, str>>,
+}
+
+impl<'a> TestRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> TestRequestBuilder<'a> {
+        TestRequestBuilder {
+            request: TestRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`TestRequest`](struct.TestRequest.html), created by `TestRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct TestRequestBuilder<'a> {
+    request: TestRequest<'a>,
+}
+
+impl<'a> TestRequestBuilder<'a> {
+    /// Error response to return
+    pub fn error(mut self, error: impl Into<Cow<'a, str>>) -> Self {
+        self.request.error = Some(error.into());
+        self
+    }
+
     /// example property to return
-    pub foo: Option<&'a str>,
+    pub fn foo(mut self, foo: impl Into<Cow<'a, str>>) -> Self {
+        self.request.foo = Some(foo.into());
+        self
+    }
+
+    pub fn build(self) -> TestRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -48,6 +85,8 @@ pub struct TestResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -46,12 +48,43 @@ pub struct RevokeRequest {
     pub test: Option<bool>,
 }
 
+impl RevokeRequest {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> RevokeRequestBuilder {
+        RevokeRequestBuilder {
+            request: RevokeRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`RevokeRequest`](struct.RevokeRequest.html), created by `RevokeRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RevokeRequestBuilder {
+    request: RevokeRequest,
+}
+
+impl RevokeRequestBuilder {
+    /// Setting this parameter to 1 triggers a testing mode where the specified token will not actually be revoked.
+    pub fn test(mut self, test: bool) -> Self {
+        self.request.test = Some(test);
+        self
+    }
+
+    pub fn build(self) -> RevokeRequest {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RevokeResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub revoked: Option<bool>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -197,6 +230,8 @@ pub struct TestResponse {
     pub url: Option<String>,
     pub user: Option<String>,
     pub user_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -23,7 +25,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), request.bot.map(|bot| ("bot", bot))];
+    let params = vec![Some(("token", token)), request.bot.as_ref().map(|bot| ("bot", &bot[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("bots.info");
     client
@@ -38,7 +40,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// Bot user to get info on
-    pub bot: Option<&'a str>,
+    pub bot: Option<Cow<'a, str>>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> InfoRequestBuilder<'a> {
+        InfoRequestBuilder {
+            request: InfoRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`InfoRequest`](struct.InfoRequest.html), created by `InfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder<'a> {
+    request: InfoRequest<'a>,
+}
+
+impl<'a> InfoRequestBuilder<'a> {
+    /// Bot user to get info on
+    pub fn bot(mut self, bot: impl Into<Cow<'a, str>>) -> Self {
+        self.request.bot = Some(bot.into());
+        self
+    }
+
+    pub fn build(self) -> InfoRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -47,6 +78,8 @@ pub struct InfoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -56,6 +89,8 @@ pub struct InfoResponseBot {
     pub icons: Option<InfoResponseBotIcons>,
     pub id: Option<String>,
     pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -63,6 +98,8 @@ pub struct InfoResponseBotIcons {
     pub image_36: Option<String>,
     pub image_48: Option<String>,
     pub image_72: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
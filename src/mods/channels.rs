@@ -1,6 +1,8 @@
 //! Get info on your team's Slack channels, create or archive channels, invite users, set the topic and purpose, and mark a channel as read.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -24,7 +26,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.archive");
     client
@@ -41,7 +43,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct ArchiveRequest<'a> {
     /// Channel to archive
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> ArchiveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> ArchiveRequestBuilder<'a> {
+        ArchiveRequestBuilder {
+            request: ArchiveRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`ArchiveRequest`](struct.ArchiveRequest.html), created by `ArchiveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ArchiveRequestBuilder<'a> {
+    request: ArchiveRequest<'a>,
+}
+
+impl<'a> ArchiveRequestBuilder<'a> {
+    pub fn build(self) -> ArchiveRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -49,6 +74,8 @@ pub struct ArchiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -212,7 +239,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
+        Some(("name", &request.name[..])),
         request.validate.map(|validate| {
             ("validate", if validate { "1" } else { "0" })
         }),
@@ -231,17 +258,49 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct CreateRequest<'a> {
     /// Name of channel to create
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
+impl<'a> CreateRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(name: impl Into<Cow<'a, str>>) -> CreateRequestBuilder<'a> {
+        CreateRequestBuilder {
+            request: CreateRequest {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`CreateRequest`](struct.CreateRequest.html), created by `CreateRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct CreateRequestBuilder<'a> {
+    request: CreateRequest<'a>,
+}
+
+impl<'a> CreateRequestBuilder<'a> {
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.request.validate = Some(validate);
+        self
+    }
+
+    pub fn build(self) -> CreateRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateResponse {
     pub channel: Option<::Channel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -427,9 +486,9 @@ where
     let count = request.count.map(|count| count.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.latest.map(|latest| ("latest", latest)),
-        request.oldest.map(|oldest| ("oldest", oldest)),
+        Some(("channel", &request.channel[..])),
+        request.latest.as_ref().map(|latest| ("latest", &latest[..])),
+        request.oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
         request.inclusive.map(|inclusive| {
             ("inclusive", if inclusive { "1" } else { "0" })
         }),
@@ -454,11 +513,11 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct HistoryRequest<'a> {
     /// Channel to fetch history for.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// End of time range of messages to include in results.
-    pub latest: Option<&'a str>,
+    pub latest: Option<Cow<'a, str>>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<&'a str>,
+    pub oldest: Option<Cow<'a, str>>,
     /// Include messages with latest or oldest timestamp in results.
     pub inclusive: Option<bool>,
     /// Number of messages to return, between 1 and 1000.
@@ -467,6 +526,76 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
+impl<'a> HistoryRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> HistoryRequestBuilder<'a> {
+        HistoryRequestBuilder {
+            request: HistoryRequest {
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`HistoryRequest`](struct.HistoryRequest.html), created by `HistoryRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct HistoryRequestBuilder<'a> {
+    request: HistoryRequest<'a>,
+}
+
+impl<'a> HistoryRequestBuilder<'a> {
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.latest = Some(latest.into());
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.oldest = Some(oldest.into());
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.request.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Number of messages to return, between 1 and 1000.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Include unread_count_display in the output?
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.request.unreads = Some(unreads);
+        self
+    }
+
+    pub fn build(self) -> HistoryRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::HistoryRequest for HistoryRequest<'a> {
+    fn set_latest(&mut self, latest: String) {
+        self.latest = Some(latest.into());
+    }
+}
+
+impl ::pagination::HistoryResponse for HistoryResponse {
+    fn has_more(&self) -> bool {
+        self.has_more.unwrap_or(false)
+    }
+
+    fn into_messages(self) -> Vec<::Message> {
+        self.messages.unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct HistoryResponse {
     error: Option<String>,
@@ -475,6 +604,8 @@ pub struct HistoryResponse {
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -622,7 +753,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.info");
     client
@@ -637,7 +768,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// Channel to get info on
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> InfoRequestBuilder<'a> {
+        InfoRequestBuilder {
+            request: InfoRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`InfoRequest`](struct.InfoRequest.html), created by `InfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder<'a> {
+    request: InfoRequest<'a>,
+}
+
+impl<'a> InfoRequestBuilder<'a> {
+    pub fn build(self) -> InfoRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -646,6 +800,8 @@ pub struct InfoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -783,8 +939,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("user", request.user)),
+        Some(("channel", &request.channel[..])),
+        Some(("user", &request.user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.invite");
@@ -800,9 +956,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InviteRequest<'a> {
     /// Channel to invite user to.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// User to invite to channel.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> InviteRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        user: impl Into<Cow<'a, str>>,
+    ) -> InviteRequestBuilder<'a> {
+        InviteRequestBuilder {
+            request: InviteRequest {
+                channel: channel.into(),
+                user: user.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`InviteRequest`](struct.InviteRequest.html), created by `InviteRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InviteRequestBuilder<'a> {
+    request: InviteRequest<'a>,
+}
+
+impl<'a> InviteRequestBuilder<'a> {
+    pub fn build(self) -> InviteRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -811,6 +994,8 @@ pub struct InviteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -994,7 +1179,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
+        Some(("name", &request.name[..])),
         request.validate.map(|validate| {
             ("validate", if validate { "1" } else { "0" })
         }),
@@ -1013,17 +1198,49 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct JoinRequest<'a> {
     /// Name of channel to join
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
+impl<'a> JoinRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(name: impl Into<Cow<'a, str>>) -> JoinRequestBuilder<'a> {
+        JoinRequestBuilder {
+            request: JoinRequest {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`JoinRequest`](struct.JoinRequest.html), created by `JoinRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct JoinRequestBuilder<'a> {
+    request: JoinRequest<'a>,
+}
+
+impl<'a> JoinRequestBuilder<'a> {
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.request.validate = Some(validate);
+        self
+    }
+
+    pub fn build(self) -> JoinRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct JoinResponse {
     pub channel: Option<::Channel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1217,8 +1434,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("user", request.user)),
+        Some(("channel", &request.channel[..])),
+        Some(("user", &request.user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.kick");
@@ -1234,9 +1451,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct KickRequest<'a> {
     /// Channel to remove user from.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// User to remove from channel.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> KickRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        user: impl Into<Cow<'a, str>>,
+    ) -> KickRequestBuilder<'a> {
+        KickRequestBuilder {
+            request: KickRequest {
+                channel: channel.into(),
+                user: user.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`KickRequest`](struct.KickRequest.html), created by `KickRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct KickRequestBuilder<'a> {
+    request: KickRequest<'a>,
+}
+
+impl<'a> KickRequestBuilder<'a> {
+    pub fn build(self) -> KickRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1244,6 +1488,8 @@ pub struct KickResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1415,7 +1661,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.leave");
     client
@@ -1430,7 +1676,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct LeaveRequest<'a> {
     /// Channel to leave
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> LeaveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> LeaveRequestBuilder<'a> {
+        LeaveRequestBuilder {
+            request: LeaveRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`LeaveRequest`](struct.LeaveRequest.html), created by `LeaveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct LeaveRequestBuilder<'a> {
+    request: LeaveRequest<'a>,
+}
+
+impl<'a> LeaveRequestBuilder<'a> {
+    pub fn build(self) -> LeaveRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1438,6 +1707,8 @@ pub struct LeaveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1592,7 +1863,7 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
         Some(("token", token)),
         request.exclude_archived.map(|exclude_archived| {
@@ -1601,6 +1872,9 @@ where
         request.exclude_members.map(|exclude_members| {
             ("exclude_members", if exclude_members { "1" } else { "0" })
         }),
+        request.team_id.as_ref().map(|team_id| ("team_id", &team_id[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.list");
@@ -1614,11 +1888,82 @@ where
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+pub struct ListRequest<'a> {
     /// Exclude archived channels from the list
     pub exclude_archived: Option<bool>,
     /// Exclude the members collection from each channel
     pub exclude_members: Option<bool>,
+    /// Encoded team ID to list channels in. Required when using an org-level token on Enterprise Grid.
+    pub team_id: Option<Cow<'a, str>>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// Exclude archived channels from the list
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.request.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    /// Exclude the members collection from each channel
+    pub fn exclude_members(mut self, exclude_members: bool) -> Self {
+        self.request.exclude_members = Some(exclude_members);
+        self
+    }
+
+    /// Encoded team ID to list channels in. Required when using an org-level token on Enterprise Grid.
+    pub fn team_id(mut self, team_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.team_id = Some(team_id.into());
+        self
+    }
+
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for ListRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for ListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1627,6 +1972,9 @@ pub struct ListResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1758,8 +2106,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.mark");
@@ -1775,9 +2123,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct MarkRequest<'a> {
     /// Channel to set reading cursor in.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Timestamp of the most recently seen message.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
+}
+
+impl<'a> MarkRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        ts: impl Into<Cow<'a, str>>,
+    ) -> MarkRequestBuilder<'a> {
+        MarkRequestBuilder {
+            request: MarkRequest {
+                channel: channel.into(),
+                ts: ts.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`MarkRequest`](struct.MarkRequest.html), created by `MarkRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct MarkRequestBuilder<'a> {
+    request: MarkRequest<'a>,
+}
+
+impl<'a> MarkRequestBuilder<'a> {
+    pub fn build(self) -> MarkRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1785,6 +2160,8 @@ pub struct MarkResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1932,8 +2309,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("name", request.name)),
+        Some(("channel", &request.channel[..])),
+        Some(("name", &request.name[..])),
         request.validate.map(|validate| {
             ("validate", if validate { "1" } else { "0" })
         }),
@@ -1952,19 +2329,55 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct RenameRequest<'a> {
     /// Channel to rename
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// New name for channel.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
+impl<'a> RenameRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> RenameRequestBuilder<'a> {
+        RenameRequestBuilder {
+            request: RenameRequest {
+                channel: channel.into(),
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`RenameRequest`](struct.RenameRequest.html), created by `RenameRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RenameRequestBuilder<'a> {
+    request: RenameRequest<'a>,
+}
+
+impl<'a> RenameRequestBuilder<'a> {
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.request.validate = Some(validate);
+        self
+    }
+
+    pub fn build(self) -> RenameRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct RenameResponse {
     pub channel: Option<RenameResponseChannel>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1973,6 +2386,8 @@ pub struct RenameResponseChannel {
     pub id: Option<String>,
     pub is_channel: Option<bool>,
     pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -2160,8 +2575,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("thread_ts", request.thread_ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("thread_ts", &request.thread_ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.replies");
@@ -2179,9 +2594,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct RepliesRequest<'a> {
     /// Channel to fetch thread from
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Unique identifier of a thread's parent message
-    pub thread_ts: &'a str,
+    pub thread_ts: Cow<'a, str>,
+}
+
+impl<'a> RepliesRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        thread_ts: impl Into<Cow<'a, str>>,
+    ) -> RepliesRequestBuilder<'a> {
+        RepliesRequestBuilder {
+            request: RepliesRequest {
+                channel: channel.into(),
+                thread_ts: thread_ts.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`RepliesRequest`](struct.RepliesRequest.html), created by `RepliesRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RepliesRequestBuilder<'a> {
+    request: RepliesRequest<'a>,
+}
+
+impl<'a> RepliesRequestBuilder<'a> {
+    pub fn build(self) -> RepliesRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -2191,6 +2633,8 @@ pub struct RepliesResponse {
     #[serde(default)]
     ok: bool,
     pub thread_info: Option<::ThreadInfo>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -2334,8 +2778,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("purpose", request.purpose)),
+        Some(("channel", &request.channel[..])),
+        Some(("purpose", &request.purpose[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.setPurpose");
@@ -2352,9 +2796,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct SetPurposeRequest<'a> {
     /// Channel to set the purpose of
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// The new purpose
-    pub purpose: &'a str,
+    pub purpose: Cow<'a, str>,
+}
+
+impl<'a> SetPurposeRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        purpose: impl Into<Cow<'a, str>>,
+    ) -> SetPurposeRequestBuilder<'a> {
+        SetPurposeRequestBuilder {
+            request: SetPurposeRequest {
+                channel: channel.into(),
+                purpose: purpose.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`SetPurposeRequest`](struct.SetPurposeRequest.html), created by `SetPurposeRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct SetPurposeRequestBuilder<'a> {
+    request: SetPurposeRequest<'a>,
+}
+
+impl<'a> SetPurposeRequestBuilder<'a> {
+    pub fn build(self) -> SetPurposeRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -2363,6 +2834,8 @@ pub struct SetPurposeResponse {
     #[serde(default)]
     ok: bool,
     pub purpose: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -2520,8 +2993,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("topic", request.topic)),
+        Some(("channel", &request.channel[..])),
+        Some(("topic", &request.topic[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.setTopic");
@@ -2539,9 +3012,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct SetTopicRequest<'a> {
     /// Channel to set the topic of
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// The new topic
-    pub topic: &'a str,
+    pub topic: Cow<'a, str>,
+}
+
+impl<'a> SetTopicRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        topic: impl Into<Cow<'a, str>>,
+    ) -> SetTopicRequestBuilder<'a> {
+        SetTopicRequestBuilder {
+            request: SetTopicRequest {
+                channel: channel.into(),
+                topic: topic.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`SetTopicRequest`](struct.SetTopicRequest.html), created by `SetTopicRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct SetTopicRequestBuilder<'a> {
+    request: SetTopicRequest<'a>,
+}
+
+impl<'a> SetTopicRequestBuilder<'a> {
+    pub fn build(self) -> SetTopicRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -2550,6 +3050,8 @@ pub struct SetTopicResponse {
     #[serde(default)]
     ok: bool,
     pub topic: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -2705,7 +3207,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("channels.unarchive");
     client
@@ -2722,7 +3224,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct UnarchiveRequest<'a> {
     /// Channel to unarchive
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> UnarchiveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> UnarchiveRequestBuilder<'a> {
+        UnarchiveRequestBuilder {
+            request: UnarchiveRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`UnarchiveRequest`](struct.UnarchiveRequest.html), created by `UnarchiveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct UnarchiveRequestBuilder<'a> {
+    request: UnarchiveRequest<'a>,
+}
+
+impl<'a> UnarchiveRequestBuilder<'a> {
+    pub fn build(self) -> UnarchiveRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -2730,6 +3255,8 @@ pub struct UnarchiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,6 +1,8 @@
 //! Post chat messages to Slack.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -11,6 +13,37 @@ use serde_json;
 
 use requests::SlackWebRequestSender;
 
+/// How Slack parses the text of a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Parse {
+    /// Only URLs and the names in `<@U123>`-style markup are linked.
+    None,
+    /// Names of users and channels, and URLs, are linked as a client would.
+    Full,
+}
+
+impl Parse {
+    /// The value sent to Slack for this variant.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Parse::None => "none",
+            Parse::Full => "full",
+        }
+    }
+}
+
+impl Default for Parse {
+    fn default() -> Self {
+        Parse::None
+    }
+}
+
+impl fmt::Display for Parse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Deletes a message.
 ///
 /// Wraps https://api.slack.com/methods/chat.delete
@@ -26,8 +59,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("ts", request.ts)),
-        Some(("channel", request.channel)),
+        Some(("ts", &request.ts[..])),
+        Some(("channel", &request.channel[..])),
         request.as_user.map(|as_user| {
             ("as_user", if as_user { "1" } else { "0" })
         }),
@@ -46,13 +79,47 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct DeleteRequest<'a> {
     /// Timestamp of the message to be deleted.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
     /// Channel containing the message to be deleted.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Pass true to delete the message as the authed user. Bot users in this context are considered authed users.
     pub as_user: Option<bool>,
 }
 
+impl<'a> DeleteRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        ts: impl Into<Cow<'a, str>>,
+        channel: impl Into<Cow<'a, str>>,
+    ) -> DeleteRequestBuilder<'a> {
+        DeleteRequestBuilder {
+            request: DeleteRequest {
+                ts: ts.into(),
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`DeleteRequest`](struct.DeleteRequest.html), created by `DeleteRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct DeleteRequestBuilder<'a> {
+    request: DeleteRequest<'a>,
+}
+
+impl<'a> DeleteRequestBuilder<'a> {
+    /// Pass true to delete the message as the authed user. Bot users in this context are considered authed users.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.request.as_user = Some(as_user);
+        self
+    }
+
+    pub fn build(self) -> DeleteRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct DeleteResponse {
     pub channel: Option<String>,
@@ -60,6 +127,8 @@ pub struct DeleteResponse {
     #[serde(default)]
     ok: bool,
     pub ts: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -200,57 +269,97 @@ impl<E: Error> Error for DeleteError<E> {
     }
 }
 
-/// Share a me message into a channel.
+/// Deletes a pending scheduled message from the queue.
 ///
-/// Wraps https://api.slack.com/methods/chat.meMessage
+/// Wraps https://api.slack.com/methods/chat.deleteScheduledMessage
 
-pub fn me_message<R>(
+pub fn delete_scheduled_message<R>(
     client: &R,
     token: &str,
-    request: &MeMessageRequest,
-) -> Result<MeMessageResponse, MeMessageError<R::Error>>
+    request: &DeleteScheduledMessageRequest,
+) -> Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("text", request.text)),
+        Some(("channel", &request.channel[..])),
+        Some(("scheduled_message_id", &request.scheduled_message_id[..])),
+        request.as_user.map(|as_user| {
+            ("as_user", if as_user { "1" } else { "0" })
+        }),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("chat.meMessage");
+    let url = ::get_slack_url_for_method("chat.deleteScheduledMessage");
     client
         .send(&url, &params[..])
-        .map_err(MeMessageError::Client)
+        .map_err(DeleteScheduledMessageError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MeMessageResponse>(&result).map_err(
-                MeMessageError::MalformedResponse,
+            serde_json::from_str::<DeleteScheduledMessageResponse>(&result).map_err(
+                DeleteScheduledMessageError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct MeMessageRequest<'a> {
-    /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name.
-    pub channel: &'a str,
-    /// Text of the message to send.
-    pub text: &'a str,
+pub struct DeleteScheduledMessageRequest<'a> {
+    /// The channel the scheduled_message is posting to
+    pub channel: Cow<'a, str>,
+    /// scheduled_message_id returned from call to chat.scheduleMessage
+    pub scheduled_message_id: Cow<'a, str>,
+    /// Pass true to delete the message as the authed user with chat:write:user scope. Bot users in this context are considered authed users. If unused or false, the message will be deleted with chat:write:bot scope.
+    pub as_user: Option<bool>,
+}
+
+impl<'a> DeleteScheduledMessageRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        scheduled_message_id: impl Into<Cow<'a, str>>,
+    ) -> DeleteScheduledMessageRequestBuilder<'a> {
+        DeleteScheduledMessageRequestBuilder {
+            request: DeleteScheduledMessageRequest {
+                channel: channel.into(),
+                scheduled_message_id: scheduled_message_id.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`DeleteScheduledMessageRequest`](struct.DeleteScheduledMessageRequest.html), created by `DeleteScheduledMessageRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct DeleteScheduledMessageRequestBuilder<'a> {
+    request: DeleteScheduledMessageRequest<'a>,
+}
+
+impl<'a> DeleteScheduledMessageRequestBuilder<'a> {
+    /// Pass true to delete the message as the authed user with chat:write:user scope. Bot users in this context are considered authed users. If unused or false, the message will be deleted with chat:write:bot scope.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.request.as_user = Some(as_user);
+        self
+    }
+
+    pub fn build(self) -> DeleteScheduledMessageRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct MeMessageResponse {
-    pub channel: Option<String>,
+pub struct DeleteScheduledMessageResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub ts: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<MeMessageResponse, MeMessageError<E>>> for MeMessageResponse {
-    fn into(self) -> Result<MeMessageResponse, MeMessageError<E>> {
+impl<E: Error> Into<Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<E>>>
+    for DeleteScheduledMessageResponse {
+    fn into(self) -> Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<E>> {
         if self.ok {
             Ok(self)
         } else {
@@ -259,19 +368,11 @@ impl<E: Error> Into<Result<MeMessageResponse, MeMessageError<E>>> for MeMessageR
     }
 }
 #[derive(Debug)]
-pub enum MeMessageError<E: Error> {
+pub enum DeleteScheduledMessageError<E: Error> {
+    /// The scheduled_message_id passed is either invalid, expired, or the message was already sent.
+    InvalidScheduledMessageId,
     /// Value passed for channel was invalid.
     ChannelNotFound,
-    /// Cannot post user messages to a channel they are not in.
-    NotInChannel,
-    /// Channel has been archived.
-    IsArchived,
-    /// Message text is too long
-    MsgTooLong,
-    /// No message text provided
-    NoText,
-    /// Application has posted too many messages, read the Rate Limit documentation for more information
-    RateLimited,
     /// No authentication token provided.
     NotAuthed,
     /// Invalid authentication token.
@@ -302,194 +403,1411 @@ pub enum MeMessageError<E: Error> {
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for MeMessageError<E> {
+impl<'a, E: Error> From<&'a str> for DeleteScheduledMessageError<E> {
     fn from(s: &'a str) -> Self {
         match s {
-            "channel_not_found" => MeMessageError::ChannelNotFound,
-            "not_in_channel" => MeMessageError::NotInChannel,
-            "is_archived" => MeMessageError::IsArchived,
-            "msg_too_long" => MeMessageError::MsgTooLong,
-            "no_text" => MeMessageError::NoText,
-            "rate_limited" => MeMessageError::RateLimited,
-            "not_authed" => MeMessageError::NotAuthed,
-            "invalid_auth" => MeMessageError::InvalidAuth,
-            "account_inactive" => MeMessageError::AccountInactive,
-            "invalid_arg_name" => MeMessageError::InvalidArgName,
-            "invalid_array_arg" => MeMessageError::InvalidArrayArg,
-            "invalid_charset" => MeMessageError::InvalidCharset,
-            "invalid_form_data" => MeMessageError::InvalidFormData,
-            "invalid_post_type" => MeMessageError::InvalidPostType,
-            "missing_post_type" => MeMessageError::MissingPostType,
-            "team_added_to_org" => MeMessageError::TeamAddedToOrg,
-            "request_timeout" => MeMessageError::RequestTimeout,
-            _ => MeMessageError::Unknown(s.to_owned()),
+            "invalid_scheduled_message_id" => DeleteScheduledMessageError::InvalidScheduledMessageId,
+            "channel_not_found" => DeleteScheduledMessageError::ChannelNotFound,
+            "not_authed" => DeleteScheduledMessageError::NotAuthed,
+            "invalid_auth" => DeleteScheduledMessageError::InvalidAuth,
+            "account_inactive" => DeleteScheduledMessageError::AccountInactive,
+            "invalid_arg_name" => DeleteScheduledMessageError::InvalidArgName,
+            "invalid_array_arg" => DeleteScheduledMessageError::InvalidArrayArg,
+            "invalid_charset" => DeleteScheduledMessageError::InvalidCharset,
+            "invalid_form_data" => DeleteScheduledMessageError::InvalidFormData,
+            "invalid_post_type" => DeleteScheduledMessageError::InvalidPostType,
+            "missing_post_type" => DeleteScheduledMessageError::MissingPostType,
+            "team_added_to_org" => DeleteScheduledMessageError::TeamAddedToOrg,
+            "request_timeout" => DeleteScheduledMessageError::RequestTimeout,
+            _ => DeleteScheduledMessageError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for DeleteScheduledMessageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for DeleteScheduledMessageError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteScheduledMessageError::InvalidScheduledMessageId => {
+                "invalid_scheduled_message_id: The scheduled_message_id passed is either invalid, expired, or the message was already sent."
+            }
+            DeleteScheduledMessageError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            DeleteScheduledMessageError::NotAuthed => {
+                "not_authed: No authentication token provided."
+            }
+            DeleteScheduledMessageError::InvalidAuth => {
+                "invalid_auth: Invalid authentication token."
+            }
+            DeleteScheduledMessageError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            DeleteScheduledMessageError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            DeleteScheduledMessageError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            DeleteScheduledMessageError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            DeleteScheduledMessageError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            DeleteScheduledMessageError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            DeleteScheduledMessageError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            DeleteScheduledMessageError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            DeleteScheduledMessageError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            DeleteScheduledMessageError::MalformedResponse(ref e) => e.description(),
+            DeleteScheduledMessageError::Unknown(ref s) => s,
+            DeleteScheduledMessageError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            DeleteScheduledMessageError::MalformedResponse(ref e) => Some(e),
+            DeleteScheduledMessageError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve a permalink URL for a specific extant message
+///
+/// Wraps https://api.slack.com/methods/chat.getPermalink
+
+pub fn get_permalink<R>(
+    client: &R,
+    token: &str,
+    request: &GetPermalinkRequest,
+) -> Result<GetPermalinkResponse, GetPermalinkError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("message_ts", &request.message_ts[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("chat.getPermalink");
+    client
+        .send(&url, &params[..])
+        .map_err(GetPermalinkError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<GetPermalinkResponse>(&result).map_err(
+                GetPermalinkError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct GetPermalinkRequest<'a> {
+    /// The ID of the conversation or channel containing the message
+    pub channel: Cow<'a, str>,
+    /// A message's ts value, uniquely identifying it within a channel
+    pub message_ts: Cow<'a, str>,
+}
+
+impl<'a> GetPermalinkRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        message_ts: impl Into<Cow<'a, str>>,
+    ) -> GetPermalinkRequestBuilder<'a> {
+        GetPermalinkRequestBuilder {
+            request: GetPermalinkRequest {
+                channel: channel.into(),
+                message_ts: message_ts.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`GetPermalinkRequest`](struct.GetPermalinkRequest.html), created by `GetPermalinkRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct GetPermalinkRequestBuilder<'a> {
+    request: GetPermalinkRequest<'a>,
+}
+
+impl<'a> GetPermalinkRequestBuilder<'a> {
+    pub fn build(self) -> GetPermalinkRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct GetPermalinkResponse {
+    pub channel: Option<String>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub permalink: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<GetPermalinkResponse, GetPermalinkError<E>>> for GetPermalinkResponse {
+    fn into(self) -> Result<GetPermalinkResponse, GetPermalinkError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum GetPermalinkError<E: Error> {
+    /// The channel was not found.
+    ChannelNotFound,
+    /// No message exists with the requested timestamp for the given channel.
+    MessageNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for GetPermalinkError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => GetPermalinkError::ChannelNotFound,
+            "message_not_found" => GetPermalinkError::MessageNotFound,
+            "not_authed" => GetPermalinkError::NotAuthed,
+            "invalid_auth" => GetPermalinkError::InvalidAuth,
+            "account_inactive" => GetPermalinkError::AccountInactive,
+            "invalid_arg_name" => GetPermalinkError::InvalidArgName,
+            "invalid_array_arg" => GetPermalinkError::InvalidArrayArg,
+            "invalid_charset" => GetPermalinkError::InvalidCharset,
+            "invalid_form_data" => GetPermalinkError::InvalidFormData,
+            "invalid_post_type" => GetPermalinkError::InvalidPostType,
+            "missing_post_type" => GetPermalinkError::MissingPostType,
+            "team_added_to_org" => GetPermalinkError::TeamAddedToOrg,
+            "request_timeout" => GetPermalinkError::RequestTimeout,
+            _ => GetPermalinkError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for GetPermalinkError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for GetPermalinkError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            GetPermalinkError::ChannelNotFound => "channel_not_found: The channel was not found.",
+            GetPermalinkError::MessageNotFound => {
+                "message_not_found: No message exists with the requested timestamp for the given channel."
+            }
+            GetPermalinkError::NotAuthed => "not_authed: No authentication token provided.",
+            GetPermalinkError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            GetPermalinkError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            GetPermalinkError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            GetPermalinkError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            GetPermalinkError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            GetPermalinkError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            GetPermalinkError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            GetPermalinkError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            GetPermalinkError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            GetPermalinkError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            GetPermalinkError::MalformedResponse(ref e) => e.description(),
+            GetPermalinkError::Unknown(ref s) => s,
+            GetPermalinkError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            GetPermalinkError::MalformedResponse(ref e) => Some(e),
+            GetPermalinkError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Share a me message into a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.meMessage
+
+pub fn me_message<R>(
+    client: &R,
+    token: &str,
+    request: &MeMessageRequest,
+) -> Result<MeMessageResponse, MeMessageError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("text", &request.text[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("chat.meMessage");
+    client
+        .send(&url, &params[..])
+        .map_err(MeMessageError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<MeMessageResponse>(&result).map_err(
+                MeMessageError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct MeMessageRequest<'a> {
+    /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name.
+    pub channel: Cow<'a, str>,
+    /// Text of the message to send.
+    pub text: Cow<'a, str>,
+}
+
+impl<'a> MeMessageRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        text: impl Into<Cow<'a, str>>,
+    ) -> MeMessageRequestBuilder<'a> {
+        MeMessageRequestBuilder {
+            request: MeMessageRequest {
+                channel: channel.into(),
+                text: text.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`MeMessageRequest`](struct.MeMessageRequest.html), created by `MeMessageRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct MeMessageRequestBuilder<'a> {
+    request: MeMessageRequest<'a>,
+}
+
+impl<'a> MeMessageRequestBuilder<'a> {
+    pub fn build(self) -> MeMessageRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MeMessageResponse {
+    pub channel: Option<String>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub ts: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<MeMessageResponse, MeMessageError<E>>> for MeMessageResponse {
+    fn into(self) -> Result<MeMessageResponse, MeMessageError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum MeMessageError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Cannot post user messages to a channel they are not in.
+    NotInChannel,
+    /// Channel has been archived.
+    IsArchived,
+    /// Message text is too long
+    MsgTooLong,
+    /// No message text provided
+    NoText,
+    /// Application has posted too many messages, read the Rate Limit documentation for more information
+    RateLimited,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for MeMessageError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => MeMessageError::ChannelNotFound,
+            "not_in_channel" => MeMessageError::NotInChannel,
+            "is_archived" => MeMessageError::IsArchived,
+            "msg_too_long" => MeMessageError::MsgTooLong,
+            "no_text" => MeMessageError::NoText,
+            "rate_limited" => MeMessageError::RateLimited,
+            "not_authed" => MeMessageError::NotAuthed,
+            "invalid_auth" => MeMessageError::InvalidAuth,
+            "account_inactive" => MeMessageError::AccountInactive,
+            "invalid_arg_name" => MeMessageError::InvalidArgName,
+            "invalid_array_arg" => MeMessageError::InvalidArrayArg,
+            "invalid_charset" => MeMessageError::InvalidCharset,
+            "invalid_form_data" => MeMessageError::InvalidFormData,
+            "invalid_post_type" => MeMessageError::InvalidPostType,
+            "missing_post_type" => MeMessageError::MissingPostType,
+            "team_added_to_org" => MeMessageError::TeamAddedToOrg,
+            "request_timeout" => MeMessageError::RequestTimeout,
+            _ => MeMessageError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for MeMessageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for MeMessageError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            MeMessageError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            MeMessageError::NotInChannel => {
+                "not_in_channel: Cannot post user messages to a channel they are not in."
+            }
+            MeMessageError::IsArchived => "is_archived: Channel has been archived.",
+            MeMessageError::MsgTooLong => "msg_too_long: Message text is too long",
+            MeMessageError::NoText => "no_text: No message text provided",
+            MeMessageError::RateLimited => {
+                "rate_limited: Application has posted too many messages, read the Rate Limit documentation for more information"
+            }
+            MeMessageError::NotAuthed => "not_authed: No authentication token provided.",
+            MeMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            MeMessageError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            MeMessageError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            MeMessageError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            MeMessageError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            MeMessageError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            MeMessageError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            MeMessageError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            MeMessageError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            MeMessageError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            MeMessageError::MalformedResponse(ref e) => e.description(),
+            MeMessageError::Unknown(ref s) => s,
+            MeMessageError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            MeMessageError::MalformedResponse(ref e) => Some(e),
+            MeMessageError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Sends an ephemeral message to a user in a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.postEphemeral
+
+pub fn post_ephemeral<R>(
+    client: &R,
+    token: &str,
+    request: &PostEphemeralRequest,
+) -> Result<PostEphemeralResponse, PostEphemeralError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let attachments = request.attachments.as_ref().map(|attachments| {
+        serde_json::to_string(attachments).expect("attachments are always serializable to JSON")
+    });
+    let blocks = request.blocks.as_ref().map(|blocks| {
+        serde_json::to_string(blocks).expect("blocks are always serializable to JSON")
+    });
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("text", &request.text[..])),
+        Some(("user", &request.user[..])),
+        request.as_user.map(|as_user| {
+            ("as_user", if as_user { "1" } else { "0" })
+        }),
+        attachments.as_ref().map(|attachments| {
+            ("attachments", &attachments[..])
+        }),
+        blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
+        request.icon_emoji.as_ref().map(|icon_emoji| ("icon_emoji", &icon_emoji[..])),
+        request.icon_url.as_ref().map(|icon_url| ("icon_url", &icon_url[..])),
+        request.link_names.map(|link_names| {
+            ("link_names", if link_names { "1" } else { "0" })
+        }),
+        request.parse.map(|parse| ("parse", parse.as_str())),
+        request.thread_ts.as_ref().map(|thread_ts| ("thread_ts", &thread_ts[..])),
+        request.username.as_ref().map(|username| ("username", &username[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("chat.postEphemeral");
+    client
+        .send(&url, &params[..])
+        .map_err(PostEphemeralError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<PostEphemeralResponse>(&result).map_err(
+                PostEphemeralError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct PostEphemeralRequest<'a> {
+    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.
+    pub channel: Cow<'a, str>,
+    /// Text of the message to send. This field is usually required, unless you're providing only attachments instead.
+    pub text: Cow<'a, str>,
+    /// id of the user who will receive the ephemeral message. The user should be in the channel specified by the channel argument.
+    pub user: Cow<'a, str>,
+    /// Pass true to post the message as the authed user. Defaults to true if the chat:write:bot scope is not included. Otherwise, defaults to false.
+    pub as_user: Option<bool>,
+    /// Structured message attachments.
+    pub attachments: Option<Cow<'a, [::attachments::Attachment]>>,
+    /// Structured Block Kit layout blocks.
+    pub blocks: Option<Cow<'a, [::blocks::Block]>>,
+    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub icon_emoji: Option<Cow<'a, str>>,
+    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub icon_url: Option<Cow<'a, str>>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// Change how messages are treated. Defaults to none.
+    pub parse: Option<Parse>,
+    /// Provide another message's ts value to post this message in a thread. Avoid using a reply's ts value; use its parent's value instead. Ephemeral messages in threads are only shown if there is already an active thread.
+    pub thread_ts: Option<Cow<'a, str>>,
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub username: Option<Cow<'a, str>>,
+}
+
+impl<'a> PostEphemeralRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        text: impl Into<Cow<'a, str>>,
+        user: impl Into<Cow<'a, str>>,
+    ) -> PostEphemeralRequestBuilder<'a> {
+        PostEphemeralRequestBuilder {
+            request: PostEphemeralRequest {
+                channel: channel.into(),
+                text: text.into(),
+                user: user.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`PostEphemeralRequest`](struct.PostEphemeralRequest.html), created by `PostEphemeralRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct PostEphemeralRequestBuilder<'a> {
+    request: PostEphemeralRequest<'a>,
+}
+
+impl<'a> PostEphemeralRequestBuilder<'a> {
+    /// Pass true to post the message as the authed user. Defaults to true if the chat:write:bot scope is not included. Otherwise, defaults to false.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.request.as_user = Some(as_user);
+        self
+    }
+
+    /// Structured message attachments.
+    pub fn attachments(
+        mut self,
+        attachments: impl Into<Cow<'a, [::attachments::Attachment]>>,
+    ) -> Self {
+        self.request.attachments = Some(attachments.into());
+        self
+    }
+
+    /// Structured Block Kit layout blocks.
+    pub fn blocks(mut self, blocks: impl Into<Cow<'a, [::blocks::Block]>>) -> Self {
+        self.request.blocks = Some(blocks.into());
+        self
+    }
+
+    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub fn icon_emoji(mut self, icon_emoji: impl Into<Cow<'a, str>>) -> Self {
+        self.request.icon_emoji = Some(icon_emoji.into());
+        self
+    }
+
+    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub fn icon_url(mut self, icon_url: impl Into<Cow<'a, str>>) -> Self {
+        self.request.icon_url = Some(icon_url.into());
+        self
+    }
+
+    /// Find and link channel names and usernames.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.request.link_names = Some(link_names);
+        self
+    }
+
+    /// Change how messages are treated. Defaults to none.
+    pub fn parse(mut self, parse: Parse) -> Self {
+        self.request.parse = Some(parse);
+        self
+    }
+
+    /// Provide another message's ts value to post this message in a thread. Avoid using a reply's ts value; use its parent's value instead. Ephemeral messages in threads are only shown if there is already an active thread.
+    pub fn thread_ts(mut self, thread_ts: impl Into<Cow<'a, str>>) -> Self {
+        self.request.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub fn username(mut self, username: impl Into<Cow<'a, str>>) -> Self {
+        self.request.username = Some(username.into());
+        self
+    }
+
+    pub fn build(self) -> PostEphemeralRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PostEphemeralResponse {
+    error: Option<String>,
+    pub message_ts: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<PostEphemeralResponse, PostEphemeralError<E>>>
+    for PostEphemeralResponse {
+    fn into(self) -> Result<PostEphemeralResponse, PostEphemeralError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PostEphemeralError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has been archived.
+    IsArchived,
+    /// Intended recipient is not in the specified channel.
+    UserNotInChannel,
+    /// Message text is too long
+    MsgTooLong,
+    /// No message text provided
+    NoText,
+    /// A workspace preference prevents the authenticated user from posting.
+    RestrictedAction,
+    /// Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.
+    TooManyAttachments,
+    /// The blocks provided were not valid Block Kit blocks.
+    InvalidBlocks,
+    /// The blocks provided were not a valid JSON array.
+    InvalidBlocksFormat,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PostEphemeralError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => PostEphemeralError::ChannelNotFound,
+            "is_archived" => PostEphemeralError::IsArchived,
+            "user_not_in_channel" => PostEphemeralError::UserNotInChannel,
+            "msg_too_long" => PostEphemeralError::MsgTooLong,
+            "no_text" => PostEphemeralError::NoText,
+            "restricted_action" => PostEphemeralError::RestrictedAction,
+            "too_many_attachments" => PostEphemeralError::TooManyAttachments,
+            "invalid_blocks" => PostEphemeralError::InvalidBlocks,
+            "invalid_blocks_format" => PostEphemeralError::InvalidBlocksFormat,
+            "not_authed" => PostEphemeralError::NotAuthed,
+            "invalid_auth" => PostEphemeralError::InvalidAuth,
+            "account_inactive" => PostEphemeralError::AccountInactive,
+            "invalid_arg_name" => PostEphemeralError::InvalidArgName,
+            "invalid_array_arg" => PostEphemeralError::InvalidArrayArg,
+            "invalid_charset" => PostEphemeralError::InvalidCharset,
+            "invalid_form_data" => PostEphemeralError::InvalidFormData,
+            "invalid_post_type" => PostEphemeralError::InvalidPostType,
+            "missing_post_type" => PostEphemeralError::MissingPostType,
+            "team_added_to_org" => PostEphemeralError::TeamAddedToOrg,
+            "request_timeout" => PostEphemeralError::RequestTimeout,
+            _ => PostEphemeralError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for PostEphemeralError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for PostEphemeralError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            PostEphemeralError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            PostEphemeralError::IsArchived => "is_archived: Channel has been archived.",
+            PostEphemeralError::UserNotInChannel => {
+                "user_not_in_channel: Intended recipient is not in the specified channel."
+            }
+            PostEphemeralError::MsgTooLong => "msg_too_long: Message text is too long",
+            PostEphemeralError::NoText => "no_text: No message text provided",
+            PostEphemeralError::RestrictedAction => {
+                "restricted_action: A workspace preference prevents the authenticated user from posting."
+            }
+            PostEphemeralError::TooManyAttachments => {
+                "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message."
+            }
+            PostEphemeralError::InvalidBlocks => {
+                "invalid_blocks: The blocks provided were not valid Block Kit blocks."
+            }
+            PostEphemeralError::InvalidBlocksFormat => {
+                "invalid_blocks_format: The blocks provided were not a valid JSON array."
+            }
+            PostEphemeralError::NotAuthed => "not_authed: No authentication token provided.",
+            PostEphemeralError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            PostEphemeralError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            PostEphemeralError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            PostEphemeralError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            PostEphemeralError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            PostEphemeralError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            PostEphemeralError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            PostEphemeralError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            PostEphemeralError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            PostEphemeralError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            PostEphemeralError::MalformedResponse(ref e) => e.description(),
+            PostEphemeralError::Unknown(ref s) => s,
+            PostEphemeralError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            PostEphemeralError::MalformedResponse(ref e) => Some(e),
+            PostEphemeralError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Sends a message to a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.postMessage
+
+pub fn post_message<R>(
+    client: &R,
+    token: &str,
+    request: &PostMessageRequest,
+) -> Result<PostMessageResponse, PostMessageError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let attachments = request.attachments.as_ref().map(|attachments| {
+        serde_json::to_string(attachments).expect("attachments are always serializable to JSON")
+    });
+    let blocks = request.blocks.as_ref().map(|blocks| {
+        serde_json::to_string(blocks).expect("blocks are always serializable to JSON")
+    });
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("text", &request.text[..])),
+        request.parse.map(|parse| ("parse", parse.as_str())),
+        request.link_names.map(|link_names| {
+            ("link_names", if link_names { "1" } else { "0" })
+        }),
+        attachments.as_ref().map(|attachments| {
+            ("attachments", &attachments[..])
+        }),
+        blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
+        request.unfurl_links.map(|unfurl_links| {
+            ("unfurl_links", if unfurl_links { "1" } else { "0" })
+        }),
+        request.unfurl_media.map(|unfurl_media| {
+            ("unfurl_media", if unfurl_media { "1" } else { "0" })
+        }),
+        request.username.as_ref().map(|username| ("username", &username[..])),
+        request.as_user.map(|as_user| {
+            ("as_user", if as_user { "1" } else { "0" })
+        }),
+        request.icon_url.as_ref().map(|icon_url| ("icon_url", &icon_url[..])),
+        request.icon_emoji.as_ref().map(|icon_emoji| ("icon_emoji", &icon_emoji[..])),
+        request.thread_ts.as_ref().map(|thread_ts| ("thread_ts", &thread_ts[..])),
+        request.reply_broadcast.map(|reply_broadcast| {
+            ("reply_broadcast", if reply_broadcast { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("chat.postMessage");
+    client
+        .send(&url, &params[..])
+        .map_err(PostMessageError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<PostMessageResponse>(&result)
+                .map_err(PostMessageError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct PostMessageRequest<'a> {
+    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See below for more details.
+    pub channel: Cow<'a, str>,
+    /// Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments instead.
+    pub text: Cow<'a, str>,
+    /// Change how messages are treated. Defaults to none. See below.
+    pub parse: Option<Parse>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// Structured message attachments.
+    pub attachments: Option<Cow<'a, [::attachments::Attachment]>>,
+    /// Structured Block Kit layout blocks.
+    pub blocks: Option<Cow<'a, [::blocks::Block]>>,
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub unfurl_links: Option<bool>,
+    /// Pass false to disable unfurling of media content.
+    pub unfurl_media: Option<bool>,
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub username: Option<Cow<'a, str>>,
+    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.
+    pub as_user: Option<bool>,
+    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub icon_url: Option<Cow<'a, str>>,
+    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub icon_emoji: Option<Cow<'a, str>>,
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub thread_ts: Option<Cow<'a, str>>,
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
+    pub reply_broadcast: Option<bool>,
+}
+
+impl<'a> PostMessageRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        text: impl Into<Cow<'a, str>>,
+    ) -> PostMessageRequestBuilder<'a> {
+        PostMessageRequestBuilder {
+            request: PostMessageRequest {
+                channel: channel.into(),
+                text: text.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`PostMessageRequest`](struct.PostMessageRequest.html), created by `PostMessageRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct PostMessageRequestBuilder<'a> {
+    request: PostMessageRequest<'a>,
+}
+
+impl<'a> PostMessageRequestBuilder<'a> {
+    /// Change how messages are treated. Defaults to none. See below.
+    pub fn parse(mut self, parse: Parse) -> Self {
+        self.request.parse = Some(parse);
+        self
+    }
+
+    /// Find and link channel names and usernames.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.request.link_names = Some(link_names);
+        self
+    }
+
+    /// Structured message attachments.
+    pub fn attachments(
+        mut self,
+        attachments: impl Into<Cow<'a, [::attachments::Attachment]>>,
+    ) -> Self {
+        self.request.attachments = Some(attachments.into());
+        self
+    }
+
+    /// Structured Block Kit layout blocks.
+    pub fn blocks(mut self, blocks: impl Into<Cow<'a, [::blocks::Block]>>) -> Self {
+        self.request.blocks = Some(blocks.into());
+        self
+    }
+
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+        self.request.unfurl_links = Some(unfurl_links);
+        self
+    }
+
+    /// Pass false to disable unfurling of media content.
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+        self.request.unfurl_media = Some(unfurl_media);
+        self
+    }
+
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn username(mut self, username: impl Into<Cow<'a, str>>) -> Self {
+        self.request.username = Some(username.into());
+        self
+    }
+
+    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.request.as_user = Some(as_user);
+        self
+    }
+
+    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn icon_url(mut self, icon_url: impl Into<Cow<'a, str>>) -> Self {
+        self.request.icon_url = Some(icon_url.into());
+        self
+    }
+
+    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn icon_emoji(mut self, icon_emoji: impl Into<Cow<'a, str>>) -> Self {
+        self.request.icon_emoji = Some(icon_emoji.into());
+        self
+    }
+
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub fn thread_ts(mut self, thread_ts: impl Into<Cow<'a, str>>) -> Self {
+        self.request.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
+    pub fn reply_broadcast(mut self, reply_broadcast: bool) -> Self {
+        self.request.reply_broadcast = Some(reply_broadcast);
+        self
+    }
+
+    pub fn build(self) -> PostMessageRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PostMessageResponse {
+    pub channel: Option<String>,
+    error: Option<String>,
+    pub message: Option<::Message>,
+    #[serde(default)]
+    ok: bool,
+    pub ts: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<PostMessageResponse, PostMessageError<E>>> for PostMessageResponse {
+    fn into(self) -> Result<PostMessageResponse, PostMessageError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PostMessageError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Cannot post user messages to a channel they are not in.
+    NotInChannel,
+    /// Channel has been archived.
+    IsArchived,
+    /// Message text is too long
+    MsgTooLong,
+    /// No message text provided
+    NoText,
+    /// Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.
+    TooManyAttachments,
+    /// Application has posted too many messages, read the Rate Limit documentation for more information
+    RateLimited,
+    /// The blocks provided were not valid Block Kit blocks.
+    InvalidBlocks,
+    /// The blocks provided were not a valid JSON array.
+    InvalidBlocksFormat,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PostMessageError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => PostMessageError::ChannelNotFound,
+            "not_in_channel" => PostMessageError::NotInChannel,
+            "is_archived" => PostMessageError::IsArchived,
+            "msg_too_long" => PostMessageError::MsgTooLong,
+            "no_text" => PostMessageError::NoText,
+            "too_many_attachments" => PostMessageError::TooManyAttachments,
+            "rate_limited" => PostMessageError::RateLimited,
+            "invalid_blocks" => PostMessageError::InvalidBlocks,
+            "invalid_blocks_format" => PostMessageError::InvalidBlocksFormat,
+            "not_authed" => PostMessageError::NotAuthed,
+            "invalid_auth" => PostMessageError::InvalidAuth,
+            "account_inactive" => PostMessageError::AccountInactive,
+            "invalid_arg_name" => PostMessageError::InvalidArgName,
+            "invalid_array_arg" => PostMessageError::InvalidArrayArg,
+            "invalid_charset" => PostMessageError::InvalidCharset,
+            "invalid_form_data" => PostMessageError::InvalidFormData,
+            "invalid_post_type" => PostMessageError::InvalidPostType,
+            "missing_post_type" => PostMessageError::MissingPostType,
+            "team_added_to_org" => PostMessageError::TeamAddedToOrg,
+            "request_timeout" => PostMessageError::RequestTimeout,
+            _ => PostMessageError::Unknown(s.to_owned()),
         }
     }
 }
 
-impl<E: Error> fmt::Display for MeMessageError<E> {
+impl<E: Error> fmt::Display for PostMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
 
-impl<E: Error> Error for MeMessageError<E> {
+impl<E: Error> Error for PostMessageError<E> {
     fn description(&self) -> &str {
         match *self {
-            MeMessageError::ChannelNotFound => {
+            PostMessageError::ChannelNotFound => {
                 "channel_not_found: Value passed for channel was invalid."
             }
-            MeMessageError::NotInChannel => {
+            PostMessageError::NotInChannel => {
                 "not_in_channel: Cannot post user messages to a channel they are not in."
             }
-            MeMessageError::IsArchived => "is_archived: Channel has been archived.",
-            MeMessageError::MsgTooLong => "msg_too_long: Message text is too long",
-            MeMessageError::NoText => "no_text: No message text provided",
-            MeMessageError::RateLimited => {
+            PostMessageError::IsArchived => "is_archived: Channel has been archived.",
+            PostMessageError::MsgTooLong => "msg_too_long: Message text is too long",
+            PostMessageError::NoText => "no_text: No message text provided",
+            PostMessageError::TooManyAttachments => {
+                "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message."
+            }
+            PostMessageError::RateLimited => {
                 "rate_limited: Application has posted too many messages, read the Rate Limit documentation for more information"
             }
-            MeMessageError::NotAuthed => "not_authed: No authentication token provided.",
-            MeMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            MeMessageError::AccountInactive => {
+            PostMessageError::InvalidBlocks => {
+                "invalid_blocks: The blocks provided were not valid Block Kit blocks."
+            }
+            PostMessageError::InvalidBlocksFormat => {
+                "invalid_blocks_format: The blocks provided were not a valid JSON array."
+            }
+            PostMessageError::NotAuthed => "not_authed: No authentication token provided.",
+            PostMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            PostMessageError::AccountInactive => {
                 "account_inactive: Authentication token is for a deleted user or team."
             }
-            MeMessageError::InvalidArgName => {
+            PostMessageError::InvalidArgName => {
                 "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
             }
-            MeMessageError::InvalidArrayArg => {
+            PostMessageError::InvalidArrayArg => {
                 "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
             }
-            MeMessageError::InvalidCharset => {
+            PostMessageError::InvalidCharset => {
                 "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
             }
-            MeMessageError::InvalidFormData => {
+            PostMessageError::InvalidFormData => {
                 "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
             }
-            MeMessageError::InvalidPostType => {
+            PostMessageError::InvalidPostType => {
                 "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
             }
-            MeMessageError::MissingPostType => {
+            PostMessageError::MissingPostType => {
                 "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
             }
-            MeMessageError::TeamAddedToOrg => {
+            PostMessageError::TeamAddedToOrg => {
                 "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
             }
-            MeMessageError::RequestTimeout => {
+            PostMessageError::RequestTimeout => {
                 "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
             }
-            MeMessageError::MalformedResponse(ref e) => e.description(),
-            MeMessageError::Unknown(ref s) => s,
-            MeMessageError::Client(ref inner) => inner.description(),
+            PostMessageError::MalformedResponse(ref e) => e.description(),
+            PostMessageError::Unknown(ref s) => s,
+            PostMessageError::Client(ref inner) => inner.description(),
         }
     }
 
     fn cause(&self) -> Option<&Error> {
         match *self {
-            MeMessageError::MalformedResponse(ref e) => Some(e),
-            MeMessageError::Client(ref inner) => Some(inner),
+            PostMessageError::MalformedResponse(ref e) => Some(e),
+            PostMessageError::Client(ref inner) => Some(inner),
             _ => None,
         }
     }
 }
 
-/// Sends a message to a channel.
+/// Schedules a message to be sent to a channel.
 ///
-/// Wraps https://api.slack.com/methods/chat.postMessage
+/// Wraps https://api.slack.com/methods/chat.scheduleMessage
 
-pub fn post_message<R>(
+pub fn schedule_message<R>(
     client: &R,
     token: &str,
-    request: &PostMessageRequest,
-) -> Result<PostMessageResponse, PostMessageError<R::Error>>
+    request: &ScheduleMessageRequest,
+) -> Result<ScheduleMessageResponse, ScheduleMessageError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-
+    let post_at = request.post_at.to_string();
+    let attachments = request.attachments.as_ref().map(|attachments| {
+        serde_json::to_string(attachments).expect("attachments are always serializable to JSON")
+    });
+    let blocks = request.blocks.as_ref().map(|blocks| {
+        serde_json::to_string(blocks).expect("blocks are always serializable to JSON")
+    });
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("text", request.text)),
-        request.parse.map(|parse| ("parse", parse)),
+        Some(("channel", &request.channel[..])),
+        Some(("post_at", &post_at[..])),
+        Some(("text", &request.text[..])),
+        request.as_user.map(|as_user| {
+            ("as_user", if as_user { "1" } else { "0" })
+        }),
+        attachments.as_ref().map(|attachments| {
+            ("attachments", &attachments[..])
+        }),
+        blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
         request.link_names.map(|link_names| {
             ("link_names", if link_names { "1" } else { "0" })
         }),
-        request.attachments.map(|attachments| {
-            ("attachments", attachments)
+        request.parse.map(|parse| ("parse", parse.as_str())),
+        request.reply_broadcast.map(|reply_broadcast| {
+            ("reply_broadcast", if reply_broadcast { "1" } else { "0" })
         }),
+        request.thread_ts.as_ref().map(|thread_ts| ("thread_ts", &thread_ts[..])),
         request.unfurl_links.map(|unfurl_links| {
             ("unfurl_links", if unfurl_links { "1" } else { "0" })
         }),
         request.unfurl_media.map(|unfurl_media| {
             ("unfurl_media", if unfurl_media { "1" } else { "0" })
         }),
-        request.username.map(|username| ("username", username)),
-        request.as_user.map(|as_user| {
-            ("as_user", if as_user { "1" } else { "0" })
-        }),
-        request.icon_url.map(|icon_url| ("icon_url", icon_url)),
-        request.icon_emoji.map(
-            |icon_emoji| ("icon_emoji", icon_emoji)
-        ),
-        request.thread_ts.map(|thread_ts| ("thread_ts", thread_ts)),
-        request.reply_broadcast.map(|reply_broadcast| {
-            ("reply_broadcast", if reply_broadcast { "1" } else { "0" })
-        }),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("chat.postMessage");
+    let url = ::get_slack_url_for_method("chat.scheduleMessage");
     client
         .send(&url, &params[..])
-        .map_err(PostMessageError::Client)
+        .map_err(ScheduleMessageError::Client)
         .and_then(|result| {
-            serde_json::from_str::<PostMessageResponse>(&result)
-                .map_err(PostMessageError::MalformedResponse)
+            serde_json::from_str::<ScheduleMessageResponse>(&result).map_err(
+                ScheduleMessageError::MalformedResponse,
+            )
         })
         .and_then(|o| o.into())
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct PostMessageRequest<'a> {
-    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See below for more details.
-    pub channel: &'a str,
-    /// Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments instead.
-    pub text: &'a str,
-    /// Change how messages are treated. Defaults to none. See below.
-    pub parse: Option<&'a str>,
+pub struct ScheduleMessageRequest<'a> {
+    /// Channel, private group, or DM channel to send message to. Can be an encoded ID, or a name.
+    pub channel: Cow<'a, str>,
+    /// Unix EPOCH timestamp of time in future to send the message.
+    pub post_at: u32,
+    /// Text of the message to send. This field is usually required, unless you're providing only attachments instead.
+    pub text: Cow<'a, str>,
+    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false.
+    pub as_user: Option<bool>,
+    /// Structured message attachments.
+    pub attachments: Option<Cow<'a, [::attachments::Attachment]>>,
+    /// Structured Block Kit layout blocks.
+    pub blocks: Option<Cow<'a, [::blocks::Block]>>,
     /// Find and link channel names and usernames.
     pub link_names: Option<bool>,
-    /// Structured message attachments.
-    pub attachments: Option<&'a str>,
+    /// Change how messages are treated. Defaults to none.
+    pub parse: Option<Parse>,
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
+    pub reply_broadcast: Option<bool>,
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub thread_ts: Option<Cow<'a, str>>,
     /// Pass true to enable unfurling of primarily text-based content.
     pub unfurl_links: Option<bool>,
     /// Pass false to disable unfurling of media content.
     pub unfurl_media: Option<bool>,
-    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub username: Option<&'a str>,
-    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.
-    pub as_user: Option<bool>,
-    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub icon_url: Option<&'a str>,
-    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
-    pub icon_emoji: Option<&'a str>,
-    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
-    pub thread_ts: Option<&'a str>,
+}
+
+impl<'a> ScheduleMessageRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        post_at: u32,
+        text: impl Into<Cow<'a, str>>,
+    ) -> ScheduleMessageRequestBuilder<'a> {
+        ScheduleMessageRequestBuilder {
+            request: ScheduleMessageRequest {
+                channel: channel.into(),
+                post_at,
+                text: text.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ScheduleMessageRequest`](struct.ScheduleMessageRequest.html), created by `ScheduleMessageRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ScheduleMessageRequestBuilder<'a> {
+    request: ScheduleMessageRequest<'a>,
+}
+
+impl<'a> ScheduleMessageRequestBuilder<'a> {
+    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.request.as_user = Some(as_user);
+        self
+    }
+
+    /// Structured message attachments.
+    pub fn attachments(
+        mut self,
+        attachments: impl Into<Cow<'a, [::attachments::Attachment]>>,
+    ) -> Self {
+        self.request.attachments = Some(attachments.into());
+        self
+    }
+
+    /// Structured Block Kit layout blocks.
+    pub fn blocks(mut self, blocks: impl Into<Cow<'a, [::blocks::Block]>>) -> Self {
+        self.request.blocks = Some(blocks.into());
+        self
+    }
+
+    /// Find and link channel names and usernames.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.request.link_names = Some(link_names);
+        self
+    }
+
+    /// Change how messages are treated. Defaults to none.
+    pub fn parse(mut self, parse: Parse) -> Self {
+        self.request.parse = Some(parse);
+        self
+    }
+
     /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
-    pub reply_broadcast: Option<bool>,
+    pub fn reply_broadcast(mut self, reply_broadcast: bool) -> Self {
+        self.request.reply_broadcast = Some(reply_broadcast);
+        self
+    }
+
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub fn thread_ts(mut self, thread_ts: impl Into<Cow<'a, str>>) -> Self {
+        self.request.thread_ts = Some(thread_ts.into());
+        self
+    }
+
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+        self.request.unfurl_links = Some(unfurl_links);
+        self
+    }
+
+    /// Pass false to disable unfurling of media content.
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+        self.request.unfurl_media = Some(unfurl_media);
+        self
+    }
+
+    pub fn build(self) -> ScheduleMessageRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct PostMessageResponse {
+pub struct ScheduleMessageResponse {
     pub channel: Option<String>,
     error: Option<String>,
     pub message: Option<::Message>,
     #[serde(default)]
     ok: bool,
-    pub ts: Option<String>,
+    pub post_at: Option<i32>,
+    pub scheduled_message_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<PostMessageResponse, PostMessageError<E>>> for PostMessageResponse {
-    fn into(self) -> Result<PostMessageResponse, PostMessageError<E>> {
+impl<E: Error> Into<Result<ScheduleMessageResponse, ScheduleMessageError<E>>>
+    for ScheduleMessageResponse {
+    fn into(self) -> Result<ScheduleMessageResponse, ScheduleMessageError<E>> {
         if self.ok {
             Ok(self)
         } else {
@@ -498,7 +1816,13 @@ impl<E: Error> Into<Result<PostMessageResponse, PostMessageError<E>>> for PostMe
     }
 }
 #[derive(Debug)]
-pub enum PostMessageError<E: Error> {
+pub enum ScheduleMessageError<E: Error> {
+    /// Value passed for post_at was invalid.
+    InvalidTime,
+    /// Value passed for post_at is in the past.
+    TimeInPast,
+    /// Value passed for post_at is more than 120 days in the future.
+    TimeTooFar,
     /// Value passed for channel was invalid.
     ChannelNotFound,
     /// Cannot post user messages to a channel they are not in.
@@ -509,10 +1833,14 @@ pub enum PostMessageError<E: Error> {
     MsgTooLong,
     /// No message text provided
     NoText,
+    /// A workspace preference prevents the authenticated user from posting.
+    RestrictedAction,
     /// Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.
     TooManyAttachments,
-    /// Application has posted too many messages, read the Rate Limit documentation for more information
-    RateLimited,
+    /// The blocks provided were not valid Block Kit blocks.
+    InvalidBlocks,
+    /// The blocks provided were not a valid JSON array.
+    InvalidBlocksFormat,
     /// No authentication token provided.
     NotAuthed,
     /// Invalid authentication token.
@@ -543,95 +1871,115 @@ pub enum PostMessageError<E: Error> {
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for PostMessageError<E> {
+impl<'a, E: Error> From<&'a str> for ScheduleMessageError<E> {
     fn from(s: &'a str) -> Self {
         match s {
-            "channel_not_found" => PostMessageError::ChannelNotFound,
-            "not_in_channel" => PostMessageError::NotInChannel,
-            "is_archived" => PostMessageError::IsArchived,
-            "msg_too_long" => PostMessageError::MsgTooLong,
-            "no_text" => PostMessageError::NoText,
-            "too_many_attachments" => PostMessageError::TooManyAttachments,
-            "rate_limited" => PostMessageError::RateLimited,
-            "not_authed" => PostMessageError::NotAuthed,
-            "invalid_auth" => PostMessageError::InvalidAuth,
-            "account_inactive" => PostMessageError::AccountInactive,
-            "invalid_arg_name" => PostMessageError::InvalidArgName,
-            "invalid_array_arg" => PostMessageError::InvalidArrayArg,
-            "invalid_charset" => PostMessageError::InvalidCharset,
-            "invalid_form_data" => PostMessageError::InvalidFormData,
-            "invalid_post_type" => PostMessageError::InvalidPostType,
-            "missing_post_type" => PostMessageError::MissingPostType,
-            "team_added_to_org" => PostMessageError::TeamAddedToOrg,
-            "request_timeout" => PostMessageError::RequestTimeout,
-            _ => PostMessageError::Unknown(s.to_owned()),
+            "invalid_time" => ScheduleMessageError::InvalidTime,
+            "time_in_past" => ScheduleMessageError::TimeInPast,
+            "time_too_far" => ScheduleMessageError::TimeTooFar,
+            "channel_not_found" => ScheduleMessageError::ChannelNotFound,
+            "not_in_channel" => ScheduleMessageError::NotInChannel,
+            "is_archived" => ScheduleMessageError::IsArchived,
+            "msg_too_long" => ScheduleMessageError::MsgTooLong,
+            "no_text" => ScheduleMessageError::NoText,
+            "restricted_action" => ScheduleMessageError::RestrictedAction,
+            "too_many_attachments" => ScheduleMessageError::TooManyAttachments,
+            "invalid_blocks" => ScheduleMessageError::InvalidBlocks,
+            "invalid_blocks_format" => ScheduleMessageError::InvalidBlocksFormat,
+            "not_authed" => ScheduleMessageError::NotAuthed,
+            "invalid_auth" => ScheduleMessageError::InvalidAuth,
+            "account_inactive" => ScheduleMessageError::AccountInactive,
+            "invalid_arg_name" => ScheduleMessageError::InvalidArgName,
+            "invalid_array_arg" => ScheduleMessageError::InvalidArrayArg,
+            "invalid_charset" => ScheduleMessageError::InvalidCharset,
+            "invalid_form_data" => ScheduleMessageError::InvalidFormData,
+            "invalid_post_type" => ScheduleMessageError::InvalidPostType,
+            "missing_post_type" => ScheduleMessageError::MissingPostType,
+            "team_added_to_org" => ScheduleMessageError::TeamAddedToOrg,
+            "request_timeout" => ScheduleMessageError::RequestTimeout,
+            _ => ScheduleMessageError::Unknown(s.to_owned()),
         }
     }
 }
 
-impl<E: Error> fmt::Display for PostMessageError<E> {
+impl<E: Error> fmt::Display for ScheduleMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
 
-impl<E: Error> Error for PostMessageError<E> {
+impl<E: Error> Error for ScheduleMessageError<E> {
     fn description(&self) -> &str {
         match *self {
-            PostMessageError::ChannelNotFound => {
+            ScheduleMessageError::InvalidTime => {
+                "invalid_time: Value passed for post_at was invalid."
+            }
+            ScheduleMessageError::TimeInPast => {
+                "time_in_past: Value passed for post_at is in the past."
+            }
+            ScheduleMessageError::TimeTooFar => {
+                "time_too_far: Value passed for post_at is more than 120 days in the future."
+            }
+            ScheduleMessageError::ChannelNotFound => {
                 "channel_not_found: Value passed for channel was invalid."
             }
-            PostMessageError::NotInChannel => {
+            ScheduleMessageError::NotInChannel => {
                 "not_in_channel: Cannot post user messages to a channel they are not in."
             }
-            PostMessageError::IsArchived => "is_archived: Channel has been archived.",
-            PostMessageError::MsgTooLong => "msg_too_long: Message text is too long",
-            PostMessageError::NoText => "no_text: No message text provided",
-            PostMessageError::TooManyAttachments => {
+            ScheduleMessageError::IsArchived => "is_archived: Channel has been archived.",
+            ScheduleMessageError::MsgTooLong => "msg_too_long: Message text is too long",
+            ScheduleMessageError::NoText => "no_text: No message text provided",
+            ScheduleMessageError::RestrictedAction => {
+                "restricted_action: A workspace preference prevents the authenticated user from posting."
+            }
+            ScheduleMessageError::TooManyAttachments => {
                 "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message."
             }
-            PostMessageError::RateLimited => {
-                "rate_limited: Application has posted too many messages, read the Rate Limit documentation for more information"
+            ScheduleMessageError::InvalidBlocks => {
+                "invalid_blocks: The blocks provided were not valid Block Kit blocks."
             }
-            PostMessageError::NotAuthed => "not_authed: No authentication token provided.",
-            PostMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            PostMessageError::AccountInactive => {
+            ScheduleMessageError::InvalidBlocksFormat => {
+                "invalid_blocks_format: The blocks provided were not a valid JSON array."
+            }
+            ScheduleMessageError::NotAuthed => "not_authed: No authentication token provided.",
+            ScheduleMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            ScheduleMessageError::AccountInactive => {
                 "account_inactive: Authentication token is for a deleted user or team."
             }
-            PostMessageError::InvalidArgName => {
+            ScheduleMessageError::InvalidArgName => {
                 "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
             }
-            PostMessageError::InvalidArrayArg => {
+            ScheduleMessageError::InvalidArrayArg => {
                 "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
             }
-            PostMessageError::InvalidCharset => {
+            ScheduleMessageError::InvalidCharset => {
                 "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
             }
-            PostMessageError::InvalidFormData => {
+            ScheduleMessageError::InvalidFormData => {
                 "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
             }
-            PostMessageError::InvalidPostType => {
+            ScheduleMessageError::InvalidPostType => {
                 "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
             }
-            PostMessageError::MissingPostType => {
+            ScheduleMessageError::MissingPostType => {
                 "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
             }
-            PostMessageError::TeamAddedToOrg => {
+            ScheduleMessageError::TeamAddedToOrg => {
                 "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
             }
-            PostMessageError::RequestTimeout => {
+            ScheduleMessageError::RequestTimeout => {
                 "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
             }
-            PostMessageError::MalformedResponse(ref e) => e.description(),
-            PostMessageError::Unknown(ref s) => s,
-            PostMessageError::Client(ref inner) => inner.description(),
+            ScheduleMessageError::MalformedResponse(ref e) => e.description(),
+            ScheduleMessageError::Unknown(ref s) => s,
+            ScheduleMessageError::Client(ref inner) => inner.description(),
         }
     }
 
     fn cause(&self) -> Option<&Error> {
         match *self {
-            PostMessageError::MalformedResponse(ref e) => Some(e),
-            PostMessageError::Client(ref inner) => Some(inner),
+            ScheduleMessageError::MalformedResponse(ref e) => Some(e),
+            ScheduleMessageError::Client(ref inner) => Some(inner),
             _ => None,
         }
     }
@@ -649,12 +1997,17 @@ pub fn unfurl<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let unfurls = request
+        .unfurls
+        .iter()
+        .map(|(url, value)| (url.as_str(), value))
+        .collect::<HashMap<_, _>>();
+    let unfurls = serde_json::to_string(&unfurls).expect("unfurls are always serializable to JSON");
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
-        Some(("unfurls", request.unfurls)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
+        Some(("unfurls", &unfurls[..])),
         request.user_auth_required.map(|user_auth_required| {
             (
                 "user_auth_required",
@@ -676,20 +2029,58 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct UnfurlRequest<'a> {
     /// Channel ID of the message
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Timestamp of the message to add unfurl behavior to
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
     /// JSON mapping a set of URLs from the message to their unfurl attachments
-    pub unfurls: &'a str,
+    pub unfurls: HashMap<::Url, ::attachments::Attachment>,
     /// Set to true or 1 to indicate the user must install your Slack app to trigger unfurls for this domain
     pub user_auth_required: Option<bool>,
 }
 
+impl<'a> UnfurlRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        ts: impl Into<Cow<'a, str>>,
+        unfurls: HashMap<::Url, ::attachments::Attachment>,
+    ) -> UnfurlRequestBuilder<'a> {
+        UnfurlRequestBuilder {
+            request: UnfurlRequest {
+                channel: channel.into(),
+                ts: ts.into(),
+                unfurls,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`UnfurlRequest`](struct.UnfurlRequest.html), created by `UnfurlRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct UnfurlRequestBuilder<'a> {
+    request: UnfurlRequest<'a>,
+}
+
+impl<'a> UnfurlRequestBuilder<'a> {
+    /// Set to true or 1 to indicate the user must install your Slack app to trigger unfurls for this domain
+    pub fn user_auth_required(mut self, user_auth_required: bool) -> Self {
+        self.request.user_auth_required = Some(user_auth_required);
+        self
+    }
+
+    pub fn build(self) -> UnfurlRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UnfurlResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -822,16 +2213,22 @@ pub fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let attachments = request.attachments.as_ref().map(|attachments| {
+        serde_json::to_string(attachments).expect("attachments are always serializable to JSON")
+    });
+    let blocks = request.blocks.as_ref().map(|blocks| {
+        serde_json::to_string(blocks).expect("blocks are always serializable to JSON")
+    });
     let params = vec![
         Some(("token", token)),
-        Some(("ts", request.ts)),
-        Some(("channel", request.channel)),
-        Some(("text", request.text)),
-        request.attachments.map(|attachments| {
-            ("attachments", attachments)
+        Some(("ts", &request.ts[..])),
+        Some(("channel", &request.channel[..])),
+        Some(("text", &request.text[..])),
+        attachments.as_ref().map(|attachments| {
+            ("attachments", &attachments[..])
         }),
-        request.parse.map(|parse| ("parse", parse)),
+        blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
+        request.parse.map(|parse| ("parse", parse.as_str())),
         request.link_names.map(|link_names| {
             ("link_names", if link_names { "1" } else { "0" })
         }),
@@ -853,21 +2250,86 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct UpdateRequest<'a> {
     /// Timestamp of the message to be updated.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
     /// Channel containing the message to be updated.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// New text for the message, using the default formatting rules.
-    pub text: &'a str,
+    pub text: Cow<'a, str>,
     /// Structured message attachments.
-    pub attachments: Option<&'a str>,
+    pub attachments: Option<Cow<'a, [::attachments::Attachment]>>,
+    /// Structured Block Kit layout blocks.
+    pub blocks: Option<Cow<'a, [::blocks::Block]>>,
     /// Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.
-    pub parse: Option<&'a str>,
+    pub parse: Option<Parse>,
     /// Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.
     pub link_names: Option<bool>,
     /// Pass true to update the message as the authed user. Bot users in this context are considered authed users.
     pub as_user: Option<bool>,
 }
 
+impl<'a> UpdateRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        ts: impl Into<Cow<'a, str>>,
+        channel: impl Into<Cow<'a, str>>,
+        text: impl Into<Cow<'a, str>>,
+    ) -> UpdateRequestBuilder<'a> {
+        UpdateRequestBuilder {
+            request: UpdateRequest {
+                ts: ts.into(),
+                channel: channel.into(),
+                text: text.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`UpdateRequest`](struct.UpdateRequest.html), created by `UpdateRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct UpdateRequestBuilder<'a> {
+    request: UpdateRequest<'a>,
+}
+
+impl<'a> UpdateRequestBuilder<'a> {
+    /// Structured message attachments.
+    pub fn attachments(
+        mut self,
+        attachments: impl Into<Cow<'a, [::attachments::Attachment]>>,
+    ) -> Self {
+        self.request.attachments = Some(attachments.into());
+        self
+    }
+
+    /// Structured Block Kit layout blocks.
+    pub fn blocks(mut self, blocks: impl Into<Cow<'a, [::blocks::Block]>>) -> Self {
+        self.request.blocks = Some(blocks.into());
+        self
+    }
+
+    /// Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.
+    pub fn parse(mut self, parse: Parse) -> Self {
+        self.request.parse = Some(parse);
+        self
+    }
+
+    /// Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.request.link_names = Some(link_names);
+        self
+    }
+
+    /// Pass true to update the message as the authed user. Bot users in this context are considered authed users.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.request.as_user = Some(as_user);
+        self
+    }
+
+    pub fn build(self) -> UpdateRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UpdateResponse {
     pub channel: Option<String>,
@@ -876,6 +2338,8 @@ pub struct UpdateResponse {
     ok: bool,
     pub text: Option<String>,
     pub ts: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -904,6 +2368,10 @@ pub enum UpdateError<E: Error> {
     TooManyAttachments,
     /// No message text provided
     NoText,
+    /// The blocks provided were not valid Block Kit blocks.
+    InvalidBlocks,
+    /// The blocks provided were not a valid JSON array.
+    InvalidBlocksFormat,
     /// No authentication token provided.
     NotAuthed,
     /// Invalid authentication token.
@@ -944,6 +2412,8 @@ impl<'a, E: Error> From<&'a str> for UpdateError<E> {
             "msg_too_long" => UpdateError::MsgTooLong,
             "too_many_attachments" => UpdateError::TooManyAttachments,
             "no_text" => UpdateError::NoText,
+            "invalid_blocks" => UpdateError::InvalidBlocks,
+            "invalid_blocks_format" => UpdateError::InvalidBlocksFormat,
             "not_authed" => UpdateError::NotAuthed,
             "invalid_auth" => UpdateError::InvalidAuth,
             "account_inactive" => UpdateError::AccountInactive,
@@ -986,6 +2456,12 @@ impl<E: Error> Error for UpdateError<E> {
                 "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message."
             }
             UpdateError::NoText => "no_text: No message text provided",
+            UpdateError::InvalidBlocks => {
+                "invalid_blocks: The blocks provided were not valid Block Kit blocks."
+            }
+            UpdateError::InvalidBlocksFormat => {
+                "invalid_blocks_format: The blocks provided were not a valid JSON array."
+            }
             UpdateError::NotAuthed => "not_authed: No authentication token provided.",
             UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
             UpdateError::AccountInactive => {
@@ -0,0 +1,271 @@
+//! Post chat messages to Slack at a later time.
+
+
+#[allow(unused_imports)]
+use std::borrow::Cow;
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Returns a list of scheduled messages.
+///
+/// Wraps https://api.slack.com/methods/chat.scheduledMessages.list
+
+pub fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = vec![
+        Some(("token", token)),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        request.latest.as_ref().map(|latest| ("latest", &latest[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request.oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("chat.scheduledMessages.list");
+    client
+        .send(&url, &params[..])
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// The channel of the scheduled messages
+    pub channel: Option<Cow<'a, str>>,
+    /// For pagination purposes, this is the cursor value returned from a previous call to chat.scheduledmessages.list indicating where you want to start this call from.
+    pub cursor: Option<Cow<'a, str>>,
+    /// A UNIX timestamp of the latest value in the time range
+    pub latest: Option<Cow<'a, str>>,
+    /// Maximum number of original entries to return.
+    pub limit: Option<u32>,
+    /// A UNIX timestamp of the oldest value in the time range
+    pub oldest: Option<Cow<'a, str>>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// The channel of the scheduled messages
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
+
+    /// For pagination purposes, this is the cursor value returned from a previous call to chat.scheduledmessages.list indicating where you want to start this call from.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// A UNIX timestamp of the latest value in the time range
+    pub fn latest(mut self, latest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.latest = Some(latest.into());
+        self
+    }
+
+    /// Maximum number of original entries to return.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// A UNIX timestamp of the oldest value in the time range
+    pub fn oldest(mut self, oldest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.oldest = Some(oldest.into());
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for ListRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for ListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    pub scheduled_messages: Option<Vec<ListResponseScheduledMessage>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponseScheduledMessage {
+    pub channel_id: Option<String>,
+    pub date_created: Option<i32>,
+    pub id: Option<String>,
+    pub post_at: Option<i32>,
+    pub text: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// The channel passed is invalid
+    InvalidChannel,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_channel" => ListError::InvalidChannel,
+            "invalid_cursor" => ListError::InvalidCursor,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ListError::InvalidChannel => "invalid_channel: The channel passed is invalid",
+            ListError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
+            ListError::NotAuthed => "not_authed: No authentication token provided.",
+            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            ListError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            ListError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            ListError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            ListError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            ListError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            ListError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            ListError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            ListError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            ListError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            ListError::MalformedResponse(ref e) => e.description(),
+            ListError::Unknown(ref s) => s,
+            ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            ListError::MalformedResponse(ref e) => Some(e),
+            ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,4151 @@
+//! Read and manage channels, private channels, direct messages and group messages alike.
+
+
+#[allow(unused_imports)]
+use std::borrow::Cow;
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// A kind of conversation to include in a conversation listing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConversationType {
+    /// Public channels.
+    PublicChannel,
+    /// Private channels.
+    PrivateChannel,
+    /// Multi-person direct messages.
+    Mpim,
+    /// Direct messages.
+    Im,
+}
+
+impl ConversationType {
+    /// The value sent to Slack for this variant.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            ConversationType::PublicChannel => "public_channel",
+            ConversationType::PrivateChannel => "private_channel",
+            ConversationType::Mpim => "mpim",
+            ConversationType::Im => "im",
+        }
+    }
+}
+
+impl Default for ConversationType {
+    fn default() -> Self {
+        ConversationType::PublicChannel
+    }
+}
+
+impl fmt::Display for ConversationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Archives a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.archive
+
+pub fn archive<R>(
+    client: &R,
+    token: &str,
+    request: &ArchiveRequest,
+) -> Result<ArchiveResponse, ArchiveError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.archive");
+    client
+        .send(&url, &params[..])
+        .map_err(ArchiveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ArchiveResponse>(&result).map_err(
+                ArchiveError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ArchiveRequest<'a> {
+    /// ID of conversation to archive
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> ArchiveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> ArchiveRequestBuilder<'a> {
+        ArchiveRequestBuilder {
+            request: ArchiveRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`ArchiveRequest`](struct.ArchiveRequest.html), created by `ArchiveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ArchiveRequestBuilder<'a> {
+    request: ArchiveRequest<'a>,
+}
+
+impl<'a> ArchiveRequestBuilder<'a> {
+    pub fn build(self) -> ArchiveRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ArchiveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveResponse {
+    fn into(self) -> Result<ArchiveResponse, ArchiveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ArchiveError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has already been archived.
+    AlreadyArchived,
+    /// You cannot archive the general channel
+    CantArchiveGeneral,
+    /// A team preference prevents the authenticated user from archiving.
+    RestrictedAction,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => ArchiveError::ChannelNotFound,
+            "already_archived" => ArchiveError::AlreadyArchived,
+            "cant_archive_general" => ArchiveError::CantArchiveGeneral,
+            "restricted_action" => ArchiveError::RestrictedAction,
+            "not_in_channel" => ArchiveError::NotInChannel,
+            "user_is_restricted" => ArchiveError::UserIsRestricted,
+            "not_authed" => ArchiveError::NotAuthed,
+            "invalid_auth" => ArchiveError::InvalidAuth,
+            "account_inactive" => ArchiveError::AccountInactive,
+            "invalid_arg_name" => ArchiveError::InvalidArgName,
+            "invalid_array_arg" => ArchiveError::InvalidArrayArg,
+            "invalid_charset" => ArchiveError::InvalidCharset,
+            "invalid_form_data" => ArchiveError::InvalidFormData,
+            "invalid_post_type" => ArchiveError::InvalidPostType,
+            "missing_post_type" => ArchiveError::MissingPostType,
+            "team_added_to_org" => ArchiveError::TeamAddedToOrg,
+            "request_timeout" => ArchiveError::RequestTimeout,
+            _ => ArchiveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ArchiveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ArchiveError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ArchiveError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            ArchiveError::AlreadyArchived => "already_archived: Channel has already been archived.",
+            ArchiveError::CantArchiveGeneral => {
+                "cant_archive_general: You cannot archive the general channel"
+            }
+            ArchiveError::RestrictedAction => {
+                "restricted_action: A team preference prevents the authenticated user from archiving."
+            }
+            ArchiveError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            ArchiveError::UserIsRestricted => {
+                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
+            }
+            ArchiveError::NotAuthed => "not_authed: No authentication token provided.",
+            ArchiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            ArchiveError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            ArchiveError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            ArchiveError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            ArchiveError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            ArchiveError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            ArchiveError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            ArchiveError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            ArchiveError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            ArchiveError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            ArchiveError::MalformedResponse(ref e) => e.description(),
+            ArchiveError::Unknown(ref s) => s,
+            ArchiveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            ArchiveError::MalformedResponse(ref e) => Some(e),
+            ArchiveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Closes a direct message or multi-person direct message.
+///
+/// Wraps https://api.slack.com/methods/conversations.close
+
+pub fn close<R>(
+    client: &R,
+    token: &str,
+    request: &CloseRequest,
+) -> Result<CloseResponse, CloseError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.close");
+    client
+        .send(&url, &params[..])
+        .map_err(CloseError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CloseResponse>(&result).map_err(CloseError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CloseRequest<'a> {
+    /// Conversation to close.
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> CloseRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> CloseRequestBuilder<'a> {
+        CloseRequestBuilder {
+            request: CloseRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`CloseRequest`](struct.CloseRequest.html), created by `CloseRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct CloseRequestBuilder<'a> {
+    request: CloseRequest<'a>,
+}
+
+impl<'a> CloseRequestBuilder<'a> {
+    pub fn build(self) -> CloseRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CloseResponse {
+    pub already_closed: Option<bool>,
+    error: Option<String>,
+    pub no_op: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<CloseResponse, CloseError<E>>> for CloseResponse {
+    fn into(self) -> Result<CloseResponse, CloseError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CloseError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Calling user does not own this DM channel.
+    UserDoesNotOwnChannel,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CloseError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => CloseError::ChannelNotFound,
+            "user_does_not_own_channel" => CloseError::UserDoesNotOwnChannel,
+            "not_authed" => CloseError::NotAuthed,
+            "invalid_auth" => CloseError::InvalidAuth,
+            "account_inactive" => CloseError::AccountInactive,
+            "invalid_arg_name" => CloseError::InvalidArgName,
+            "invalid_array_arg" => CloseError::InvalidArrayArg,
+            "invalid_charset" => CloseError::InvalidCharset,
+            "invalid_form_data" => CloseError::InvalidFormData,
+            "invalid_post_type" => CloseError::InvalidPostType,
+            "missing_post_type" => CloseError::MissingPostType,
+            "team_added_to_org" => CloseError::TeamAddedToOrg,
+            "request_timeout" => CloseError::RequestTimeout,
+            _ => CloseError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for CloseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CloseError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            CloseError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            CloseError::UserDoesNotOwnChannel => {
+                "user_does_not_own_channel: Calling user does not own this DM channel."
+            }
+            CloseError::NotAuthed => "not_authed: No authentication token provided.",
+            CloseError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            CloseError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            CloseError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            CloseError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            CloseError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            CloseError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            CloseError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            CloseError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            CloseError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            CloseError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            CloseError::MalformedResponse(ref e) => e.description(),
+            CloseError::Unknown(ref s) => s,
+            CloseError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            CloseError::MalformedResponse(ref e) => Some(e),
+            CloseError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Initiates a public or private channel-based conversation
+///
+/// Wraps https://api.slack.com/methods/conversations.create
+
+pub fn create<R>(
+    client: &R,
+    token: &str,
+    request: &CreateRequest,
+) -> Result<CreateResponse, CreateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("name", &request.name[..])),
+        request.is_private.map(|is_private| {
+            ("is_private", if is_private { "1" } else { "0" })
+        }),
+        request.team_id.as_ref().map(|team_id| ("team_id", &team_id[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.create");
+    client
+        .send(&url, &params[..])
+        .map_err(CreateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CreateResponse>(&result).map_err(CreateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequest<'a> {
+    /// Name of the public or private channel to create
+    pub name: Cow<'a, str>,
+    /// Create a private channel instead of a public one
+    pub is_private: Option<bool>,
+    /// Encoded team ID to create the channel in. Required when using an org-level token on Enterprise Grid.
+    pub team_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(name: impl Into<Cow<'a, str>>) -> CreateRequestBuilder<'a> {
+        CreateRequestBuilder {
+            request: CreateRequest {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`CreateRequest`](struct.CreateRequest.html), created by `CreateRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct CreateRequestBuilder<'a> {
+    request: CreateRequest<'a>,
+}
+
+impl<'a> CreateRequestBuilder<'a> {
+    /// Create a private channel instead of a public one
+    pub fn is_private(mut self, is_private: bool) -> Self {
+        self.request.is_private = Some(is_private);
+        self
+    }
+
+    /// Encoded team ID to create the channel in. Required when using an org-level token on Enterprise Grid.
+    pub fn team_id(mut self, team_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.team_id = Some(team_id.into());
+        self
+    }
+
+    pub fn build(self) -> CreateRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
+    fn into(self) -> Result<CreateResponse, CreateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CreateError<E: Error> {
+    /// A channel cannot be created with the given name.
+    NameTaken,
+    /// Value passed for name was empty.
+    InvalidNameRequired,
+    /// Value passed for name contained only punctuation.
+    InvalidNamePunctuation,
+    /// Value passed for name exceeded max length.
+    InvalidNameMaxlength,
+    /// Value passed for name contained unallowed special characters or upper case characters.
+    InvalidNameSpecials,
+    /// Value passed for name was invalid.
+    InvalidName,
+    /// A team preference prevents the authenticated user from creating channels.
+    RestrictedAction,
+    /// Value passed for name was empty.
+    NoChannel,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CreateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "name_taken" => CreateError::NameTaken,
+            "invalid_name_required" => CreateError::InvalidNameRequired,
+            "invalid_name_punctuation" => CreateError::InvalidNamePunctuation,
+            "invalid_name_maxlength" => CreateError::InvalidNameMaxlength,
+            "invalid_name_specials" => CreateError::InvalidNameSpecials,
+            "invalid_name" => CreateError::InvalidName,
+            "restricted_action" => CreateError::RestrictedAction,
+            "no_channel" => CreateError::NoChannel,
+            "user_is_restricted" => CreateError::UserIsRestricted,
+            "not_authed" => CreateError::NotAuthed,
+            "invalid_auth" => CreateError::InvalidAuth,
+            "account_inactive" => CreateError::AccountInactive,
+            "invalid_arg_name" => CreateError::InvalidArgName,
+            "invalid_array_arg" => CreateError::InvalidArrayArg,
+            "invalid_charset" => CreateError::InvalidCharset,
+            "invalid_form_data" => CreateError::InvalidFormData,
+            "invalid_post_type" => CreateError::InvalidPostType,
+            "missing_post_type" => CreateError::MissingPostType,
+            "team_added_to_org" => CreateError::TeamAddedToOrg,
+            "request_timeout" => CreateError::RequestTimeout,
+            _ => CreateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for CreateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for CreateError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            CreateError::NameTaken => {
+                "name_taken: A channel cannot be created with the given name."
+            }
+            CreateError::InvalidNameRequired => {
+                "invalid_name_required: Value passed for name was empty."
+            }
+            CreateError::InvalidNamePunctuation => {
+                "invalid_name_punctuation: Value passed for name contained only punctuation."
+            }
+            CreateError::InvalidNameMaxlength => {
+                "invalid_name_maxlength: Value passed for name exceeded max length."
+            }
+            CreateError::InvalidNameSpecials => {
+                "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters."
+            }
+            CreateError::InvalidName => "invalid_name: Value passed for name was invalid.",
+            CreateError::RestrictedAction => {
+                "restricted_action: A team preference prevents the authenticated user from creating channels."
+            }
+            CreateError::NoChannel => "no_channel: Value passed for name was empty.",
+            CreateError::UserIsRestricted => {
+                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
+            }
+            CreateError::NotAuthed => "not_authed: No authentication token provided.",
+            CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            CreateError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            CreateError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            CreateError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            CreateError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            CreateError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            CreateError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            CreateError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            CreateError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            CreateError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            CreateError::MalformedResponse(ref e) => e.description(),
+            CreateError::Unknown(ref s) => s,
+            CreateError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            CreateError::MalformedResponse(ref e) => Some(e),
+            CreateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Fetches a conversation's history of messages and events.
+///
+/// Wraps https://api.slack.com/methods/conversations.history
+
+pub fn history<R>(
+    client: &R,
+    token: &str,
+    request: &HistoryRequest,
+) -> Result<HistoryResponse, HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        request.inclusive.map(|inclusive| {
+            ("inclusive", if inclusive { "1" } else { "0" })
+        }),
+        request.latest.as_ref().map(|latest| ("latest", &latest[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request.oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.history");
+    client
+        .send(&url, &params[..])
+        .map_err(HistoryError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<HistoryResponse>(&result).map_err(
+                HistoryError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct HistoryRequest<'a> {
+    /// Conversation ID to fetch history for.
+    pub channel: Cow<'a, str>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results.
+    pub latest: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub limit: Option<u32>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<Cow<'a, str>>,
+}
+
+impl<'a> HistoryRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> HistoryRequestBuilder<'a> {
+        HistoryRequestBuilder {
+            request: HistoryRequest {
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`HistoryRequest`](struct.HistoryRequest.html), created by `HistoryRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct HistoryRequestBuilder<'a> {
+    request: HistoryRequest<'a>,
+}
+
+impl<'a> HistoryRequestBuilder<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.request.inclusive = Some(inclusive);
+        self
+    }
+
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.latest = Some(latest.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.oldest = Some(oldest.into());
+        self
+    }
+
+    pub fn build(self) -> HistoryRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for HistoryRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for HistoryResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct HistoryResponse {
+    error: Option<String>,
+    pub has_more: Option<bool>,
+    pub messages: Option<Vec<::Message>>,
+    #[serde(default)]
+    ok: bool,
+    pub pin_count: Option<i32>,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<HistoryResponse, HistoryError<E>>> for HistoryResponse {
+    fn into(self) -> Result<HistoryResponse, HistoryError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum HistoryError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Value passed for latest was invalid
+    InvalidTsLatest,
+    /// Value passed for oldest was invalid
+    InvalidTsOldest,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for HistoryError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => HistoryError::ChannelNotFound,
+            "invalid_cursor" => HistoryError::InvalidCursor,
+            "invalid_ts_latest" => HistoryError::InvalidTsLatest,
+            "invalid_ts_oldest" => HistoryError::InvalidTsOldest,
+            "not_in_channel" => HistoryError::NotInChannel,
+            "not_authed" => HistoryError::NotAuthed,
+            "invalid_auth" => HistoryError::InvalidAuth,
+            "account_inactive" => HistoryError::AccountInactive,
+            "invalid_arg_name" => HistoryError::InvalidArgName,
+            "invalid_array_arg" => HistoryError::InvalidArrayArg,
+            "invalid_charset" => HistoryError::InvalidCharset,
+            "invalid_form_data" => HistoryError::InvalidFormData,
+            "invalid_post_type" => HistoryError::InvalidPostType,
+            "missing_post_type" => HistoryError::MissingPostType,
+            "team_added_to_org" => HistoryError::TeamAddedToOrg,
+            "request_timeout" => HistoryError::RequestTimeout,
+            _ => HistoryError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for HistoryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for HistoryError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            HistoryError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            HistoryError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
+            HistoryError::InvalidTsLatest => {
+                "invalid_ts_latest: Value passed for latest was invalid"
+            }
+            HistoryError::InvalidTsOldest => {
+                "invalid_ts_oldest: Value passed for oldest was invalid"
+            }
+            HistoryError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            HistoryError::NotAuthed => "not_authed: No authentication token provided.",
+            HistoryError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            HistoryError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            HistoryError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            HistoryError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            HistoryError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            HistoryError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            HistoryError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            HistoryError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            HistoryError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            HistoryError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            HistoryError::MalformedResponse(ref e) => e.description(),
+            HistoryError::Unknown(ref s) => s,
+            HistoryError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            HistoryError::MalformedResponse(ref e) => Some(e),
+            HistoryError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve information about a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.info
+
+pub fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        request.include_locale.map(|include_locale| {
+            ("include_locale", if include_locale { "1" } else { "0" })
+        }),
+        request.include_num_members.map(|include_num_members| {
+            ("include_num_members", if include_num_members { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.info");
+    client
+        .send(&url, &params[..])
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequest<'a> {
+    /// Conversation ID to learn more about
+    pub channel: Cow<'a, str>,
+    /// Set this to true to receive the locale for this conversation. Defaults to false
+    pub include_locale: Option<bool>,
+    /// Set to true to include the member count for the specified conversation. Defaults to false
+    pub include_num_members: Option<bool>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> InfoRequestBuilder<'a> {
+        InfoRequestBuilder {
+            request: InfoRequest {
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`InfoRequest`](struct.InfoRequest.html), created by `InfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder<'a> {
+    request: InfoRequest<'a>,
+}
+
+impl<'a> InfoRequestBuilder<'a> {
+    /// Set this to true to receive the locale for this conversation. Defaults to false
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.request.include_locale = Some(include_locale);
+        self
+    }
+
+    /// Set to true to include the member count for the specified conversation. Defaults to false
+    pub fn include_num_members(mut self, include_num_members: bool) -> Self {
+        self.request.include_num_members = Some(include_num_members);
+        self
+    }
+
+    pub fn build(self) -> InfoRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InfoResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
+    fn into(self) -> Result<InfoResponse, InfoError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InfoError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InfoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => InfoError::ChannelNotFound,
+            "not_authed" => InfoError::NotAuthed,
+            "invalid_auth" => InfoError::InvalidAuth,
+            "account_inactive" => InfoError::AccountInactive,
+            "invalid_arg_name" => InfoError::InvalidArgName,
+            "invalid_array_arg" => InfoError::InvalidArrayArg,
+            "invalid_charset" => InfoError::InvalidCharset,
+            "invalid_form_data" => InfoError::InvalidFormData,
+            "invalid_post_type" => InfoError::InvalidPostType,
+            "missing_post_type" => InfoError::MissingPostType,
+            "team_added_to_org" => InfoError::TeamAddedToOrg,
+            "request_timeout" => InfoError::RequestTimeout,
+            _ => InfoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InfoError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            InfoError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            InfoError::NotAuthed => "not_authed: No authentication token provided.",
+            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            InfoError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            InfoError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            InfoError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            InfoError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            InfoError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            InfoError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            InfoError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            InfoError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            InfoError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            InfoError::MalformedResponse(ref e) => e.description(),
+            InfoError::Unknown(ref s) => s,
+            InfoError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            InfoError::MalformedResponse(ref e) => Some(e),
+            InfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Invites users to a channel.
+///
+/// Wraps https://api.slack.com/methods/conversations.invite
+
+pub fn invite<R>(
+    client: &R,
+    token: &str,
+    request: &InviteRequest,
+) -> Result<InviteResponse, InviteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    if request.users.len() > 1000 {
+        return Err(InviteError::TooManyUsers);
+    }
+    let users = request.users.join(",");
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("users", &users[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.invite");
+    client
+        .send(&url, &params[..])
+        .map_err(InviteError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InviteResponse>(&result).map_err(InviteError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InviteRequest<'a> {
+    /// The ID of the public or private channel to invite user(s) to.
+    pub channel: Cow<'a, str>,
+    /// The IDs of the users to invite. Up to 1000 users may be listed.
+    pub users: Vec<Cow<'a, str>>,
+}
+
+impl<'a> InviteRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        users: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> InviteRequestBuilder<'a> {
+        InviteRequestBuilder {
+            request: InviteRequest {
+                channel: channel.into(),
+                users: users.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+/// Builder for [`InviteRequest`](struct.InviteRequest.html), created by `InviteRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InviteRequestBuilder<'a> {
+    request: InviteRequest<'a>,
+}
+
+impl<'a> InviteRequestBuilder<'a> {
+    pub fn build(self) -> InviteRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InviteResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
+    fn into(self) -> Result<InviteResponse, InviteError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InviteError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for users was invalid.
+    UserNotFound,
+    /// No value was passed for users.
+    NoUser,
+    /// Authenticated user cannot invite themselves to a channel.
+    CantInviteSelf,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// Invited user is already in the channel.
+    AlreadyInChannel,
+    /// The conversation is archived.
+    IsArchived,
+    /// User cannot be invited to this channel.
+    CantInvite,
+    /// Needs at most 1000 users
+    TooManyUsers,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InviteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => InviteError::ChannelNotFound,
+            "user_not_found" => InviteError::UserNotFound,
+            "no_user" => InviteError::NoUser,
+            "cant_invite_self" => InviteError::CantInviteSelf,
+            "not_in_channel" => InviteError::NotInChannel,
+            "already_in_channel" => InviteError::AlreadyInChannel,
+            "is_archived" => InviteError::IsArchived,
+            "cant_invite" => InviteError::CantInvite,
+            "too_many_users" => InviteError::TooManyUsers,
+            "not_authed" => InviteError::NotAuthed,
+            "invalid_auth" => InviteError::InvalidAuth,
+            "account_inactive" => InviteError::AccountInactive,
+            "invalid_arg_name" => InviteError::InvalidArgName,
+            "invalid_array_arg" => InviteError::InvalidArrayArg,
+            "invalid_charset" => InviteError::InvalidCharset,
+            "invalid_form_data" => InviteError::InvalidFormData,
+            "invalid_post_type" => InviteError::InvalidPostType,
+            "missing_post_type" => InviteError::MissingPostType,
+            "team_added_to_org" => InviteError::TeamAddedToOrg,
+            "request_timeout" => InviteError::RequestTimeout,
+            _ => InviteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InviteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InviteError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            InviteError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            InviteError::UserNotFound => "user_not_found: Value passed for users was invalid.",
+            InviteError::NoUser => "no_user: No value was passed for users.",
+            InviteError::CantInviteSelf => {
+                "cant_invite_self: Authenticated user cannot invite themselves to a channel."
+            }
+            InviteError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            InviteError::AlreadyInChannel => {
+                "already_in_channel: Invited user is already in the channel."
+            }
+            InviteError::IsArchived => "is_archived: The conversation is archived.",
+            InviteError::CantInvite => "cant_invite: User cannot be invited to this channel.",
+            InviteError::TooManyUsers => "too_many_users: Needs at most 1000 users",
+            InviteError::NotAuthed => "not_authed: No authentication token provided.",
+            InviteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            InviteError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            InviteError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            InviteError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            InviteError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            InviteError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            InviteError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            InviteError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            InviteError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            InviteError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            InviteError::MalformedResponse(ref e) => e.description(),
+            InviteError::Unknown(ref s) => s,
+            InviteError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            InviteError::MalformedResponse(ref e) => Some(e),
+            InviteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Joins an existing conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.join
+
+pub fn join<R>(
+    client: &R,
+    token: &str,
+    request: &JoinRequest,
+) -> Result<JoinResponse, JoinError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.join");
+    client
+        .send(&url, &params[..])
+        .map_err(JoinError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<JoinResponse>(&result).map_err(JoinError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct JoinRequest<'a> {
+    /// ID of conversation to join
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> JoinRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> JoinRequestBuilder<'a> {
+        JoinRequestBuilder {
+            request: JoinRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`JoinRequest`](struct.JoinRequest.html), created by `JoinRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct JoinRequestBuilder<'a> {
+    request: JoinRequest<'a>,
+}
+
+impl<'a> JoinRequestBuilder<'a> {
+    pub fn build(self) -> JoinRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct JoinResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<JoinResponse, JoinError<E>>> for JoinResponse {
+    fn into(self) -> Result<JoinResponse, JoinError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum JoinError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The conversation is archived.
+    IsArchived,
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for JoinError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => JoinError::ChannelNotFound,
+            "is_archived" => JoinError::IsArchived,
+            "method_not_supported_for_channel_type" => JoinError::MethodNotSupportedForChannelType,
+            "user_is_restricted" => JoinError::UserIsRestricted,
+            "not_authed" => JoinError::NotAuthed,
+            "invalid_auth" => JoinError::InvalidAuth,
+            "account_inactive" => JoinError::AccountInactive,
+            "invalid_arg_name" => JoinError::InvalidArgName,
+            "invalid_array_arg" => JoinError::InvalidArrayArg,
+            "invalid_charset" => JoinError::InvalidCharset,
+            "invalid_form_data" => JoinError::InvalidFormData,
+            "invalid_post_type" => JoinError::InvalidPostType,
+            "missing_post_type" => JoinError::MissingPostType,
+            "team_added_to_org" => JoinError::TeamAddedToOrg,
+            "request_timeout" => JoinError::RequestTimeout,
+            _ => JoinError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for JoinError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for JoinError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            JoinError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            JoinError::IsArchived => "is_archived: The conversation is archived.",
+            JoinError::MethodNotSupportedForChannelType => {
+                "method_not_supported_for_channel_type: This type of conversation cannot be used with this method."
+            }
+            JoinError::UserIsRestricted => {
+                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
+            }
+            JoinError::NotAuthed => "not_authed: No authentication token provided.",
+            JoinError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            JoinError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            JoinError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            JoinError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            JoinError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            JoinError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            JoinError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            JoinError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            JoinError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            JoinError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            JoinError::MalformedResponse(ref e) => e.description(),
+            JoinError::Unknown(ref s) => s,
+            JoinError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            JoinError::MalformedResponse(ref e) => Some(e),
+            JoinError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Removes a user from a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.kick
+
+pub fn kick<R>(
+    client: &R,
+    token: &str,
+    request: &KickRequest,
+) -> Result<KickResponse, KickError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("user", &request.user[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.kick");
+    client
+        .send(&url, &params[..])
+        .map_err(KickError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<KickResponse>(&result).map_err(KickError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct KickRequest<'a> {
+    /// ID of conversation to remove user from.
+    pub channel: Cow<'a, str>,
+    /// User ID to be removed.
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> KickRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        user: impl Into<Cow<'a, str>>,
+    ) -> KickRequestBuilder<'a> {
+        KickRequestBuilder {
+            request: KickRequest {
+                channel: channel.into(),
+                user: user.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`KickRequest`](struct.KickRequest.html), created by `KickRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct KickRequestBuilder<'a> {
+    request: KickRequest<'a>,
+}
+
+impl<'a> KickRequestBuilder<'a> {
+    pub fn build(self) -> KickRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct KickResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<KickResponse, KickError<E>>> for KickResponse {
+    fn into(self) -> Result<KickResponse, KickError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum KickError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for user was invalid.
+    UserNotFound,
+    /// Authenticated user can't kick themselves from a channel.
+    CantKickSelf,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// User cannot be removed from #general.
+    CantKickFromGeneral,
+    /// A team preference prevents the authenticated user from kicking.
+    RestrictedAction,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for KickError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => KickError::ChannelNotFound,
+            "user_not_found" => KickError::UserNotFound,
+            "cant_kick_self" => KickError::CantKickSelf,
+            "not_in_channel" => KickError::NotInChannel,
+            "cant_kick_from_general" => KickError::CantKickFromGeneral,
+            "restricted_action" => KickError::RestrictedAction,
+            "not_authed" => KickError::NotAuthed,
+            "invalid_auth" => KickError::InvalidAuth,
+            "account_inactive" => KickError::AccountInactive,
+            "invalid_arg_name" => KickError::InvalidArgName,
+            "invalid_array_arg" => KickError::InvalidArrayArg,
+            "invalid_charset" => KickError::InvalidCharset,
+            "invalid_form_data" => KickError::InvalidFormData,
+            "invalid_post_type" => KickError::InvalidPostType,
+            "missing_post_type" => KickError::MissingPostType,
+            "team_added_to_org" => KickError::TeamAddedToOrg,
+            "request_timeout" => KickError::RequestTimeout,
+            _ => KickError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for KickError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for KickError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            KickError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            KickError::UserNotFound => "user_not_found: Value passed for user was invalid.",
+            KickError::CantKickSelf => {
+                "cant_kick_self: Authenticated user can't kick themselves from a channel."
+            }
+            KickError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            KickError::CantKickFromGeneral => {
+                "cant_kick_from_general: User cannot be removed from #general."
+            }
+            KickError::RestrictedAction => {
+                "restricted_action: A team preference prevents the authenticated user from kicking."
+            }
+            KickError::NotAuthed => "not_authed: No authentication token provided.",
+            KickError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            KickError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            KickError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            KickError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            KickError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            KickError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            KickError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            KickError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            KickError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            KickError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            KickError::MalformedResponse(ref e) => e.description(),
+            KickError::Unknown(ref s) => s,
+            KickError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            KickError::MalformedResponse(ref e) => Some(e),
+            KickError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Leaves a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.leave
+
+pub fn leave<R>(
+    client: &R,
+    token: &str,
+    request: &LeaveRequest,
+) -> Result<LeaveResponse, LeaveError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.leave");
+    client
+        .send(&url, &params[..])
+        .map_err(LeaveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<LeaveResponse>(&result).map_err(LeaveError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct LeaveRequest<'a> {
+    /// Conversation to leave
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> LeaveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> LeaveRequestBuilder<'a> {
+        LeaveRequestBuilder {
+            request: LeaveRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`LeaveRequest`](struct.LeaveRequest.html), created by `LeaveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct LeaveRequestBuilder<'a> {
+    request: LeaveRequest<'a>,
+}
+
+impl<'a> LeaveRequestBuilder<'a> {
+    pub fn build(self) -> LeaveRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LeaveResponse {
+    error: Option<String>,
+    pub not_in_channel: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<LeaveResponse, LeaveError<E>>> for LeaveResponse {
+    fn into(self) -> Result<LeaveResponse, LeaveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum LeaveError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The conversation is archived.
+    IsArchived,
+    /// Authenticated user cannot leave the general channel
+    CantLeaveGeneral,
+    /// Authenticated user is the last member of a private channel and cannot leave
+    LastMember,
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for LeaveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => LeaveError::ChannelNotFound,
+            "is_archived" => LeaveError::IsArchived,
+            "cant_leave_general" => LeaveError::CantLeaveGeneral,
+            "last_member" => LeaveError::LastMember,
+            "method_not_supported_for_channel_type" => LeaveError::MethodNotSupportedForChannelType,
+            "not_authed" => LeaveError::NotAuthed,
+            "invalid_auth" => LeaveError::InvalidAuth,
+            "account_inactive" => LeaveError::AccountInactive,
+            "invalid_arg_name" => LeaveError::InvalidArgName,
+            "invalid_array_arg" => LeaveError::InvalidArrayArg,
+            "invalid_charset" => LeaveError::InvalidCharset,
+            "invalid_form_data" => LeaveError::InvalidFormData,
+            "invalid_post_type" => LeaveError::InvalidPostType,
+            "missing_post_type" => LeaveError::MissingPostType,
+            "team_added_to_org" => LeaveError::TeamAddedToOrg,
+            "request_timeout" => LeaveError::RequestTimeout,
+            _ => LeaveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for LeaveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for LeaveError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            LeaveError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            LeaveError::IsArchived => "is_archived: The conversation is archived.",
+            LeaveError::CantLeaveGeneral => {
+                "cant_leave_general: Authenticated user cannot leave the general channel"
+            }
+            LeaveError::LastMember => {
+                "last_member: Authenticated user is the last member of a private channel and cannot leave"
+            }
+            LeaveError::MethodNotSupportedForChannelType => {
+                "method_not_supported_for_channel_type: This type of conversation cannot be used with this method."
+            }
+            LeaveError::NotAuthed => "not_authed: No authentication token provided.",
+            LeaveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            LeaveError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            LeaveError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            LeaveError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            LeaveError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            LeaveError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            LeaveError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            LeaveError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            LeaveError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            LeaveError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            LeaveError::MalformedResponse(ref e) => e.description(),
+            LeaveError::Unknown(ref s) => s,
+            LeaveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            LeaveError::MalformedResponse(ref e) => Some(e),
+            LeaveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Lists all channels in a Slack team.
+///
+/// Wraps https://api.slack.com/methods/conversations.list
+
+pub fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let types = request.types.as_ref().map(|types| {
+        types.iter().map(ConversationType::as_str).collect::<Vec<_>>().join(",")
+    });
+    let params = vec![
+        Some(("token", token)),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        request.exclude_archived.map(|exclude_archived| {
+            ("exclude_archived", if exclude_archived { "1" } else { "0" })
+        }),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request.team_id.as_ref().map(|team_id| ("team_id", &team_id[..])),
+        types.as_ref().map(|types| ("types", &types[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.list");
+    client
+        .send(&url, &params[..])
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// Set to true to exclude archived channels from the list
+    pub exclude_archived: Option<bool>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
+    pub limit: Option<u32>,
+    /// Encoded team ID to list channels in. Required when using an org-level token on Enterprise Grid.
+    pub team_id: Option<Cow<'a, str>>,
+    /// The kinds of conversation to include. Defaults to public channels.
+    pub types: Option<Cow<'a, [ConversationType]>>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Set to true to exclude archived channels from the list
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.request.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Encoded team ID to list channels in. Required when using an org-level token on Enterprise Grid.
+    pub fn team_id(mut self, team_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.team_id = Some(team_id.into());
+        self
+    }
+
+    /// The kinds of conversation to include. Defaults to public channels.
+    pub fn types(mut self, types: impl Into<Cow<'a, [ConversationType]>>) -> Self {
+        self.request.types = Some(types.into());
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for ListRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for ListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    pub channels: Option<Vec<::Conversation>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Value passed for limit is not understood.
+    InvalidLimit,
+    /// Value passed for type could not be used based on the method's capabilities or the permission scopes granted to the used token.
+    InvalidTypes,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_cursor" => ListError::InvalidCursor,
+            "invalid_limit" => ListError::InvalidLimit,
+            "invalid_types" => ListError::InvalidTypes,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ListError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
+            ListError::InvalidLimit => "invalid_limit: Value passed for limit is not understood.",
+            ListError::InvalidTypes => {
+                "invalid_types: Value passed for type could not be used based on the method's capabilities or the permission scopes granted to the used token."
+            }
+            ListError::NotAuthed => "not_authed: No authentication token provided.",
+            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            ListError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            ListError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            ListError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            ListError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            ListError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            ListError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            ListError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            ListError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            ListError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            ListError::MalformedResponse(ref e) => e.description(),
+            ListError::Unknown(ref s) => s,
+            ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            ListError::MalformedResponse(ref e) => Some(e),
+            ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Sets the read cursor in a channel.
+///
+/// Wraps https://api.slack.com/methods/conversations.mark
+
+pub fn mark<R>(
+    client: &R,
+    token: &str,
+    request: &MarkRequest,
+) -> Result<MarkResponse, MarkError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.mark");
+    client
+        .send(&url, &params[..])
+        .map_err(MarkError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<MarkResponse>(&result).map_err(MarkError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct MarkRequest<'a> {
+    /// Channel or conversation to set the read cursor for.
+    pub channel: Cow<'a, str>,
+    /// Unique identifier of message you want marked as most recently seen in this conversation.
+    pub ts: Cow<'a, str>,
+}
+
+impl<'a> MarkRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        ts: impl Into<Cow<'a, str>>,
+    ) -> MarkRequestBuilder<'a> {
+        MarkRequestBuilder {
+            request: MarkRequest {
+                channel: channel.into(),
+                ts: ts.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`MarkRequest`](struct.MarkRequest.html), created by `MarkRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct MarkRequestBuilder<'a> {
+    request: MarkRequest<'a>,
+}
+
+impl<'a> MarkRequestBuilder<'a> {
+    pub fn build(self) -> MarkRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarkResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<MarkResponse, MarkError<E>>> for MarkResponse {
+    fn into(self) -> Result<MarkResponse, MarkError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum MarkError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for timestamp was invalid.
+    InvalidTimestamp,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for MarkError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => MarkError::ChannelNotFound,
+            "invalid_timestamp" => MarkError::InvalidTimestamp,
+            "not_in_channel" => MarkError::NotInChannel,
+            "not_authed" => MarkError::NotAuthed,
+            "invalid_auth" => MarkError::InvalidAuth,
+            "account_inactive" => MarkError::AccountInactive,
+            "invalid_arg_name" => MarkError::InvalidArgName,
+            "invalid_array_arg" => MarkError::InvalidArrayArg,
+            "invalid_charset" => MarkError::InvalidCharset,
+            "invalid_form_data" => MarkError::InvalidFormData,
+            "invalid_post_type" => MarkError::InvalidPostType,
+            "missing_post_type" => MarkError::MissingPostType,
+            "team_added_to_org" => MarkError::TeamAddedToOrg,
+            "request_timeout" => MarkError::RequestTimeout,
+            _ => MarkError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for MarkError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for MarkError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            MarkError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            MarkError::InvalidTimestamp => {
+                "invalid_timestamp: Value passed for timestamp was invalid."
+            }
+            MarkError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            MarkError::NotAuthed => "not_authed: No authentication token provided.",
+            MarkError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            MarkError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            MarkError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            MarkError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            MarkError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            MarkError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            MarkError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            MarkError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            MarkError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            MarkError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            MarkError::MalformedResponse(ref e) => e.description(),
+            MarkError::Unknown(ref s) => s,
+            MarkError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            MarkError::MalformedResponse(ref e) => Some(e),
+            MarkError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve members of a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.members
+
+pub fn members<R>(
+    client: &R,
+    token: &str,
+    request: &MembersRequest,
+) -> Result<MembersResponse, MembersError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.members");
+    client
+        .send(&url, &params[..])
+        .map_err(MembersError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<MembersResponse>(&result).map_err(
+                MembersError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct MembersRequest<'a> {
+    /// ID of the conversation to retrieve members for
+    pub channel: Cow<'a, str>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+impl<'a> MembersRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> MembersRequestBuilder<'a> {
+        MembersRequestBuilder {
+            request: MembersRequest {
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`MembersRequest`](struct.MembersRequest.html), created by `MembersRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct MembersRequestBuilder<'a> {
+    request: MembersRequest<'a>,
+}
+
+impl<'a> MembersRequestBuilder<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> MembersRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for MembersRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for MembersResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MembersResponse {
+    error: Option<String>,
+    pub members: Option<Vec<String>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<MembersResponse, MembersError<E>>> for MembersResponse {
+    fn into(self) -> Result<MembersResponse, MembersError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum MembersError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Failed to fetch members for the conversation.
+    FetchMembersFailed,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for MembersError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => MembersError::ChannelNotFound,
+            "invalid_cursor" => MembersError::InvalidCursor,
+            "fetch_members_failed" => MembersError::FetchMembersFailed,
+            "not_authed" => MembersError::NotAuthed,
+            "invalid_auth" => MembersError::InvalidAuth,
+            "account_inactive" => MembersError::AccountInactive,
+            "invalid_arg_name" => MembersError::InvalidArgName,
+            "invalid_array_arg" => MembersError::InvalidArrayArg,
+            "invalid_charset" => MembersError::InvalidCharset,
+            "invalid_form_data" => MembersError::InvalidFormData,
+            "invalid_post_type" => MembersError::InvalidPostType,
+            "missing_post_type" => MembersError::MissingPostType,
+            "team_added_to_org" => MembersError::TeamAddedToOrg,
+            "request_timeout" => MembersError::RequestTimeout,
+            _ => MembersError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for MembersError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for MembersError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            MembersError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            MembersError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
+            MembersError::FetchMembersFailed => {
+                "fetch_members_failed: Failed to fetch members for the conversation."
+            }
+            MembersError::NotAuthed => "not_authed: No authentication token provided.",
+            MembersError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            MembersError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            MembersError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            MembersError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            MembersError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            MembersError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            MembersError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            MembersError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            MembersError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            MembersError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            MembersError::MalformedResponse(ref e) => e.description(),
+            MembersError::Unknown(ref s) => s,
+            MembersError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            MembersError::MalformedResponse(ref e) => Some(e),
+            MembersError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Opens or resumes a direct message or multi-person direct message.
+///
+/// Wraps https://api.slack.com/methods/conversations.open
+
+pub fn open<R>(
+    client: &R,
+    token: &str,
+    request: &OpenRequest,
+) -> Result<OpenResponse, OpenError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    if request.users.as_ref().map_or(false, |users| users.len() > 8) {
+        return Err(OpenError::TooManyUsers);
+    }
+    let users = request.users.as_ref().map(|users| users.join(","));
+    let params = vec![
+        Some(("token", token)),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.return_im.map(|return_im| {
+            ("return_im", if return_im { "1" } else { "0" })
+        }),
+        users.as_ref().map(|users| ("users", &users[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.open");
+    client
+        .send(&url, &params[..])
+        .map_err(OpenError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<OpenResponse>(&result).map_err(OpenError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct OpenRequest<'a> {
+    /// Resume a conversation by supplying an im or mpim's ID. Or provide the users field instead.
+    pub channel: Option<Cow<'a, str>>,
+    /// Boolean, indicates you want the full IM channel definition in the response.
+    pub return_im: Option<bool>,
+    /// The users to open a conversation with. If only one user is included, this creates a 1:1 DM. The ordering of the users is preserved whenever a multi-person direct message is returned. Supply a channel when not supplying users.
+    pub users: Option<Vec<Cow<'a, str>>>,
+}
+
+impl<'a> OpenRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> OpenRequestBuilder<'a> {
+        OpenRequestBuilder {
+            request: OpenRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`OpenRequest`](struct.OpenRequest.html), created by `OpenRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct OpenRequestBuilder<'a> {
+    request: OpenRequest<'a>,
+}
+
+impl<'a> OpenRequestBuilder<'a> {
+    /// Resume a conversation by supplying an im or mpim's ID. Or provide the users field instead.
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
+
+    /// Boolean, indicates you want the full IM channel definition in the response.
+    pub fn return_im(mut self, return_im: bool) -> Self {
+        self.request.return_im = Some(return_im);
+        self
+    }
+
+    /// The users to open a conversation with. If only one user is included, this creates a 1:1 DM. The ordering of the users is preserved whenever a multi-person direct message is returned. Supply a channel when not supplying users.
+    pub fn users(mut self, users: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> Self {
+        self.request.users = Some(users.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn build(self) -> OpenRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OpenResponse {
+    pub already_open: Option<bool>,
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    pub no_op: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
+    fn into(self) -> Result<OpenResponse, OpenError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum OpenError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// One or more of the users provided was invalid.
+    UserNotFound,
+    /// The user has been disabled.
+    UserDisabled,
+    /// Needs at least 2 users to open
+    NotEnoughUsers,
+    /// Needs at most 8 users
+    TooManyUsers,
+    /// Missing channel or users.
+    UsersListNotSupplied,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for OpenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => OpenError::ChannelNotFound,
+            "user_not_found" => OpenError::UserNotFound,
+            "user_disabled" => OpenError::UserDisabled,
+            "not_enough_users" => OpenError::NotEnoughUsers,
+            "too_many_users" => OpenError::TooManyUsers,
+            "users_list_not_supplied" => OpenError::UsersListNotSupplied,
+            "not_authed" => OpenError::NotAuthed,
+            "invalid_auth" => OpenError::InvalidAuth,
+            "account_inactive" => OpenError::AccountInactive,
+            "invalid_arg_name" => OpenError::InvalidArgName,
+            "invalid_array_arg" => OpenError::InvalidArrayArg,
+            "invalid_charset" => OpenError::InvalidCharset,
+            "invalid_form_data" => OpenError::InvalidFormData,
+            "invalid_post_type" => OpenError::InvalidPostType,
+            "missing_post_type" => OpenError::MissingPostType,
+            "team_added_to_org" => OpenError::TeamAddedToOrg,
+            "request_timeout" => OpenError::RequestTimeout,
+            _ => OpenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for OpenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for OpenError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            OpenError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            OpenError::UserNotFound => {
+                "user_not_found: One or more of the users provided was invalid."
+            }
+            OpenError::UserDisabled => "user_disabled: The user has been disabled.",
+            OpenError::NotEnoughUsers => "not_enough_users: Needs at least 2 users to open",
+            OpenError::TooManyUsers => "too_many_users: Needs at most 8 users",
+            OpenError::UsersListNotSupplied => "users_list_not_supplied: Missing channel or users.",
+            OpenError::NotAuthed => "not_authed: No authentication token provided.",
+            OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            OpenError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            OpenError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            OpenError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            OpenError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            OpenError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            OpenError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            OpenError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            OpenError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            OpenError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            OpenError::MalformedResponse(ref e) => e.description(),
+            OpenError::Unknown(ref s) => s,
+            OpenError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            OpenError::MalformedResponse(ref e) => Some(e),
+            OpenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Renames a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.rename
+
+pub fn rename<R>(
+    client: &R,
+    token: &str,
+    request: &RenameRequest,
+) -> Result<RenameResponse, RenameError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("name", &request.name[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.rename");
+    client
+        .send(&url, &params[..])
+        .map_err(RenameError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RenameResponse>(&result).map_err(RenameError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RenameRequest<'a> {
+    /// ID of conversation to rename
+    pub channel: Cow<'a, str>,
+    /// New name for conversation.
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> RenameRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> RenameRequestBuilder<'a> {
+        RenameRequestBuilder {
+            request: RenameRequest {
+                channel: channel.into(),
+                name: name.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`RenameRequest`](struct.RenameRequest.html), created by `RenameRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RenameRequestBuilder<'a> {
+    request: RenameRequest<'a>,
+}
+
+impl<'a> RenameRequestBuilder<'a> {
+    pub fn build(self) -> RenameRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RenameResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
+    fn into(self) -> Result<RenameResponse, RenameError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RenameError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// Caller cannot rename this channel
+    NotAuthorized,
+    /// A channel cannot be created with the given name.
+    NameTaken,
+    /// Value passed for name was empty.
+    InvalidNameRequired,
+    /// Value passed for name contained only punctuation.
+    InvalidNamePunctuation,
+    /// Value passed for name exceeded max length.
+    InvalidNameMaxlength,
+    /// Value passed for name contained unallowed special characters or upper case characters.
+    InvalidNameSpecials,
+    /// Value passed for name was invalid.
+    InvalidName,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RenameError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => RenameError::ChannelNotFound,
+            "not_in_channel" => RenameError::NotInChannel,
+            "not_authorized" => RenameError::NotAuthorized,
+            "name_taken" => RenameError::NameTaken,
+            "invalid_name_required" => RenameError::InvalidNameRequired,
+            "invalid_name_punctuation" => RenameError::InvalidNamePunctuation,
+            "invalid_name_maxlength" => RenameError::InvalidNameMaxlength,
+            "invalid_name_specials" => RenameError::InvalidNameSpecials,
+            "invalid_name" => RenameError::InvalidName,
+            "user_is_restricted" => RenameError::UserIsRestricted,
+            "not_authed" => RenameError::NotAuthed,
+            "invalid_auth" => RenameError::InvalidAuth,
+            "account_inactive" => RenameError::AccountInactive,
+            "invalid_arg_name" => RenameError::InvalidArgName,
+            "invalid_array_arg" => RenameError::InvalidArrayArg,
+            "invalid_charset" => RenameError::InvalidCharset,
+            "invalid_form_data" => RenameError::InvalidFormData,
+            "invalid_post_type" => RenameError::InvalidPostType,
+            "missing_post_type" => RenameError::MissingPostType,
+            "team_added_to_org" => RenameError::TeamAddedToOrg,
+            "request_timeout" => RenameError::RequestTimeout,
+            _ => RenameError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RenameError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RenameError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            RenameError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            RenameError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            RenameError::NotAuthorized => "not_authorized: Caller cannot rename this channel",
+            RenameError::NameTaken => {
+                "name_taken: A channel cannot be created with the given name."
+            }
+            RenameError::InvalidNameRequired => {
+                "invalid_name_required: Value passed for name was empty."
+            }
+            RenameError::InvalidNamePunctuation => {
+                "invalid_name_punctuation: Value passed for name contained only punctuation."
+            }
+            RenameError::InvalidNameMaxlength => {
+                "invalid_name_maxlength: Value passed for name exceeded max length."
+            }
+            RenameError::InvalidNameSpecials => {
+                "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters."
+            }
+            RenameError::InvalidName => "invalid_name: Value passed for name was invalid.",
+            RenameError::UserIsRestricted => {
+                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
+            }
+            RenameError::NotAuthed => "not_authed: No authentication token provided.",
+            RenameError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            RenameError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            RenameError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            RenameError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            RenameError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            RenameError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            RenameError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            RenameError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            RenameError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            RenameError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            RenameError::MalformedResponse(ref e) => e.description(),
+            RenameError::Unknown(ref s) => s,
+            RenameError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            RenameError::MalformedResponse(ref e) => Some(e),
+            RenameError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve a thread of messages posted to a conversation
+///
+/// Wraps https://api.slack.com/methods/conversations.replies
+
+pub fn replies<R>(
+    client: &R,
+    token: &str,
+    request: &RepliesRequest,
+) -> Result<RepliesResponse, RepliesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        request.inclusive.map(|inclusive| {
+            ("inclusive", if inclusive { "1" } else { "0" })
+        }),
+        request.latest.as_ref().map(|latest| ("latest", &latest[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request.oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.replies");
+    client
+        .send(&url, &params[..])
+        .map_err(RepliesError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RepliesResponse>(&result).map_err(
+                RepliesError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RepliesRequest<'a> {
+    /// Conversation ID to fetch thread from.
+    pub channel: Cow<'a, str>,
+    /// Unique identifier of a thread's parent message.
+    pub ts: Cow<'a, str>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results.
+    pub latest: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub limit: Option<u32>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<Cow<'a, str>>,
+}
+
+impl<'a> RepliesRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        ts: impl Into<Cow<'a, str>>,
+    ) -> RepliesRequestBuilder<'a> {
+        RepliesRequestBuilder {
+            request: RepliesRequest {
+                channel: channel.into(),
+                ts: ts.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`RepliesRequest`](struct.RepliesRequest.html), created by `RepliesRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RepliesRequestBuilder<'a> {
+    request: RepliesRequest<'a>,
+}
+
+impl<'a> RepliesRequestBuilder<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.request.inclusive = Some(inclusive);
+        self
+    }
+
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.latest = Some(latest.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.oldest = Some(oldest.into());
+        self
+    }
+
+    pub fn build(self) -> RepliesRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for RepliesRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for RepliesResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RepliesResponse {
+    error: Option<String>,
+    pub has_more: Option<bool>,
+    pub messages: Option<Vec<::Message>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<RepliesResponse, RepliesError<E>>> for RepliesResponse {
+    fn into(self) -> Result<RepliesResponse, RepliesError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RepliesError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value for ts was missing or invalid.
+    ThreadNotFound,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Value passed for latest was invalid
+    InvalidTsLatest,
+    /// Value passed for oldest was invalid
+    InvalidTsOldest,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RepliesError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => RepliesError::ChannelNotFound,
+            "thread_not_found" => RepliesError::ThreadNotFound,
+            "invalid_cursor" => RepliesError::InvalidCursor,
+            "invalid_ts_latest" => RepliesError::InvalidTsLatest,
+            "invalid_ts_oldest" => RepliesError::InvalidTsOldest,
+            "not_authed" => RepliesError::NotAuthed,
+            "invalid_auth" => RepliesError::InvalidAuth,
+            "account_inactive" => RepliesError::AccountInactive,
+            "invalid_arg_name" => RepliesError::InvalidArgName,
+            "invalid_array_arg" => RepliesError::InvalidArrayArg,
+            "invalid_charset" => RepliesError::InvalidCharset,
+            "invalid_form_data" => RepliesError::InvalidFormData,
+            "invalid_post_type" => RepliesError::InvalidPostType,
+            "missing_post_type" => RepliesError::MissingPostType,
+            "team_added_to_org" => RepliesError::TeamAddedToOrg,
+            "request_timeout" => RepliesError::RequestTimeout,
+            _ => RepliesError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RepliesError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RepliesError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            RepliesError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            RepliesError::ThreadNotFound => {
+                "thread_not_found: Value for ts was missing or invalid."
+            }
+            RepliesError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
+            RepliesError::InvalidTsLatest => {
+                "invalid_ts_latest: Value passed for latest was invalid"
+            }
+            RepliesError::InvalidTsOldest => {
+                "invalid_ts_oldest: Value passed for oldest was invalid"
+            }
+            RepliesError::NotAuthed => "not_authed: No authentication token provided.",
+            RepliesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            RepliesError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            RepliesError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            RepliesError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            RepliesError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            RepliesError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            RepliesError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            RepliesError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            RepliesError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            RepliesError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            RepliesError::MalformedResponse(ref e) => e.description(),
+            RepliesError::Unknown(ref s) => s,
+            RepliesError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            RepliesError::MalformedResponse(ref e) => Some(e),
+            RepliesError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Sets the purpose for a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.setPurpose
+
+pub fn set_purpose<R>(
+    client: &R,
+    token: &str,
+    request: &SetPurposeRequest,
+) -> Result<SetPurposeResponse, SetPurposeError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("purpose", &request.purpose[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.setPurpose");
+    client
+        .send(&url, &params[..])
+        .map_err(SetPurposeError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetPurposeResponse>(&result).map_err(
+                SetPurposeError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetPurposeRequest<'a> {
+    /// Conversation to set the purpose of
+    pub channel: Cow<'a, str>,
+    /// A new, specialer purpose
+    pub purpose: Cow<'a, str>,
+}
+
+impl<'a> SetPurposeRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        purpose: impl Into<Cow<'a, str>>,
+    ) -> SetPurposeRequestBuilder<'a> {
+        SetPurposeRequestBuilder {
+            request: SetPurposeRequest {
+                channel: channel.into(),
+                purpose: purpose.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`SetPurposeRequest`](struct.SetPurposeRequest.html), created by `SetPurposeRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct SetPurposeRequestBuilder<'a> {
+    request: SetPurposeRequest<'a>,
+}
+
+impl<'a> SetPurposeRequestBuilder<'a> {
+    pub fn build(self) -> SetPurposeRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetPurposeResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<SetPurposeResponse, SetPurposeError<E>>> for SetPurposeResponse {
+    fn into(self) -> Result<SetPurposeResponse, SetPurposeError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetPurposeError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// The conversation is archived.
+    IsArchived,
+    /// Purpose was longer than 250 characters.
+    TooLong,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetPurposeError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => SetPurposeError::ChannelNotFound,
+            "not_in_channel" => SetPurposeError::NotInChannel,
+            "is_archived" => SetPurposeError::IsArchived,
+            "too_long" => SetPurposeError::TooLong,
+            "user_is_restricted" => SetPurposeError::UserIsRestricted,
+            "not_authed" => SetPurposeError::NotAuthed,
+            "invalid_auth" => SetPurposeError::InvalidAuth,
+            "account_inactive" => SetPurposeError::AccountInactive,
+            "invalid_arg_name" => SetPurposeError::InvalidArgName,
+            "invalid_array_arg" => SetPurposeError::InvalidArrayArg,
+            "invalid_charset" => SetPurposeError::InvalidCharset,
+            "invalid_form_data" => SetPurposeError::InvalidFormData,
+            "invalid_post_type" => SetPurposeError::InvalidPostType,
+            "missing_post_type" => SetPurposeError::MissingPostType,
+            "team_added_to_org" => SetPurposeError::TeamAddedToOrg,
+            "request_timeout" => SetPurposeError::RequestTimeout,
+            _ => SetPurposeError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetPurposeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetPurposeError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            SetPurposeError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            SetPurposeError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            SetPurposeError::IsArchived => "is_archived: The conversation is archived.",
+            SetPurposeError::TooLong => "too_long: Purpose was longer than 250 characters.",
+            SetPurposeError::UserIsRestricted => {
+                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
+            }
+            SetPurposeError::NotAuthed => "not_authed: No authentication token provided.",
+            SetPurposeError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            SetPurposeError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            SetPurposeError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            SetPurposeError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            SetPurposeError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            SetPurposeError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            SetPurposeError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            SetPurposeError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            SetPurposeError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            SetPurposeError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            SetPurposeError::MalformedResponse(ref e) => e.description(),
+            SetPurposeError::Unknown(ref s) => s,
+            SetPurposeError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            SetPurposeError::MalformedResponse(ref e) => Some(e),
+            SetPurposeError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Sets the topic for a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.setTopic
+
+pub fn set_topic<R>(
+    client: &R,
+    token: &str,
+    request: &SetTopicRequest,
+) -> Result<SetTopicResponse, SetTopicError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("channel", &request.channel[..])),
+        Some(("topic", &request.topic[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.setTopic");
+    client
+        .send(&url, &params[..])
+        .map_err(SetTopicError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetTopicResponse>(&result).map_err(
+                SetTopicError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetTopicRequest<'a> {
+    /// Conversation to set the topic of
+    pub channel: Cow<'a, str>,
+    /// The new topic string. Does not support formatting or linkification.
+    pub topic: Cow<'a, str>,
+}
+
+impl<'a> SetTopicRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        topic: impl Into<Cow<'a, str>>,
+    ) -> SetTopicRequestBuilder<'a> {
+        SetTopicRequestBuilder {
+            request: SetTopicRequest {
+                channel: channel.into(),
+                topic: topic.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`SetTopicRequest`](struct.SetTopicRequest.html), created by `SetTopicRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct SetTopicRequestBuilder<'a> {
+    request: SetTopicRequest<'a>,
+}
+
+impl<'a> SetTopicRequestBuilder<'a> {
+    pub fn build(self) -> SetTopicRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SetTopicResponse {
+    pub channel: Option<::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<SetTopicResponse, SetTopicError<E>>> for SetTopicResponse {
+    fn into(self) -> Result<SetTopicResponse, SetTopicError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetTopicError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// The conversation is archived.
+    IsArchived,
+    /// Topic was longer than 250 characters.
+    TooLong,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetTopicError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => SetTopicError::ChannelNotFound,
+            "not_in_channel" => SetTopicError::NotInChannel,
+            "is_archived" => SetTopicError::IsArchived,
+            "too_long" => SetTopicError::TooLong,
+            "user_is_restricted" => SetTopicError::UserIsRestricted,
+            "not_authed" => SetTopicError::NotAuthed,
+            "invalid_auth" => SetTopicError::InvalidAuth,
+            "account_inactive" => SetTopicError::AccountInactive,
+            "invalid_arg_name" => SetTopicError::InvalidArgName,
+            "invalid_array_arg" => SetTopicError::InvalidArrayArg,
+            "invalid_charset" => SetTopicError::InvalidCharset,
+            "invalid_form_data" => SetTopicError::InvalidFormData,
+            "invalid_post_type" => SetTopicError::InvalidPostType,
+            "missing_post_type" => SetTopicError::MissingPostType,
+            "team_added_to_org" => SetTopicError::TeamAddedToOrg,
+            "request_timeout" => SetTopicError::RequestTimeout,
+            _ => SetTopicError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetTopicError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for SetTopicError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            SetTopicError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            SetTopicError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            SetTopicError::IsArchived => "is_archived: The conversation is archived.",
+            SetTopicError::TooLong => "too_long: Topic was longer than 250 characters.",
+            SetTopicError::UserIsRestricted => {
+                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
+            }
+            SetTopicError::NotAuthed => "not_authed: No authentication token provided.",
+            SetTopicError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            SetTopicError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            SetTopicError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            SetTopicError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            SetTopicError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            SetTopicError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            SetTopicError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            SetTopicError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            SetTopicError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            SetTopicError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            SetTopicError::MalformedResponse(ref e) => e.description(),
+            SetTopicError::Unknown(ref s) => s,
+            SetTopicError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            SetTopicError::MalformedResponse(ref e) => Some(e),
+            SetTopicError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Reverses conversation archival.
+///
+/// Wraps https://api.slack.com/methods/conversations.unarchive
+
+pub fn unarchive<R>(
+    client: &R,
+    token: &str,
+    request: &UnarchiveRequest,
+) -> Result<UnarchiveResponse, UnarchiveError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("conversations.unarchive");
+    client
+        .send(&url, &params[..])
+        .map_err(UnarchiveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UnarchiveResponse>(&result).map_err(
+                UnarchiveError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UnarchiveRequest<'a> {
+    /// ID of conversation to unarchive
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> UnarchiveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> UnarchiveRequestBuilder<'a> {
+        UnarchiveRequestBuilder {
+            request: UnarchiveRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`UnarchiveRequest`](struct.UnarchiveRequest.html), created by `UnarchiveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct UnarchiveRequestBuilder<'a> {
+    request: UnarchiveRequest<'a>,
+}
+
+impl<'a> UnarchiveRequestBuilder<'a> {
+    pub fn build(self) -> UnarchiveRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UnarchiveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveResponse {
+    fn into(self) -> Result<UnarchiveResponse, UnarchiveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UnarchiveError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel is not archived.
+    NotArchived,
+    /// The calling user is not a member of the conversation.
+    NotInChannel,
+    /// This method cannot be called by a restricted user or single channel guest.
+    UserIsRestricted,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => UnarchiveError::ChannelNotFound,
+            "not_archived" => UnarchiveError::NotArchived,
+            "not_in_channel" => UnarchiveError::NotInChannel,
+            "user_is_restricted" => UnarchiveError::UserIsRestricted,
+            "not_authed" => UnarchiveError::NotAuthed,
+            "invalid_auth" => UnarchiveError::InvalidAuth,
+            "account_inactive" => UnarchiveError::AccountInactive,
+            "invalid_arg_name" => UnarchiveError::InvalidArgName,
+            "invalid_array_arg" => UnarchiveError::InvalidArrayArg,
+            "invalid_charset" => UnarchiveError::InvalidCharset,
+            "invalid_form_data" => UnarchiveError::InvalidFormData,
+            "invalid_post_type" => UnarchiveError::InvalidPostType,
+            "missing_post_type" => UnarchiveError::MissingPostType,
+            "team_added_to_org" => UnarchiveError::TeamAddedToOrg,
+            "request_timeout" => UnarchiveError::RequestTimeout,
+            _ => UnarchiveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for UnarchiveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UnarchiveError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            UnarchiveError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            UnarchiveError::NotArchived => "not_archived: Channel is not archived.",
+            UnarchiveError::NotInChannel => {
+                "not_in_channel: The calling user is not a member of the conversation."
+            }
+            UnarchiveError::UserIsRestricted => {
+                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
+            }
+            UnarchiveError::NotAuthed => "not_authed: No authentication token provided.",
+            UnarchiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            UnarchiveError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            UnarchiveError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            UnarchiveError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            UnarchiveError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            UnarchiveError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            UnarchiveError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            UnarchiveError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            UnarchiveError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            UnarchiveError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            UnarchiveError::MalformedResponse(ref e) => e.description(),
+            UnarchiveError::Unknown(ref s) => s,
+            UnarchiveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            UnarchiveError::MalformedResponse(ref e) => Some(e),
+            UnarchiveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Adjust and view Do Not Disturb settings for team members.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -35,6 +37,8 @@ pub struct EndDndResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -191,6 +195,8 @@ pub struct EndSnoozeResponse {
     #[serde(default)]
     ok: bool,
     pub snooze_enabled: Option<bool>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -338,7 +344,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("dnd.info");
@@ -354,7 +360,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// User to fetch status for (defaults to current user)
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> InfoRequestBuilder<'a> {
+        InfoRequestBuilder {
+            request: InfoRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`InfoRequest`](struct.InfoRequest.html), created by `InfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder<'a> {
+    request: InfoRequest<'a>,
+}
+
+impl<'a> InfoRequestBuilder<'a> {
+    /// User to fetch status for (defaults to current user)
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
+
+    pub fn build(self) -> InfoRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -368,6 +403,8 @@ pub struct InfoResponse {
     pub snooze_enabled: Option<bool>,
     pub snooze_endtime: Option<f32>,
     pub snooze_remaining: Option<f32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -524,6 +561,29 @@ pub struct SetSnoozeRequest {
     pub num_minutes: u32,
 }
 
+impl SetSnoozeRequest {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(num_minutes: u32) -> SetSnoozeRequestBuilder {
+        SetSnoozeRequestBuilder {
+            request: SetSnoozeRequest {
+                num_minutes,
+            },
+        }
+    }
+}
+
+/// Builder for [`SetSnoozeRequest`](struct.SetSnoozeRequest.html), created by `SetSnoozeRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct SetSnoozeRequestBuilder {
+    request: SetSnoozeRequest,
+}
+
+impl SetSnoozeRequestBuilder {
+    pub fn build(self) -> SetSnoozeRequest {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SetSnoozeResponse {
     error: Option<String>,
@@ -532,6 +592,8 @@ pub struct SetSnoozeResponse {
     pub snooze_enabled: Option<bool>,
     pub snooze_endtime: Option<f32>,
     pub snooze_remaining: Option<f32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -676,10 +738,10 @@ pub fn team_info<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let users = request.users.as_ref().map(|users| users.join(","));
     let params = vec![
         Some(("token", token)),
-        request.users.map(|users| ("users", users)),
+        users.as_ref().map(|users| ("users", &users[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("dnd.teamInfo");
@@ -696,8 +758,37 @@ where
 
 #[derive(Clone, Default, Debug)]
 pub struct TeamInfoRequest<'a> {
-    /// Comma-separated list of users to fetch Do Not Disturb status for
-    pub users: Option<&'a str>,
+    /// The users to fetch Do Not Disturb status for.
+    pub users: Option<Vec<Cow<'a, str>>>,
+}
+
+impl<'a> TeamInfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> TeamInfoRequestBuilder<'a> {
+        TeamInfoRequestBuilder {
+            request: TeamInfoRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`TeamInfoRequest`](struct.TeamInfoRequest.html), created by `TeamInfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct TeamInfoRequestBuilder<'a> {
+    request: TeamInfoRequest<'a>,
+}
+
+impl<'a> TeamInfoRequestBuilder<'a> {
+    /// The users to fetch Do Not Disturb status for.
+    pub fn users(mut self, users: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> Self {
+        self.request.users = Some(users.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn build(self) -> TeamInfoRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -706,6 +797,8 @@ pub struct TeamInfoResponse {
     #[serde(default)]
     ok: bool,
     pub users: Option<HashMap<String, bool>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
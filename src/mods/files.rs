@@ -1,6 +1,8 @@
 //! Get info on files uploaded to Slack, upload new files to Slack.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -11,6 +13,52 @@ use serde_json;
 
 use requests::SlackWebRequestSender;
 
+/// A type of file to filter a file listing by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileType {
+    /// Files of any type.
+    All,
+    /// Posts.
+    Spaces,
+    /// Snippets.
+    Snippets,
+    /// Image files.
+    Images,
+    /// Google docs.
+    Gdocs,
+    /// Zip files.
+    Zips,
+    /// PDF files.
+    Pdfs,
+}
+
+impl FileType {
+    /// The value sent to Slack for this variant.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            FileType::All => "all",
+            FileType::Spaces => "spaces",
+            FileType::Snippets => "snippets",
+            FileType::Images => "images",
+            FileType::Gdocs => "gdocs",
+            FileType::Zips => "zips",
+            FileType::Pdfs => "pdfs",
+        }
+    }
+}
+
+impl Default for FileType {
+    fn default() -> Self {
+        FileType::All
+    }
+}
+
+impl fmt::Display for FileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Deletes a file.
 ///
 /// Wraps https://api.slack.com/methods/files.delete
@@ -24,7 +72,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
+    let params = vec![Some(("token", token)), Some(("file", &request.file[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.delete");
     client
@@ -39,7 +87,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct DeleteRequest<'a> {
     /// ID of file to delete.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(file: impl Into<Cow<'a, str>>) -> DeleteRequestBuilder<'a> {
+        DeleteRequestBuilder {
+            request: DeleteRequest {
+                file: file.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`DeleteRequest`](struct.DeleteRequest.html), created by `DeleteRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct DeleteRequestBuilder<'a> {
+    request: DeleteRequest<'a>,
+}
+
+impl<'a> DeleteRequestBuilder<'a> {
+    pub fn build(self) -> DeleteRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -47,6 +118,8 @@ pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -193,11 +266,14 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
+    let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("file", request.file)),
+        Some(("file", &request.file[..])),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.info");
@@ -213,11 +289,75 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// Specify a file by providing its ID.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(file: impl Into<Cow<'a, str>>) -> InfoRequestBuilder<'a> {
+        InfoRequestBuilder {
+            request: InfoRequest {
+                file: file.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`InfoRequest`](struct.InfoRequest.html), created by `InfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder<'a> {
+    request: InfoRequest<'a>,
+}
+
+impl<'a> InfoRequestBuilder<'a> {
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> InfoRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for InfoRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for InfoResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -228,6 +368,9 @@ pub struct InfoResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -366,15 +509,18 @@ where
 {
     let ts_from = request.ts_from.map(|ts_from| ts_from.to_string());
     let ts_to = request.ts_to.map(|ts_to| ts_to.to_string());
+    let types = request.types.as_ref().map(|types| {
+        types.iter().map(FileType::as_str).collect::<Vec<_>>().join(",")
+    });
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
-        request.channel.map(|channel| ("channel", channel)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
         ts_from.as_ref().map(|ts_from| ("ts_from", &ts_from[..])),
         ts_to.as_ref().map(|ts_to| ("ts_to", &ts_to[..])),
-        request.types.map(|types| ("types", types)),
+        types.as_ref().map(|types| ("types", &types[..])),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -392,9 +538,9 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Filter files created by a single user.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// Filter files appearing in a specific channel, indicated by its ID.
-    pub channel: Option<&'a str>,
+    pub channel: Option<Cow<'a, str>>,
     /// Filter files created after this timestamp (inclusive).
     pub ts_from: Option<u32>,
     /// Filter files created before this timestamp (inclusive).
@@ -411,14 +557,109 @@ pub struct ListRequest<'a> {
     /// pdfs - PDF files
     ///
     ///
-    /// You can pass multiple values in the types argument, like types=spaces,snippets.The default value is all, which does not filter the list.
-    pub types: Option<&'a str>,
+    /// The types of file to include. Defaults to all, which does not filter the list.
+    pub types: Option<Cow<'a, [FileType]>>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
 }
 
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// Filter files created by a single user.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
+
+    /// Filter files appearing in a specific channel, indicated by its ID.
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
+
+    /// Filter files created after this timestamp (inclusive).
+    pub fn ts_from(mut self, ts_from: u32) -> Self {
+        self.request.ts_from = Some(ts_from);
+        self
+    }
+
+    /// Filter files created before this timestamp (inclusive).
+    pub fn ts_to(mut self, ts_to: u32) -> Self {
+        self.request.ts_to = Some(ts_to);
+        self
+    }
+
+    /// Filter files by type:
+    ///
+    ///
+    /// all - All files
+    /// spaces - Posts
+    /// snippets - Snippets
+    /// images - Image files
+    /// gdocs - Google docs
+    /// zips - Zip files
+    /// pdfs - PDF files
+    ///
+    ///
+    /// The types of file to include. Defaults to all, which does not filter the list.
+    pub fn types(mut self, types: impl Into<Cow<'a, [FileType]>>) -> Self {
+        self.request.types = Some(types.into());
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::PageRequest for ListRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl ::pagination::PageResponse for ListResponse {
+    type Item = ::File;
+
+    fn paging(&self) -> Option<&::Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<::File> {
+        self.files.unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
@@ -426,6 +667,8 @@ pub struct ListResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -567,7 +810,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
+    let params = vec![Some(("token", token)), Some(("file", &request.file[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.revokePublicURL");
     client
@@ -583,7 +826,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct RevokePublicURLRequest<'a> {
     /// File to revoke
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
+}
+
+impl<'a> RevokePublicURLRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(file: impl Into<Cow<'a, str>>) -> RevokePublicURLRequestBuilder<'a> {
+        RevokePublicURLRequestBuilder {
+            request: RevokePublicURLRequest {
+                file: file.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`RevokePublicURLRequest`](struct.RevokePublicURLRequest.html), created by `RevokePublicURLRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RevokePublicURLRequestBuilder<'a> {
+    request: RevokePublicURLRequest<'a>,
+}
+
+impl<'a> RevokePublicURLRequestBuilder<'a> {
+    pub fn build(self) -> RevokePublicURLRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -592,6 +858,8 @@ pub struct RevokePublicURLResponse {
     pub file: Option<::File>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -740,7 +1008,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
+    let params = vec![Some(("token", token)), Some(("file", &request.file[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.sharedPublicURL");
     client
@@ -756,7 +1024,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct SharedPublicURLRequest<'a> {
     /// File to share
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
+}
+
+impl<'a> SharedPublicURLRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(file: impl Into<Cow<'a, str>>) -> SharedPublicURLRequestBuilder<'a> {
+        SharedPublicURLRequestBuilder {
+            request: SharedPublicURLRequest {
+                file: file.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`SharedPublicURLRequest`](struct.SharedPublicURLRequest.html), created by `SharedPublicURLRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct SharedPublicURLRequestBuilder<'a> {
+    request: SharedPublicURLRequest<'a>,
+}
+
+impl<'a> SharedPublicURLRequestBuilder<'a> {
+    pub fn build(self) -> SharedPublicURLRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -765,6 +1056,8 @@ pub struct SharedPublicURLResponse {
     pub file: Option<::File>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
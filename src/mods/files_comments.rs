@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,8 +27,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("file", request.file)),
-        Some(("comment", request.comment)),
+        Some(("file", &request.file[..])),
+        Some(("comment", &request.comment[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.comments.add");
@@ -42,9 +44,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct AddRequest<'a> {
     /// File to add a comment to.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
     /// Text of the comment to add.
-    pub comment: &'a str,
+    pub comment: Cow<'a, str>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        file: impl Into<Cow<'a, str>>,
+        comment: impl Into<Cow<'a, str>>,
+    ) -> AddRequestBuilder<'a> {
+        AddRequestBuilder {
+            request: AddRequest {
+                file: file.into(),
+                comment: comment.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`AddRequest`](struct.AddRequest.html), created by `AddRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AddRequestBuilder<'a> {
+    request: AddRequest<'a>,
+}
+
+impl<'a> AddRequestBuilder<'a> {
+    pub fn build(self) -> AddRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -53,6 +82,8 @@ pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -196,8 +227,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("file", request.file)),
-        Some(("id", request.id)),
+        Some(("file", &request.file[..])),
+        Some(("id", &request.id[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.comments.delete");
@@ -213,9 +244,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct DeleteRequest<'a> {
     /// File to delete a comment from.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
     /// The comment to delete.
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        file: impl Into<Cow<'a, str>>,
+        id: impl Into<Cow<'a, str>>,
+    ) -> DeleteRequestBuilder<'a> {
+        DeleteRequestBuilder {
+            request: DeleteRequest {
+                file: file.into(),
+                id: id.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`DeleteRequest`](struct.DeleteRequest.html), created by `DeleteRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct DeleteRequestBuilder<'a> {
+    request: DeleteRequest<'a>,
+}
+
+impl<'a> DeleteRequestBuilder<'a> {
+    pub fn build(self) -> DeleteRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -223,6 +281,8 @@ pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -366,9 +426,9 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("file", request.file)),
-        Some(("id", request.id)),
-        Some(("comment", request.comment)),
+        Some(("file", &request.file[..])),
+        Some(("id", &request.id[..])),
+        Some(("comment", &request.comment[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("files.comments.edit");
@@ -384,11 +444,40 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct EditRequest<'a> {
     /// File containing the comment to edit.
-    pub file: &'a str,
+    pub file: Cow<'a, str>,
     /// The comment to edit.
-    pub id: &'a str,
+    pub id: Cow<'a, str>,
     /// Text of the comment to edit.
-    pub comment: &'a str,
+    pub comment: Cow<'a, str>,
+}
+
+impl<'a> EditRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        file: impl Into<Cow<'a, str>>,
+        id: impl Into<Cow<'a, str>>,
+        comment: impl Into<Cow<'a, str>>,
+    ) -> EditRequestBuilder<'a> {
+        EditRequestBuilder {
+            request: EditRequest {
+                file: file.into(),
+                id: id.into(),
+                comment: comment.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`EditRequest`](struct.EditRequest.html), created by `EditRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct EditRequestBuilder<'a> {
+    request: EditRequest<'a>,
+}
+
+impl<'a> EditRequestBuilder<'a> {
+    pub fn build(self) -> EditRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -397,6 +486,8 @@ pub struct EditResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
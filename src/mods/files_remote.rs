@@ -0,0 +1,1329 @@
+//! Add, update and share files that are stored outside of Slack.
+
+
+#[allow(unused_imports)]
+use std::borrow::Cow;
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Adds a file from a remote service
+///
+/// Wraps https://api.slack.com/methods/files.remote.add
+
+pub fn add<R>(
+    client: &R,
+    token: &str,
+    request: &AddRequest,
+) -> Result<AddResponse, AddError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        Some(("external_id", &request.external_id[..])),
+        Some(("external_url", &request.external_url[..])),
+        Some(("title", &request.title[..])),
+        request.filetype.as_ref().map(|filetype| ("filetype", &filetype[..])),
+        request.indexable_file_contents.as_ref().map(|indexable_file_contents| {
+            ("indexable_file_contents", &indexable_file_contents[..])
+        }),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("files.remote.add");
+    client
+        .send(&url, &params[..])
+        .map_err(AddError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AddResponse>(&result).map_err(AddError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AddRequest<'a> {
+    /// Creator defined GUID for the file.
+    pub external_id: Cow<'a, str>,
+    /// URL of the remote file.
+    pub external_url: Cow<'a, str>,
+    /// Title of the file being shared.
+    pub title: Cow<'a, str>,
+    /// type of file
+    pub filetype: Option<Cow<'a, str>>,
+    /// Text of the file, used to improve discovery of the remote file in search.
+    pub indexable_file_contents: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        external_id: impl Into<Cow<'a, str>>,
+        external_url: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+    ) -> AddRequestBuilder<'a> {
+        AddRequestBuilder {
+            request: AddRequest {
+                external_id: external_id.into(),
+                external_url: external_url.into(),
+                title: title.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AddRequest`](struct.AddRequest.html), created by `AddRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AddRequestBuilder<'a> {
+    request: AddRequest<'a>,
+}
+
+impl<'a> AddRequestBuilder<'a> {
+    /// type of file
+    pub fn filetype(mut self, filetype: impl Into<Cow<'a, str>>) -> Self {
+        self.request.filetype = Some(filetype.into());
+        self
+    }
+
+    /// Text of the file, used to improve discovery of the remote file in search.
+    pub fn indexable_file_contents(
+        mut self,
+        indexable_file_contents: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.request.indexable_file_contents = Some(indexable_file_contents.into());
+        self
+    }
+
+    pub fn build(self) -> AddRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddResponse {
+    error: Option<String>,
+    pub file: Option<::File>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
+    fn into(self) -> Result<AddResponse, AddError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddError<E: Error> {
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "not_authed" => AddError::NotAuthed,
+            "invalid_auth" => AddError::InvalidAuth,
+            "account_inactive" => AddError::AccountInactive,
+            "invalid_arg_name" => AddError::InvalidArgName,
+            "invalid_array_arg" => AddError::InvalidArrayArg,
+            "invalid_charset" => AddError::InvalidCharset,
+            "invalid_form_data" => AddError::InvalidFormData,
+            "invalid_post_type" => AddError::InvalidPostType,
+            "missing_post_type" => AddError::MissingPostType,
+            "team_added_to_org" => AddError::TeamAddedToOrg,
+            "request_timeout" => AddError::RequestTimeout,
+            _ => AddError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AddError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AddError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            AddError::NotAuthed => "not_authed: No authentication token provided.",
+            AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            AddError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            AddError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            AddError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            AddError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            AddError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            AddError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            AddError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            AddError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            AddError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            AddError::MalformedResponse(ref e) => e.description(),
+            AddError::Unknown(ref s) => s,
+            AddError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            AddError::MalformedResponse(ref e) => Some(e),
+            AddError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve information about a remote file added to Slack
+///
+/// Wraps https://api.slack.com/methods/files.remote.info
+
+pub fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        request.external_id.as_ref().map(|external_id| ("external_id", &external_id[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("files.remote.info");
+    client
+        .send(&url, &params[..])
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequest<'a> {
+    /// Creator defined GUID for the file.
+    pub external_id: Option<Cow<'a, str>>,
+    /// Specify a file by providing its ID.
+    pub file: Option<Cow<'a, str>>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> InfoRequestBuilder<'a> {
+        InfoRequestBuilder {
+            request: InfoRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`InfoRequest`](struct.InfoRequest.html), created by `InfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder<'a> {
+    request: InfoRequest<'a>,
+}
+
+impl<'a> InfoRequestBuilder<'a> {
+    /// Creator defined GUID for the file.
+    pub fn external_id(mut self, external_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Specify a file by providing its ID.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
+    pub fn build(self) -> InfoRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InfoResponse {
+    error: Option<String>,
+    pub file: Option<::File>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
+    fn into(self) -> Result<InfoResponse, InfoError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InfoError<E: Error> {
+    /// The file was not found.
+    FileNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InfoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "file_not_found" => InfoError::FileNotFound,
+            "not_authed" => InfoError::NotAuthed,
+            "invalid_auth" => InfoError::InvalidAuth,
+            "account_inactive" => InfoError::AccountInactive,
+            "invalid_arg_name" => InfoError::InvalidArgName,
+            "invalid_array_arg" => InfoError::InvalidArrayArg,
+            "invalid_charset" => InfoError::InvalidCharset,
+            "invalid_form_data" => InfoError::InvalidFormData,
+            "invalid_post_type" => InfoError::InvalidPostType,
+            "missing_post_type" => InfoError::MissingPostType,
+            "team_added_to_org" => InfoError::TeamAddedToOrg,
+            "request_timeout" => InfoError::RequestTimeout,
+            _ => InfoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for InfoError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            InfoError::FileNotFound => "file_not_found: The file was not found.",
+            InfoError::NotAuthed => "not_authed: No authentication token provided.",
+            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            InfoError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            InfoError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            InfoError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            InfoError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            InfoError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            InfoError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            InfoError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            InfoError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            InfoError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            InfoError::MalformedResponse(ref e) => e.description(),
+            InfoError::Unknown(ref s) => s,
+            InfoError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            InfoError::MalformedResponse(ref e) => Some(e),
+            InfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Retrieve information about remote files added to Slack
+///
+/// Wraps https://api.slack.com/methods/files.remote.list
+
+pub fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = vec![
+        Some(("token", token)),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request.ts_from.as_ref().map(|ts_from| ("ts_from", &ts_from[..])),
+        request.ts_to.as_ref().map(|ts_to| ("ts_to", &ts_to[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("files.remote.list");
+    client
+        .send(&url, &params[..])
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Filter files appearing in a specific channel, indicated by its ID.
+    pub channel: Option<Cow<'a, str>>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return.
+    pub limit: Option<u32>,
+    /// Filter files created after this timestamp (inclusive).
+    pub ts_from: Option<Cow<'a, str>>,
+    /// Filter files created before this timestamp (inclusive).
+    pub ts_to: Option<Cow<'a, str>>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// Filter files appearing in a specific channel, indicated by its ID.
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
+
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// Filter files created after this timestamp (inclusive).
+    pub fn ts_from(mut self, ts_from: impl Into<Cow<'a, str>>) -> Self {
+        self.request.ts_from = Some(ts_from.into());
+        self
+    }
+
+    /// Filter files created before this timestamp (inclusive).
+    pub fn ts_to(mut self, ts_to: impl Into<Cow<'a, str>>) -> Self {
+        self.request.ts_to = Some(ts_to.into());
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for ListRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for ListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListResponse {
+    error: Option<String>,
+    pub files: Option<Vec<::File>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
+    fn into(self) -> Result<ListResponse, ListError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// The channel passed is invalid
+    InvalidChannel,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_channel" => ListError::InvalidChannel,
+            "invalid_cursor" => ListError::InvalidCursor,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ListError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ListError::InvalidChannel => "invalid_channel: The channel passed is invalid",
+            ListError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
+            ListError::NotAuthed => "not_authed: No authentication token provided.",
+            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            ListError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            ListError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            ListError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            ListError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            ListError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            ListError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            ListError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            ListError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            ListError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            ListError::MalformedResponse(ref e) => e.description(),
+            ListError::Unknown(ref s) => s,
+            ListError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            ListError::MalformedResponse(ref e) => Some(e),
+            ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Remove a remote file.
+///
+/// Wraps https://api.slack.com/methods/files.remote.remove
+
+pub fn remove<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveRequest,
+) -> Result<RemoveResponse, RemoveError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        request.external_id.as_ref().map(|external_id| ("external_id", &external_id[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("files.remote.remove");
+    client
+        .send(&url, &params[..])
+        .map_err(RemoveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RemoveResponse>(&result).map_err(RemoveError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveRequest<'a> {
+    /// Creator defined GUID for the file.
+    pub external_id: Option<Cow<'a, str>>,
+    /// Specify a file by providing its ID.
+    pub file: Option<Cow<'a, str>>,
+}
+
+impl<'a> RemoveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> RemoveRequestBuilder<'a> {
+        RemoveRequestBuilder {
+            request: RemoveRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`RemoveRequest`](struct.RemoveRequest.html), created by `RemoveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RemoveRequestBuilder<'a> {
+    request: RemoveRequest<'a>,
+}
+
+impl<'a> RemoveRequestBuilder<'a> {
+    /// Creator defined GUID for the file.
+    pub fn external_id(mut self, external_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Specify a file by providing its ID.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
+    pub fn build(self) -> RemoveRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RemoveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
+    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveError<E: Error> {
+    /// The file was not found.
+    FileNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "file_not_found" => RemoveError::FileNotFound,
+            "not_authed" => RemoveError::NotAuthed,
+            "invalid_auth" => RemoveError::InvalidAuth,
+            "account_inactive" => RemoveError::AccountInactive,
+            "invalid_arg_name" => RemoveError::InvalidArgName,
+            "invalid_array_arg" => RemoveError::InvalidArrayArg,
+            "invalid_charset" => RemoveError::InvalidCharset,
+            "invalid_form_data" => RemoveError::InvalidFormData,
+            "invalid_post_type" => RemoveError::InvalidPostType,
+            "missing_post_type" => RemoveError::MissingPostType,
+            "team_added_to_org" => RemoveError::TeamAddedToOrg,
+            "request_timeout" => RemoveError::RequestTimeout,
+            _ => RemoveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for RemoveError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            RemoveError::FileNotFound => "file_not_found: The file was not found.",
+            RemoveError::NotAuthed => "not_authed: No authentication token provided.",
+            RemoveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            RemoveError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            RemoveError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            RemoveError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            RemoveError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            RemoveError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            RemoveError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            RemoveError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            RemoveError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            RemoveError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            RemoveError::MalformedResponse(ref e) => e.description(),
+            RemoveError::Unknown(ref s) => s,
+            RemoveError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            RemoveError::MalformedResponse(ref e) => Some(e),
+            RemoveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Share a remote file into a channel.
+///
+/// Wraps https://api.slack.com/methods/files.remote.share
+
+pub fn share<R>(
+    client: &R,
+    token: &str,
+    request: &ShareRequest,
+) -> Result<ShareResponse, ShareError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let channels = request.channels.join(",");
+    let params = vec![
+        Some(("token", token)),
+        Some(("channels", &channels[..])),
+        request.external_id.as_ref().map(|external_id| ("external_id", &external_id[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("files.remote.share");
+    client
+        .send(&url, &params[..])
+        .map_err(ShareError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ShareResponse>(&result).map_err(ShareError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ShareRequest<'a> {
+    /// The IDs of the channels where the file will be shared.
+    pub channels: Vec<Cow<'a, str>>,
+    /// Creator defined GUID for the file.
+    pub external_id: Option<Cow<'a, str>>,
+    /// Specify a file by providing its ID.
+    pub file: Option<Cow<'a, str>>,
+}
+
+impl<'a> ShareRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channels: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> ShareRequestBuilder<'a> {
+        ShareRequestBuilder {
+            request: ShareRequest {
+                channels: channels.into_iter().map(Into::into).collect(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ShareRequest`](struct.ShareRequest.html), created by `ShareRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ShareRequestBuilder<'a> {
+    request: ShareRequest<'a>,
+}
+
+impl<'a> ShareRequestBuilder<'a> {
+    /// Creator defined GUID for the file.
+    pub fn external_id(mut self, external_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Specify a file by providing its ID.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
+    pub fn build(self) -> ShareRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ShareResponse {
+    error: Option<String>,
+    pub file: Option<::File>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<ShareResponse, ShareError<E>>> for ShareResponse {
+    fn into(self) -> Result<ShareResponse, ShareError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ShareError<E: Error> {
+    /// The file was not found.
+    FileNotFound,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ShareError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "file_not_found" => ShareError::FileNotFound,
+            "channel_not_found" => ShareError::ChannelNotFound,
+            "not_authed" => ShareError::NotAuthed,
+            "invalid_auth" => ShareError::InvalidAuth,
+            "account_inactive" => ShareError::AccountInactive,
+            "invalid_arg_name" => ShareError::InvalidArgName,
+            "invalid_array_arg" => ShareError::InvalidArrayArg,
+            "invalid_charset" => ShareError::InvalidCharset,
+            "invalid_form_data" => ShareError::InvalidFormData,
+            "invalid_post_type" => ShareError::InvalidPostType,
+            "missing_post_type" => ShareError::MissingPostType,
+            "team_added_to_org" => ShareError::TeamAddedToOrg,
+            "request_timeout" => ShareError::RequestTimeout,
+            _ => ShareError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ShareError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ShareError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ShareError::FileNotFound => "file_not_found: The file was not found.",
+            ShareError::ChannelNotFound => {
+                "channel_not_found: Value passed for channel was invalid."
+            }
+            ShareError::NotAuthed => "not_authed: No authentication token provided.",
+            ShareError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            ShareError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            ShareError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            ShareError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            ShareError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            ShareError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            ShareError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            ShareError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            ShareError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            ShareError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            ShareError::MalformedResponse(ref e) => e.description(),
+            ShareError::Unknown(ref s) => s,
+            ShareError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            ShareError::MalformedResponse(ref e) => Some(e),
+            ShareError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Updates an existing remote file.
+///
+/// Wraps https://api.slack.com/methods/files.remote.update
+
+pub fn update<R>(
+    client: &R,
+    token: &str,
+    request: &UpdateRequest,
+) -> Result<UpdateResponse, UpdateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("token", token)),
+        request.external_id.as_ref().map(|external_id| ("external_id", &external_id[..])),
+        request.external_url.as_ref().map(|external_url| ("external_url", &external_url[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.filetype.as_ref().map(|filetype| ("filetype", &filetype[..])),
+        request.indexable_file_contents.as_ref().map(|indexable_file_contents| {
+            ("indexable_file_contents", &indexable_file_contents[..])
+        }),
+        request.title.as_ref().map(|title| ("title", &title[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("files.remote.update");
+    client
+        .send(&url, &params[..])
+        .map_err(UpdateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UpdateResponse>(&result).map_err(UpdateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UpdateRequest<'a> {
+    /// Creator defined GUID for the file.
+    pub external_id: Option<Cow<'a, str>>,
+    /// URL of the remote file.
+    pub external_url: Option<Cow<'a, str>>,
+    /// Specify a file by providing its ID.
+    pub file: Option<Cow<'a, str>>,
+    /// type of file
+    pub filetype: Option<Cow<'a, str>>,
+    /// Text of the file, used to improve discovery of the remote file in search.
+    pub indexable_file_contents: Option<Cow<'a, str>>,
+    /// Title of the file being shared.
+    pub title: Option<Cow<'a, str>>,
+}
+
+impl<'a> UpdateRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> UpdateRequestBuilder<'a> {
+        UpdateRequestBuilder {
+            request: UpdateRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`UpdateRequest`](struct.UpdateRequest.html), created by `UpdateRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct UpdateRequestBuilder<'a> {
+    request: UpdateRequest<'a>,
+}
+
+impl<'a> UpdateRequestBuilder<'a> {
+    /// Creator defined GUID for the file.
+    pub fn external_id(mut self, external_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.external_id = Some(external_id.into());
+        self
+    }
+
+    /// URL of the remote file.
+    pub fn external_url(mut self, external_url: impl Into<Cow<'a, str>>) -> Self {
+        self.request.external_url = Some(external_url.into());
+        self
+    }
+
+    /// Specify a file by providing its ID.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
+    /// type of file
+    pub fn filetype(mut self, filetype: impl Into<Cow<'a, str>>) -> Self {
+        self.request.filetype = Some(filetype.into());
+        self
+    }
+
+    /// Text of the file, used to improve discovery of the remote file in search.
+    pub fn indexable_file_contents(
+        mut self,
+        indexable_file_contents: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.request.indexable_file_contents = Some(indexable_file_contents.into());
+        self
+    }
+
+    /// Title of the file being shared.
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.request.title = Some(title.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateResponse {
+    error: Option<String>,
+    pub file: Option<::File>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
+    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UpdateError<E: Error> {
+    /// The file was not found.
+    FileNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UpdateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "file_not_found" => UpdateError::FileNotFound,
+            "not_authed" => UpdateError::NotAuthed,
+            "invalid_auth" => UpdateError::InvalidAuth,
+            "account_inactive" => UpdateError::AccountInactive,
+            "invalid_arg_name" => UpdateError::InvalidArgName,
+            "invalid_array_arg" => UpdateError::InvalidArrayArg,
+            "invalid_charset" => UpdateError::InvalidCharset,
+            "invalid_form_data" => UpdateError::InvalidFormData,
+            "invalid_post_type" => UpdateError::InvalidPostType,
+            "missing_post_type" => UpdateError::MissingPostType,
+            "team_added_to_org" => UpdateError::TeamAddedToOrg,
+            "request_timeout" => UpdateError::RequestTimeout,
+            _ => UpdateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for UpdateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UpdateError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateError::FileNotFound => "file_not_found: The file was not found.",
+            UpdateError::NotAuthed => "not_authed: No authentication token provided.",
+            UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            UpdateError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            UpdateError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            UpdateError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            UpdateError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            UpdateError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            UpdateError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            UpdateError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            UpdateError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            UpdateError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            UpdateError::MalformedResponse(ref e) => e.description(),
+            UpdateError::Unknown(ref s) => s,
+            UpdateError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            UpdateError::MalformedResponse(ref e) => Some(e),
+            UpdateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
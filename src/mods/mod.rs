@@ -3,14 +3,18 @@ pub mod auth;
 pub mod bots;
 pub mod channels;
 pub mod chat;
+pub mod chat_scheduled_messages;
+pub mod conversations;
 pub mod dnd;
 pub mod emoji;
 pub mod files_comments;
 pub mod files;
+pub mod files_remote;
 pub mod groups;
 pub mod im;
 pub mod mpim;
 pub mod oauth;
+pub mod oauth_v2;
 pub mod pins;
 pub mod reactions;
 pub mod reminders;
@@ -22,4 +26,5 @@ pub mod team_profile;
 pub mod usergroups;
 pub mod usergroups_users;
 pub mod users;
-pub mod users_profile;
\ No newline at end of file
+pub mod users_profile;
+pub mod views;
\ No newline at end of file
@@ -1,6 +1,8 @@
 //! Get info on your multiparty direct messages.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -24,7 +26,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("mpim.close");
     client
@@ -39,7 +41,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct CloseRequest<'a> {
     /// MPIM to close.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
+}
+
+impl<'a> CloseRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> CloseRequestBuilder<'a> {
+        CloseRequestBuilder {
+            request: CloseRequest {
+                channel: channel.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`CloseRequest`](struct.CloseRequest.html), created by `CloseRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct CloseRequestBuilder<'a> {
+    request: CloseRequest<'a>,
+}
+
+impl<'a> CloseRequestBuilder<'a> {
+    pub fn build(self) -> CloseRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -47,6 +72,8 @@ pub struct CloseResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -184,9 +211,9 @@ where
     let count = request.count.map(|count| count.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.latest.map(|latest| ("latest", latest)),
-        request.oldest.map(|oldest| ("oldest", oldest)),
+        Some(("channel", &request.channel[..])),
+        request.latest.as_ref().map(|latest| ("latest", &latest[..])),
+        request.oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
         request.inclusive.map(|inclusive| {
             ("inclusive", if inclusive { "1" } else { "0" })
         }),
@@ -211,11 +238,11 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct HistoryRequest<'a> {
     /// Multiparty direct message to fetch history for.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// End of time range of messages to include in results.
-    pub latest: Option<&'a str>,
+    pub latest: Option<Cow<'a, str>>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<&'a str>,
+    pub oldest: Option<Cow<'a, str>>,
     /// Include messages with latest or oldest timestamp in results.
     pub inclusive: Option<bool>,
     /// Number of messages to return, between 1 and 1000.
@@ -224,6 +251,76 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
+impl<'a> HistoryRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> HistoryRequestBuilder<'a> {
+        HistoryRequestBuilder {
+            request: HistoryRequest {
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`HistoryRequest`](struct.HistoryRequest.html), created by `HistoryRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct HistoryRequestBuilder<'a> {
+    request: HistoryRequest<'a>,
+}
+
+impl<'a> HistoryRequestBuilder<'a> {
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.latest = Some(latest.into());
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: impl Into<Cow<'a, str>>) -> Self {
+        self.request.oldest = Some(oldest.into());
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.request.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Number of messages to return, between 1 and 1000.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Include unread_count_display in the output?
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.request.unreads = Some(unreads);
+        self
+    }
+
+    pub fn build(self) -> HistoryRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::HistoryRequest for HistoryRequest<'a> {
+    fn set_latest(&mut self, latest: String) {
+        self.latest = Some(latest.into());
+    }
+}
+
+impl ::pagination::HistoryResponse for HistoryResponse {
+    fn has_more(&self) -> bool {
+        self.has_more.unwrap_or(false)
+    }
+
+    fn into_messages(self) -> Vec<::Message> {
+        self.messages.unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct HistoryResponse {
     error: Option<String>,
@@ -232,6 +329,8 @@ pub struct HistoryResponse {
     pub messages: Option<Vec<::Message>>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -370,11 +469,21 @@ impl<E: Error> Error for HistoryError<E> {
 ///
 /// Wraps https://api.slack.com/methods/mpim.list
 
-pub fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
+pub fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = vec![
+        Some(("token", token)),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("mpim.list");
     client
         .send(&url, &params[..])
@@ -385,12 +494,70 @@ where
         .and_then(|o| o.into())
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for ListRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for ListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub groups: Option<Vec<::Mpim>>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -522,8 +689,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("ts", &request.ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("mpim.mark");
@@ -539,9 +706,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct MarkRequest<'a> {
     /// multiparty direct message channel to set reading cursor in.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Timestamp of the most recently seen message.
-    pub ts: &'a str,
+    pub ts: Cow<'a, str>,
+}
+
+impl<'a> MarkRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        ts: impl Into<Cow<'a, str>>,
+    ) -> MarkRequestBuilder<'a> {
+        MarkRequestBuilder {
+            request: MarkRequest {
+                channel: channel.into(),
+                ts: ts.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`MarkRequest`](struct.MarkRequest.html), created by `MarkRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct MarkRequestBuilder<'a> {
+    request: MarkRequest<'a>,
+}
+
+impl<'a> MarkRequestBuilder<'a> {
+    pub fn build(self) -> MarkRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -549,6 +743,8 @@ pub struct MarkResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -689,8 +885,11 @@ pub fn open<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("users", request.users))];
+    if request.users.len() > 8 {
+        return Err(OpenError::TooManyUsers);
+    }
+    let users = request.users.join(",");
+    let params = vec![Some(("token", token)), Some(("users", &users[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("mpim.open");
     client
@@ -704,8 +903,33 @@ where
 
 #[derive(Clone, Default, Debug)]
 pub struct OpenRequest<'a> {
-    /// Comma separated lists of users.  The ordering of the users is preserved whenever a MPIM group is returned.
-    pub users: &'a str,
+    /// The users to open a multi-person direct message with. The ordering of the users is preserved whenever a MPIM group is returned.
+    pub users: Vec<Cow<'a, str>>,
+}
+
+impl<'a> OpenRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        users: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> OpenRequestBuilder<'a> {
+        OpenRequestBuilder {
+            request: OpenRequest {
+                users: users.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+/// Builder for [`OpenRequest`](struct.OpenRequest.html), created by `OpenRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct OpenRequestBuilder<'a> {
+    request: OpenRequest<'a>,
+}
+
+impl<'a> OpenRequestBuilder<'a> {
+    pub fn build(self) -> OpenRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -714,6 +938,8 @@ pub struct OpenResponse {
     pub group: Option<::Mpim>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -857,8 +1083,8 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("thread_ts", request.thread_ts)),
+        Some(("channel", &request.channel[..])),
+        Some(("thread_ts", &request.thread_ts[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("mpim.replies");
@@ -876,9 +1102,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct RepliesRequest<'a> {
     /// Multiparty direct message channel to fetch thread from.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// Unique identifier of a thread's parent message.
-    pub thread_ts: &'a str,
+    pub thread_ts: Cow<'a, str>,
+}
+
+impl<'a> RepliesRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        channel: impl Into<Cow<'a, str>>,
+        thread_ts: impl Into<Cow<'a, str>>,
+    ) -> RepliesRequestBuilder<'a> {
+        RepliesRequestBuilder {
+            request: RepliesRequest {
+                channel: channel.into(),
+                thread_ts: thread_ts.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`RepliesRequest`](struct.RepliesRequest.html), created by `RepliesRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RepliesRequestBuilder<'a> {
+    request: RepliesRequest<'a>,
+}
+
+impl<'a> RepliesRequestBuilder<'a> {
+    pub fn build(self) -> RepliesRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -888,6 +1141,8 @@ pub struct RepliesResponse {
     #[serde(default)]
     ok: bool,
     pub thread_info: Option<::ThreadInfo>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -23,12 +25,10 @@ where
 {
 
     let params = vec![
-        Some(("client_id", request.client_id)),
-        Some(("client_secret", request.client_secret)),
-        Some(("code", request.code)),
-        request.redirect_uri.map(|redirect_uri| {
-            ("redirect_uri", redirect_uri)
-        }),
+        Some(("client_id", &request.client_id[..])),
+        Some(("client_secret", &request.client_secret[..])),
+        Some(("code", &request.code[..])),
+        request.redirect_uri.as_ref().map(|redirect_uri| ("redirect_uri", &redirect_uri[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("oauth.access");
@@ -38,28 +38,102 @@ where
         .and_then(|result| {
             serde_json::from_str::<AccessResponse>(&result).map_err(AccessError::MalformedResponse)
         })
+        .and_then(|o| o.into())
 }
 
 #[derive(Clone, Default, Debug)]
 pub struct AccessRequest<'a> {
     /// Issued when you created your application.
-    pub client_id: &'a str,
+    pub client_id: Cow<'a, str>,
     /// Issued when you created your application.
-    pub client_secret: &'a str,
+    pub client_secret: Cow<'a, str>,
     /// The code param returned via the OAuth callback.
-    pub code: &'a str,
+    pub code: Cow<'a, str>,
     /// This must match the originally submitted URI (if one was sent).
-    pub redirect_uri: Option<&'a str>,
+    pub redirect_uri: Option<Cow<'a, str>>,
+}
+
+impl<'a> AccessRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        client_id: impl Into<Cow<'a, str>>,
+        client_secret: impl Into<Cow<'a, str>>,
+        code: impl Into<Cow<'a, str>>,
+    ) -> AccessRequestBuilder<'a> {
+        AccessRequestBuilder {
+            request: AccessRequest {
+                client_id: client_id.into(),
+                client_secret: client_secret.into(),
+                code: code.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AccessRequest`](struct.AccessRequest.html), created by `AccessRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AccessRequestBuilder<'a> {
+    request: AccessRequest<'a>,
+}
+
+impl<'a> AccessRequestBuilder<'a> {
+    /// This must match the originally submitted URI (if one was sent).
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<Cow<'a, str>>) -> Self {
+        self.request.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    pub fn build(self) -> AccessRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct AccessResponse {
     pub access_token: Option<String>,
+    pub bot: Option<AccessResponseBot>,
+    pub enterprise_id: Option<String>,
+    error: Option<String>,
+    pub incoming_webhook: Option<AccessResponseIncomingWebhook>,
+    #[serde(default)]
+    ok: bool,
     pub scope: Option<String>,
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+    pub user_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseBot {
+    pub bot_access_token: Option<String>,
+    pub bot_user_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseIncomingWebhook {
+    pub channel: Option<String>,
+    pub channel_id: Option<String>,
+    pub configuration_url: Option<String>,
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
 
 
+impl<E: Error> Into<Result<AccessResponse, AccessError<E>>> for AccessResponse {
+    fn into(self) -> Result<AccessResponse, AccessError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
 #[derive(Debug)]
 pub enum AccessError<E: Error> {
     /// Value passed for client_id was invalid.
@@ -0,0 +1,337 @@
+//! Exchange OAuth codes and refresh tokens for access tokens.
+
+
+#[allow(unused_imports)]
+use std::borrow::Cow;
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// How an OAuth access token is granted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GrantType {
+    /// Exchange the code returned to the redirect URI.
+    AuthorizationCode,
+    /// Exchange a refresh token for a new access token.
+    RefreshToken,
+}
+
+impl GrantType {
+    /// The value sent to Slack for this variant.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            GrantType::AuthorizationCode => "authorization_code",
+            GrantType::RefreshToken => "refresh_token",
+        }
+    }
+}
+
+impl Default for GrantType {
+    fn default() -> Self {
+        GrantType::AuthorizationCode
+    }
+}
+
+impl fmt::Display for GrantType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Exchanges a temporary OAuth verifier code for an access token.
+///
+/// Wraps https://api.slack.com/methods/oauth.v2.access
+
+pub fn access<R>(
+    client: &R,
+    request: &AccessRequest,
+) -> Result<AccessResponse, AccessError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![
+        Some(("client_id", &request.client_id[..])),
+        Some(("client_secret", &request.client_secret[..])),
+        request.code.as_ref().map(|code| ("code", &code[..])),
+        request.grant_type.map(|grant_type| ("grant_type", grant_type.as_str())),
+        request.redirect_uri.as_ref().map(|redirect_uri| ("redirect_uri", &redirect_uri[..])),
+        request.refresh_token.as_ref().map(|refresh_token| ("refresh_token", &refresh_token[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("oauth.v2.access");
+    client
+        .send(&url, &params[..])
+        .map_err(AccessError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AccessResponse>(&result).map_err(AccessError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AccessRequest<'a> {
+    /// Issued when you created your application.
+    pub client_id: Cow<'a, str>,
+    /// Issued when you created your application.
+    pub client_secret: Cow<'a, str>,
+    /// The code param returned via the OAuth callback.
+    pub code: Option<Cow<'a, str>>,
+    /// The grant_type param as described in the OAuth spec.
+    pub grant_type: Option<GrantType>,
+    /// This must match the originally submitted URI (if one was sent).
+    pub redirect_uri: Option<Cow<'a, str>>,
+    /// The refresh_token param as described in the OAuth spec.
+    pub refresh_token: Option<Cow<'a, str>>,
+}
+
+impl<'a> AccessRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        client_id: impl Into<Cow<'a, str>>,
+        client_secret: impl Into<Cow<'a, str>>,
+    ) -> AccessRequestBuilder<'a> {
+        AccessRequestBuilder {
+            request: AccessRequest {
+                client_id: client_id.into(),
+                client_secret: client_secret.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AccessRequest`](struct.AccessRequest.html), created by `AccessRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AccessRequestBuilder<'a> {
+    request: AccessRequest<'a>,
+}
+
+impl<'a> AccessRequestBuilder<'a> {
+    /// The code param returned via the OAuth callback.
+    pub fn code(mut self, code: impl Into<Cow<'a, str>>) -> Self {
+        self.request.code = Some(code.into());
+        self
+    }
+
+    /// The grant_type param as described in the OAuth spec.
+    pub fn grant_type(mut self, grant_type: GrantType) -> Self {
+        self.request.grant_type = Some(grant_type);
+        self
+    }
+
+    /// This must match the originally submitted URI (if one was sent).
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<Cow<'a, str>>) -> Self {
+        self.request.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// The refresh_token param as described in the OAuth spec.
+    pub fn refresh_token(mut self, refresh_token: impl Into<Cow<'a, str>>) -> Self {
+        self.request.refresh_token = Some(refresh_token.into());
+        self
+    }
+
+    pub fn build(self) -> AccessRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponse {
+    pub access_token: Option<String>,
+    pub app_id: Option<String>,
+    pub authed_user: Option<AccessResponseAuthedUser>,
+    pub bot_user_id: Option<String>,
+    pub enterprise: Option<AccessResponseEnterprise>,
+    error: Option<String>,
+    pub expires_in: Option<i32>,
+    pub incoming_webhook: Option<AccessResponseIncomingWebhook>,
+    pub is_enterprise_install: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+    pub team: Option<AccessResponseTeam>,
+    pub token_type: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseAuthedUser {
+    pub access_token: Option<String>,
+    pub expires_in: Option<i32>,
+    pub id: Option<String>,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+    pub token_type: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseEnterprise {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseIncomingWebhook {
+    pub channel: Option<String>,
+    pub channel_id: Option<String>,
+    pub configuration_url: Option<String>,
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessResponseTeam {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<AccessResponse, AccessError<E>>> for AccessResponse {
+    fn into(self) -> Result<AccessResponse, AccessError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AccessError<E: Error> {
+    /// Value passed for client_id was invalid.
+    InvalidClientId,
+    /// Value passed for client_secret was invalid.
+    BadClientSecret,
+    /// Value passed for code was invalid.
+    InvalidCode,
+    /// Value passed for redirect_uri did not match the redirect_uri in the original request.
+    BadRedirectUri,
+    /// Value passed for grant_type was invalid.
+    InvalidGrantType,
+    /// The given refresh token is invalid.
+    InvalidRefreshToken,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AccessError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_client_id" => AccessError::InvalidClientId,
+            "bad_client_secret" => AccessError::BadClientSecret,
+            "invalid_code" => AccessError::InvalidCode,
+            "bad_redirect_uri" => AccessError::BadRedirectUri,
+            "invalid_grant_type" => AccessError::InvalidGrantType,
+            "invalid_refresh_token" => AccessError::InvalidRefreshToken,
+            "invalid_arg_name" => AccessError::InvalidArgName,
+            "invalid_array_arg" => AccessError::InvalidArrayArg,
+            "invalid_charset" => AccessError::InvalidCharset,
+            "invalid_form_data" => AccessError::InvalidFormData,
+            "invalid_post_type" => AccessError::InvalidPostType,
+            "missing_post_type" => AccessError::MissingPostType,
+            "team_added_to_org" => AccessError::TeamAddedToOrg,
+            "request_timeout" => AccessError::RequestTimeout,
+            _ => AccessError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AccessError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for AccessError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            AccessError::InvalidClientId => {
+                "invalid_client_id: Value passed for client_id was invalid."
+            }
+            AccessError::BadClientSecret => {
+                "bad_client_secret: Value passed for client_secret was invalid."
+            }
+            AccessError::InvalidCode => "invalid_code: Value passed for code was invalid.",
+            AccessError::BadRedirectUri => {
+                "bad_redirect_uri: Value passed for redirect_uri did not match the redirect_uri in the original request."
+            }
+            AccessError::InvalidGrantType => {
+                "invalid_grant_type: Value passed for grant_type was invalid."
+            }
+            AccessError::InvalidRefreshToken => {
+                "invalid_refresh_token: The given refresh token is invalid."
+            }
+            AccessError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            AccessError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            AccessError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            AccessError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            AccessError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            AccessError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            AccessError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            AccessError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            AccessError::MalformedResponse(ref e) => e.description(),
+            AccessError::Unknown(ref s) => s,
+            AccessError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            AccessError::MalformedResponse(ref e) => Some(e),
+            AccessError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,12 +27,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        Some(("channel", &request.channel[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("pins.add");
@@ -46,13 +46,55 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct AddRequest<'a> {
     /// Channel to pin the item in.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// File to pin.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
+    /// File comment to pin.
+    pub file_comment: Option<Cow<'a, str>>,
+    /// Timestamp of the message to pin.
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> AddRequestBuilder<'a> {
+        AddRequestBuilder {
+            request: AddRequest {
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AddRequest`](struct.AddRequest.html), created by `AddRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AddRequestBuilder<'a> {
+    request: AddRequest<'a>,
+}
+
+impl<'a> AddRequestBuilder<'a> {
+    /// File to pin.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
     /// File comment to pin.
-    pub file_comment: Option<&'a str>,
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file_comment = Some(file_comment.into());
+        self
+    }
+
     /// Timestamp of the message to pin.
-    pub timestamp: Option<&'a str>,
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.request.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn build(self) -> AddRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -60,6 +102,8 @@ pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -239,7 +283,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
+    let params = vec![Some(("token", token)), Some(("channel", &request.channel[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("pins.list");
     client
@@ -254,95 +298,40 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Channel to get pinned items for.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponse {
-    error: Option<String>,
-    pub items: Option<Vec<ListResponseItem>>,
-    #[serde(default)]
-    ok: bool,
-}
-
-#[derive(Clone, Debug)]
-pub enum ListResponseItem {
-    Message(ListResponseItemMessage),
-    File(ListResponseItemFile),
-    FileComment(ListResponseItemFileComment),
-}
-
-impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: ::serde::Deserializer<'de>,
-    {
-        use serde::de::Error as SerdeError;
-
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
-                            .map(ListResponseItem::Message)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<ListResponseItemFile>(value.clone())
-                            .map(ListResponseItem::File)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
-                            .map(ListResponseItem::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(
-                    ::serde::de::Unexpected::Unit,
-                    &"a string",
-                ))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                channel: channel.into(),
+            },
         }
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFile {
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
 }
 
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFileComment {
-    pub comment: ::FileComment,
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+impl<'a> ListRequestBuilder<'a> {
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
 }
 
-
 #[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemMessage {
-    pub channel: String,
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub message: ::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
+pub struct ListResponse {
+    error: Option<String>,
+    pub items: Option<Vec<::Item>>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -480,12 +469,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        Some(("channel", &request.channel[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("pins.remove");
@@ -501,13 +488,55 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct RemoveRequest<'a> {
     /// Channel where the item is pinned to.
-    pub channel: &'a str,
+    pub channel: Cow<'a, str>,
     /// File to un-pin.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
     /// File comment to un-pin.
-    pub file_comment: Option<&'a str>,
+    pub file_comment: Option<Cow<'a, str>>,
     /// Timestamp of the message to un-pin.
-    pub timestamp: Option<&'a str>,
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> RemoveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(channel: impl Into<Cow<'a, str>>) -> RemoveRequestBuilder<'a> {
+        RemoveRequestBuilder {
+            request: RemoveRequest {
+                channel: channel.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`RemoveRequest`](struct.RemoveRequest.html), created by `RemoveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RemoveRequestBuilder<'a> {
+    request: RemoveRequest<'a>,
+}
+
+impl<'a> RemoveRequestBuilder<'a> {
+    /// File to un-pin.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
+    /// File comment to un-pin.
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file_comment = Some(file_comment.into());
+        self
+    }
+
+    /// Timestamp of the message to un-pin.
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.request.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn build(self) -> RemoveRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -515,6 +544,8 @@ pub struct RemoveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,13 +27,11 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        Some(("name", &request.name[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reactions.add");
@@ -47,15 +47,63 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct AddRequest<'a> {
     /// Reaction (emoji) name.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
+    /// File to add reaction to.
+    pub file: Option<Cow<'a, str>>,
+    /// File comment to add reaction to.
+    pub file_comment: Option<Cow<'a, str>>,
+    /// Channel where the message to add reaction to was posted.
+    pub channel: Option<Cow<'a, str>>,
+    /// Timestamp of the message to add reaction to.
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(name: impl Into<Cow<'a, str>>) -> AddRequestBuilder<'a> {
+        AddRequestBuilder {
+            request: AddRequest {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AddRequest`](struct.AddRequest.html), created by `AddRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AddRequestBuilder<'a> {
+    request: AddRequest<'a>,
+}
+
+impl<'a> AddRequestBuilder<'a> {
     /// File to add reaction to.
-    pub file: Option<&'a str>,
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
     /// File comment to add reaction to.
-    pub file_comment: Option<&'a str>,
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file_comment = Some(file_comment.into());
+        self
+    }
+
     /// Channel where the message to add reaction to was posted.
-    pub channel: Option<&'a str>,
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
+
     /// Timestamp of the message to add reaction to.
-    pub timestamp: Option<&'a str>,
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.request.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn build(self) -> AddRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -63,6 +111,8 @@ pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -242,12 +292,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
         request.full.map(
             |full| ("full", if full { "1" } else { "0" })
         ),
@@ -266,140 +314,118 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct GetRequest<'a> {
     /// File to get reactions for.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
     /// File comment to get reactions for.
-    pub file_comment: Option<&'a str>,
+    pub file_comment: Option<Cow<'a, str>>,
     /// Channel where the message to get reactions for was posted.
-    pub channel: Option<&'a str>,
+    pub channel: Option<Cow<'a, str>>,
     /// Timestamp of the message to get reactions for.
-    pub timestamp: Option<&'a str>,
+    pub timestamp: Option<Cow<'a, str>>,
     /// If true always return the complete reaction list.
     pub full: Option<bool>,
 }
 
+impl<'a> GetRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> GetRequestBuilder<'a> {
+        GetRequestBuilder {
+            request: GetRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`GetRequest`](struct.GetRequest.html), created by `GetRequest::builder`.
 #[derive(Clone, Debug)]
-pub enum GetResponse {
-    Message(GetResponseMessage),
-    File(GetResponseFile),
-    FileComment(GetResponseFileComment),
+pub struct GetRequestBuilder<'a> {
+    request: GetRequest<'a>,
 }
 
-impl<'de> ::serde::Deserialize<'de> for GetResponse {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: ::serde::Deserializer<'de>,
-    {
-        use serde::de::Error as SerdeError;
+impl<'a> GetRequestBuilder<'a> {
+    /// File to get reactions for.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
 
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<GetResponseMessage>(value.clone())
-                            .map(GetResponse::Message)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<GetResponseFile>(value.clone())
-                            .map(GetResponse::File)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<GetResponseFileComment>(value.clone())
-                            .map(GetResponse::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(
-                    ::serde::de::Unexpected::Unit,
-                    &"a string",
-                ))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
-        }
+    /// File comment to get reactions for.
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file_comment = Some(file_comment.into());
+        self
     }
-}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseFile {
-    error: Option<String>,
-    pub file: ::File,
-    #[serde(default)]
-    ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+    /// Channel where the message to get reactions for was posted.
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
 
+    /// Timestamp of the message to get reactions for.
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.request.timestamp = Some(timestamp.into());
+        self
+    }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseFileComment {
-    pub comment: ::FileComment,
-    error: Option<String>,
-    pub file: ::File,
-    #[serde(default)]
-    ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+    /// If true always return the complete reaction list.
+    pub fn full(mut self, full: bool) -> Self {
+        self.request.full = Some(full);
+        self
+    }
 
+    pub fn build(self) -> GetRequest<'a> {
+        self.request
+    }
+}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseMessage {
-    pub channel: String,
+#[derive(Clone, Debug)]
+pub struct GetResponse {
     error: Option<String>,
-    pub message: ::Message,
-    #[serde(default)]
+    /// The item that was reacted to, whose fields Slack sends at the top level of the response.
+    pub item: Option<::Item>,
     ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
+    /// Every field of the response other than `ok` and `error`, including those of the item.
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
+impl<'de> ::serde::Deserialize<'de> for GetResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use serde::de::Error as SerdeError;
 
-impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
-    fn into(self) -> Result<GetResponse, GetError<E>> {
-        match self {
-            GetResponse::Message(inner) => {
-                let x: Result<GetResponseMessage, GetError<E>> = inner.into();
-                x.map(GetResponse::Message)
-            }
-            GetResponse::File(inner) => {
-                let x: Result<GetResponseFile, GetError<E>> = inner.into();
-                x.map(GetResponse::File)
+        let mut extra = HashMap::<String, ::serde_json::Value>::deserialize(deserializer)?;
+        let ok = match extra.remove("ok") {
+            Some(ok) => serde_json::from_value(ok).map_err(|e| D::Error::custom(&format!("{}", e)))?,
+            None => false,
+        };
+        let error = match extra.remove("error") {
+            Some(error) => {
+                serde_json::from_value(error).map_err(|e| D::Error::custom(&format!("{}", e)))?
             }
-            GetResponse::FileComment(inner) => {
-                let x: Result<GetResponseFileComment, GetError<E>> = inner.into();
-                x.map(GetResponse::FileComment)
-            }
-        }
-    }
-}
-
-impl<E: Error> Into<Result<GetResponseMessage, GetError<E>>> for GetResponseMessage {
-    fn into(self) -> Result<GetResponseMessage, GetError<E>> {
-        if self.ok {
-            Ok(self)
-        } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
-        }
-    }
-}
-impl<E: Error> Into<Result<GetResponseFile, GetError<E>>> for GetResponseFile {
-    fn into(self) -> Result<GetResponseFile, GetError<E>> {
-        if self.ok {
-            Ok(self)
+            None => None,
+        };
+        let item = if extra.contains_key("type") {
+            let item = serde_json::to_value(&extra)
+                .and_then(serde_json::from_value)
+                .map_err(|e| D::Error::custom(&format!("{}", e)))?;
+            Some(item)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
-        }
+            None
+        };
+        Ok(GetResponse {
+            error,
+            item,
+            ok,
+            extra,
+        })
     }
 }
-impl<E: Error> Into<Result<GetResponseFileComment, GetError<E>>> for GetResponseFileComment {
-    fn into(self) -> Result<GetResponseFileComment, GetError<E>> {
+
+
+impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
+    fn into(self) -> Result<GetResponse, GetError<E>> {
         if self.ok {
             Ok(self)
         } else {
@@ -551,14 +577,17 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
+    let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
         request.full.map(
             |full| ("full", if full { "1" } else { "0" })
         ),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reactions.list");
@@ -574,96 +603,118 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Show reactions made by this user. Defaults to the authed user.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// If true always return the complete reaction list.
     pub full: Option<bool>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponse {
-    error: Option<String>,
-    pub items: Option<Vec<ListResponseItem>>,
-    #[serde(default)]
-    ok: bool,
-    pub paging: Option<::Paging>,
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
 }
 
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
 #[derive(Clone, Debug)]
-pub enum ListResponseItem {
-    Message(ListResponseItemMessage),
-    File(ListResponseItemFile),
-    FileComment(ListResponseItemFileComment),
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
 }
 
-impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: ::serde::Deserializer<'de>,
-    {
-        use serde::de::Error as SerdeError;
+impl<'a> ListRequestBuilder<'a> {
+    /// Show reactions made by this user. Defaults to the authed user.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
 
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
-                            .map(ListResponseItem::Message)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<ListResponseItemFile>(value.clone())
-                            .map(ListResponseItem::File)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
-                            .map(ListResponseItem::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(
-                    ::serde::de::Unexpected::Unit,
-                    &"a string",
-                ))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
-        }
+    /// If true always return the complete reaction list.
+    pub fn full(mut self, full: bool) -> Self {
+        self.request.full = Some(full);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFile {
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+impl<'a> ::pagination::CursorRequest for ListRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
 }
 
+impl ::pagination::CursorResponse for ListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFileComment {
-    pub comment: ::FileComment,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+impl<'a> ::pagination::PageRequest for ListRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
 }
 
+impl ::pagination::PageResponse for ListResponse {
+    type Item = ::Item;
+
+    fn paging(&self) -> Option<&::Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<::Item> {
+        self.items.unwrap_or_default()
+    }
+}
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemMessage {
-    pub channel: String,
-    pub message: ::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
+pub struct ListResponse {
+    error: Option<String>,
+    pub items: Option<Vec<::Item>>,
+    #[serde(default)]
+    ok: bool,
+    pub paging: Option<::Paging>,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -799,13 +850,11 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        Some(("name", &request.name[..])),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reactions.remove");
@@ -821,15 +870,63 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct RemoveRequest<'a> {
     /// Reaction (emoji) name.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
+    /// File to remove reaction from.
+    pub file: Option<Cow<'a, str>>,
+    /// File comment to remove reaction from.
+    pub file_comment: Option<Cow<'a, str>>,
+    /// Channel where the message to remove reaction from was posted.
+    pub channel: Option<Cow<'a, str>>,
+    /// Timestamp of the message to remove reaction from.
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> RemoveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(name: impl Into<Cow<'a, str>>) -> RemoveRequestBuilder<'a> {
+        RemoveRequestBuilder {
+            request: RemoveRequest {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`RemoveRequest`](struct.RemoveRequest.html), created by `RemoveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RemoveRequestBuilder<'a> {
+    request: RemoveRequest<'a>,
+}
+
+impl<'a> RemoveRequestBuilder<'a> {
     /// File to remove reaction from.
-    pub file: Option<&'a str>,
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
     /// File comment to remove reaction from.
-    pub file_comment: Option<&'a str>,
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file_comment = Some(file_comment.into());
+        self
+    }
+
     /// Channel where the message to remove reaction from was posted.
-    pub channel: Option<&'a str>,
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
+
     /// Timestamp of the message to remove reaction from.
-    pub timestamp: Option<&'a str>,
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.request.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn build(self) -> RemoveRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -837,6 +934,8 @@ pub struct RemoveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
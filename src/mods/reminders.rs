@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,9 +27,9 @@ where
     let time = request.time.to_string();
     let params = vec![
         Some(("token", token)),
-        Some(("text", request.text)),
+        Some(("text", &request.text[..])),
         Some(("time", &time[..])),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reminders.add");
@@ -43,11 +45,42 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct AddRequest<'a> {
     /// The content of the reminder
-    pub text: &'a str,
+    pub text: Cow<'a, str>,
     /// When this reminder should happen: the Unix timestamp (up to five years from now), the number of seconds until the reminder (if within 24 hours), or a natural language description (Ex. "in 15 minutes," or "every Thursday")
     pub time: u32,
     /// The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(text: impl Into<Cow<'a, str>>, time: u32) -> AddRequestBuilder<'a> {
+        AddRequestBuilder {
+            request: AddRequest {
+                text: text.into(),
+                time,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AddRequest`](struct.AddRequest.html), created by `AddRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AddRequestBuilder<'a> {
+    request: AddRequest<'a>,
+}
+
+impl<'a> AddRequestBuilder<'a> {
+    /// The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
+
+    pub fn build(self) -> AddRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -56,6 +89,8 @@ pub struct AddResponse {
     #[serde(default)]
     ok: bool,
     pub reminder: Option<::Reminder>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -221,7 +256,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
+    let params = vec![Some(("token", token)), Some(("reminder", &request.reminder[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reminders.complete");
     client
@@ -238,7 +273,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct CompleteRequest<'a> {
     /// The ID of the reminder to be marked as complete
-    pub reminder: &'a str,
+    pub reminder: Cow<'a, str>,
+}
+
+impl<'a> CompleteRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(reminder: impl Into<Cow<'a, str>>) -> CompleteRequestBuilder<'a> {
+        CompleteRequestBuilder {
+            request: CompleteRequest {
+                reminder: reminder.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`CompleteRequest`](struct.CompleteRequest.html), created by `CompleteRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct CompleteRequestBuilder<'a> {
+    request: CompleteRequest<'a>,
+}
+
+impl<'a> CompleteRequestBuilder<'a> {
+    pub fn build(self) -> CompleteRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -246,6 +304,8 @@ pub struct CompleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -395,7 +455,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
+    let params = vec![Some(("token", token)), Some(("reminder", &request.reminder[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reminders.delete");
     client
@@ -410,7 +470,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct DeleteRequest<'a> {
     /// The ID of the reminder
-    pub reminder: &'a str,
+    pub reminder: Cow<'a, str>,
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(reminder: impl Into<Cow<'a, str>>) -> DeleteRequestBuilder<'a> {
+        DeleteRequestBuilder {
+            request: DeleteRequest {
+                reminder: reminder.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`DeleteRequest`](struct.DeleteRequest.html), created by `DeleteRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct DeleteRequestBuilder<'a> {
+    request: DeleteRequest<'a>,
+}
+
+impl<'a> DeleteRequestBuilder<'a> {
+    pub fn build(self) -> DeleteRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -418,6 +501,8 @@ pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -555,7 +640,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
+    let params = vec![Some(("token", token)), Some(("reminder", &request.reminder[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("reminders.info");
     client
@@ -570,7 +655,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// The ID of the reminder
-    pub reminder: &'a str,
+    pub reminder: Cow<'a, str>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(reminder: impl Into<Cow<'a, str>>) -> InfoRequestBuilder<'a> {
+        InfoRequestBuilder {
+            request: InfoRequest {
+                reminder: reminder.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`InfoRequest`](struct.InfoRequest.html), created by `InfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder<'a> {
+    request: InfoRequest<'a>,
+}
+
+impl<'a> InfoRequestBuilder<'a> {
+    pub fn build(self) -> InfoRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -579,6 +687,8 @@ pub struct InfoResponse {
     #[serde(default)]
     ok: bool,
     pub reminder: Option<::Reminder>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -728,6 +838,8 @@ pub struct ListResponse {
     #[serde(default)]
     ok: bool,
     pub reminders: Option<Vec<::Reminder>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
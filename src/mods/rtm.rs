@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -40,12 +42,16 @@ pub struct ConnectResponse {
     pub slf: Option<ConnectResponseSelf>,
     pub team: Option<ConnectResponseTeam>,
     pub url: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct ConnectResponseSelf {
     pub id: Option<String>,
     pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -56,6 +62,8 @@ pub struct ConnectResponseTeam {
     pub enterprise_name: Option<String>,
     pub id: Option<String>,
     pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -218,6 +226,47 @@ pub struct StartRequest {
     pub no_latest: Option<bool>,
 }
 
+impl StartRequest {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> StartRequestBuilder {
+        StartRequestBuilder {
+            request: StartRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`StartRequest`](struct.StartRequest.html), created by `StartRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct StartRequestBuilder {
+    request: StartRequest,
+}
+
+impl StartRequestBuilder {
+    /// Skip unread counts for each channel (improves performance).
+    pub fn no_unreads(mut self, no_unreads: bool) -> Self {
+        self.request.no_unreads = Some(no_unreads);
+        self
+    }
+
+    /// Returns MPIMs to the client in the API response.
+    pub fn mpim_aware(mut self, mpim_aware: bool) -> Self {
+        self.request.mpim_aware = Some(mpim_aware);
+        self
+    }
+
+    /// Exclude latest timestamps for channels, groups, mpims, and ims. Automatically sets no_unreads to 1
+    pub fn no_latest(mut self, no_latest: bool) -> Self {
+        self.request.no_latest = Some(no_latest);
+        self
+    }
+
+    pub fn build(self) -> StartRequest {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct StartResponse {
     pub bots: Option<Vec<::Bot>>,
@@ -233,6 +282,8 @@ pub struct StartResponse {
     pub team: Option<::Team>,
     pub url: Option<String>,
     pub users: Option<Vec<::User>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,6 +1,8 @@
 //! Search your team's files and messages.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -11,6 +13,68 @@ use serde_json;
 
 use requests::SlackWebRequestSender;
 
+/// How search results are ordered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortBy {
+    /// By relevance to the query.
+    Score,
+    /// By the time of the match.
+    Timestamp,
+}
+
+impl SortBy {
+    /// The value sent to Slack for this variant.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SortBy::Score => "score",
+            SortBy::Timestamp => "timestamp",
+        }
+    }
+}
+
+impl Default for SortBy {
+    fn default() -> Self {
+        SortBy::Score
+    }
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The direction search results are sorted in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// Descending.
+    Desc,
+    /// Ascending.
+    Asc,
+}
+
+impl SortDirection {
+    /// The value sent to Slack for this variant.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SortDirection::Desc => "desc",
+            SortDirection::Asc => "asc",
+        }
+    }
+}
+
+impl Default for SortDirection {
+    fn default() -> Self {
+        SortDirection::Desc
+    }
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Searches for messages and files matching a query.
 ///
 /// Wraps https://api.slack.com/methods/search.all
@@ -27,14 +91,15 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("query", request.query)),
-        request.sort.map(|sort| ("sort", sort)),
-        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
+        Some(("query", &request.query[..])),
+        request.sort.map(|sort| ("sort", sort.as_str())),
+        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir.as_str())),
         request.highlight.map(|highlight| {
             ("highlight", if highlight { "1" } else { "0" })
         }),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
+        request.team_id.as_ref().map(|team_id| ("team_id", &team_id[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("search.all");
@@ -50,17 +115,79 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct AllRequest<'a> {
     /// Search query. May contains booleans, etc.
-    pub query: &'a str,
+    pub query: Cow<'a, str>,
     /// Return matches sorted by either score or timestamp.
-    pub sort: Option<&'a str>,
+    pub sort: Option<SortBy>,
     /// Change sort direction to ascending (asc) or descending (desc).
-    pub sort_dir: Option<&'a str>,
+    pub sort_dir: Option<SortDirection>,
     /// Pass a value of true to enable query highlight markers (see below).
     pub highlight: Option<bool>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
+    /// Encoded team ID to search in. Required when using an org-level token on Enterprise Grid.
+    pub team_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> AllRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(query: impl Into<Cow<'a, str>>) -> AllRequestBuilder<'a> {
+        AllRequestBuilder {
+            request: AllRequest {
+                query: query.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AllRequest`](struct.AllRequest.html), created by `AllRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AllRequestBuilder<'a> {
+    request: AllRequest<'a>,
+}
+
+impl<'a> AllRequestBuilder<'a> {
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort(mut self, sort: SortBy) -> Self {
+        self.request.sort = Some(sort);
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir(mut self, sort_dir: SortDirection) -> Self {
+        self.request.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.request.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    /// Encoded team ID to search in. Required when using an org-level token on Enterprise Grid.
+    pub fn team_id(mut self, team_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.team_id = Some(team_id.into());
+        self
+    }
+
+    pub fn build(self) -> AllRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -71,12 +198,16 @@ pub struct AllResponse {
     #[serde(default)]
     ok: bool,
     pub query: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct AllResponseFiles {
     pub matches: Vec<::File>,
     pub paging: ::Paging,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -84,6 +215,8 @@ pub struct AllResponseFiles {
 pub struct AllResponseMessages {
     pub matches: Vec<::Message>,
     pub paging: ::Paging,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -220,14 +353,15 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("query", request.query)),
-        request.sort.map(|sort| ("sort", sort)),
-        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
+        Some(("query", &request.query[..])),
+        request.sort.map(|sort| ("sort", sort.as_str())),
+        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir.as_str())),
         request.highlight.map(|highlight| {
             ("highlight", if highlight { "1" } else { "0" })
         }),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
+        request.team_id.as_ref().map(|team_id| ("team_id", &team_id[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("search.files");
@@ -243,17 +377,97 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct FilesRequest<'a> {
     /// Search query. May contain booleans, etc.
-    pub query: &'a str,
+    pub query: Cow<'a, str>,
     /// Return matches sorted by either score or timestamp.
-    pub sort: Option<&'a str>,
+    pub sort: Option<SortBy>,
     /// Change sort direction to ascending (asc) or descending (desc).
-    pub sort_dir: Option<&'a str>,
+    pub sort_dir: Option<SortDirection>,
     /// Pass a value of true to enable query highlight markers (see below).
     pub highlight: Option<bool>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
+    /// Encoded team ID to search in. Required when using an org-level token on Enterprise Grid.
+    pub team_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> FilesRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(query: impl Into<Cow<'a, str>>) -> FilesRequestBuilder<'a> {
+        FilesRequestBuilder {
+            request: FilesRequest {
+                query: query.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`FilesRequest`](struct.FilesRequest.html), created by `FilesRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct FilesRequestBuilder<'a> {
+    request: FilesRequest<'a>,
+}
+
+impl<'a> FilesRequestBuilder<'a> {
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort(mut self, sort: SortBy) -> Self {
+        self.request.sort = Some(sort);
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir(mut self, sort_dir: SortDirection) -> Self {
+        self.request.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.request.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    /// Encoded team ID to search in. Required when using an org-level token on Enterprise Grid.
+    pub fn team_id(mut self, team_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.team_id = Some(team_id.into());
+        self
+    }
+
+    pub fn build(self) -> FilesRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::PageRequest for FilesRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl ::pagination::PageResponse for FilesResponse {
+    type Item = ::File;
+
+    fn paging(&self) -> Option<&::Paging> {
+        self.files.as_ref().and_then(|o| o.paging.as_ref())
+    }
+
+    fn into_items(self) -> Vec<::File> {
+        self.files.and_then(|o| o.matches).unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -263,6 +477,8 @@ pub struct FilesResponse {
     #[serde(default)]
     ok: bool,
     pub query: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -270,6 +486,8 @@ pub struct FilesResponseFiles {
     pub matches: Option<Vec<::File>>,
     pub paging: Option<::Paging>,
     pub total: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -406,14 +624,15 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        Some(("query", request.query)),
-        request.sort.map(|sort| ("sort", sort)),
-        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
+        Some(("query", &request.query[..])),
+        request.sort.map(|sort| ("sort", sort.as_str())),
+        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir.as_str())),
         request.highlight.map(|highlight| {
             ("highlight", if highlight { "1" } else { "0" })
         }),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
+        request.team_id.as_ref().map(|team_id| ("team_id", &team_id[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("search.messages");
@@ -431,17 +650,97 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct MessagesRequest<'a> {
     /// Search query. May contains booleans, etc.
-    pub query: &'a str,
+    pub query: Cow<'a, str>,
     /// Return matches sorted by either score or timestamp.
-    pub sort: Option<&'a str>,
+    pub sort: Option<SortBy>,
     /// Change sort direction to ascending (asc) or descending (desc).
-    pub sort_dir: Option<&'a str>,
+    pub sort_dir: Option<SortDirection>,
     /// Pass a value of true to enable query highlight markers (see below).
     pub highlight: Option<bool>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
+    /// Encoded team ID to search in. Required when using an org-level token on Enterprise Grid.
+    pub team_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessagesRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(query: impl Into<Cow<'a, str>>) -> MessagesRequestBuilder<'a> {
+        MessagesRequestBuilder {
+            request: MessagesRequest {
+                query: query.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`MessagesRequest`](struct.MessagesRequest.html), created by `MessagesRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct MessagesRequestBuilder<'a> {
+    request: MessagesRequest<'a>,
+}
+
+impl<'a> MessagesRequestBuilder<'a> {
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort(mut self, sort: SortBy) -> Self {
+        self.request.sort = Some(sort);
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir(mut self, sort_dir: SortDirection) -> Self {
+        self.request.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.request.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    /// Encoded team ID to search in. Required when using an org-level token on Enterprise Grid.
+    pub fn team_id(mut self, team_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.team_id = Some(team_id.into());
+        self
+    }
+
+    pub fn build(self) -> MessagesRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::PageRequest for MessagesRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl ::pagination::PageResponse for MessagesResponse {
+    type Item = ::Message;
+
+    fn paging(&self) -> Option<&::Paging> {
+        self.messages.as_ref().and_then(|o| o.paging.as_ref())
+    }
+
+    fn into_items(self) -> Vec<::Message> {
+        self.messages.and_then(|o| o.matches).unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -451,6 +750,8 @@ pub struct MessagesResponse {
     #[serde(default)]
     ok: bool,
     pub query: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -458,6 +759,8 @@ pub struct MessagesResponseMessages {
     pub matches: Option<Vec<::Message>>,
     pub paging: Option<::Paging>,
     pub total: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
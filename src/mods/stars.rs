@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,12 +27,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("stars.add");
@@ -46,13 +46,60 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct AddRequest<'a> {
     /// File to add star to.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
     /// File comment to add star to.
-    pub file_comment: Option<&'a str>,
+    pub file_comment: Option<Cow<'a, str>>,
     /// Channel to add star to, or channel where the message to add star to was posted (used with timestamp).
-    pub channel: Option<&'a str>,
+    pub channel: Option<Cow<'a, str>>,
     /// Timestamp of the message to add star to.
-    pub timestamp: Option<&'a str>,
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> AddRequestBuilder<'a> {
+        AddRequestBuilder {
+            request: AddRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AddRequest`](struct.AddRequest.html), created by `AddRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AddRequestBuilder<'a> {
+    request: AddRequest<'a>,
+}
+
+impl<'a> AddRequestBuilder<'a> {
+    /// File to add star to.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
+    /// File comment to add star to.
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file_comment = Some(file_comment.into());
+        self
+    }
+
+    /// Channel to add star to, or channel where the message to add star to was posted (used with timestamp).
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
+
+    /// Timestamp of the message to add star to.
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.request.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn build(self) -> AddRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -60,6 +107,8 @@ pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -228,10 +277,13 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
+    let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
         Some(("token", token)),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("stars.list");
@@ -245,137 +297,104 @@ where
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+pub struct ListRequest<'a> {
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponse {
-    error: Option<String>,
-    pub items: Option<Vec<ListResponseItem>>,
-    #[serde(default)]
-    ok: bool,
-    pub paging: Option<::Paging>,
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
 }
 
-#[derive(Clone, Debug)]
-pub enum ListResponseItem {
-    Message(ListResponseItemMessage),
-    File(ListResponseItemFile),
-    FileComment(ListResponseItemFileComment),
-    Channel(ListResponseItemChannel),
-    Im(ListResponseItemIm),
-    Group(ListResponseItemGroup),
-}
-
-impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: ::serde::Deserializer<'de>,
-    {
-        use serde::de::Error as SerdeError;
-
-        const VARIANTS: &'static [&'static str] =
-            &["message", "file", "file_comment", "channel", "im", "group"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
-                            .map(ListResponseItem::Message)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<ListResponseItemFile>(value.clone())
-                            .map(ListResponseItem::File)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
-                            .map(ListResponseItem::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "channel" => {
-                        ::serde_json::from_value::<ListResponseItemChannel>(value.clone())
-                            .map(ListResponseItem::Channel)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "im" => {
-                        ::serde_json::from_value::<ListResponseItemIm>(value.clone())
-                            .map(ListResponseItem::Im)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "group" => {
-                        ::serde_json::from_value::<ListResponseItemGroup>(value.clone())
-                            .map(ListResponseItem::Group)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(
-                    ::serde::de::Unexpected::Unit,
-                    &"a string",
-                ))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
-        }
+impl<'a> ListRequestBuilder<'a> {
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
     }
-}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemChannel {
-    pub channel: String,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
 
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFile {
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
 }
 
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFileComment {
-    pub comment: ::FileComment,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+impl<'a> ::pagination::CursorRequest for ListRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
 }
 
+impl ::pagination::CursorResponse for ListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemGroup {
-    pub group: String,
-    #[serde(rename = "type")]
-    pub ty: String,
+impl<'a> ::pagination::PageRequest for ListRequest<'a> {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
 }
 
+impl ::pagination::PageResponse for ListResponse {
+    type Item = ::Item;
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemIm {
-    pub channel: String,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+    fn paging(&self) -> Option<&::Paging> {
+        self.paging.as_ref()
+    }
 
+    fn into_items(self) -> Vec<::Item> {
+        self.items.unwrap_or_default()
+    }
+}
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemMessage {
-    pub channel: String,
-    pub message: ::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
+pub struct ListResponse {
+    error: Option<String>,
+    pub items: Option<Vec<::Item>>,
+    #[serde(default)]
+    ok: bool,
+    pub paging: Option<::Paging>,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -521,12 +540,10 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        request.file.as_ref().map(|file| ("file", &file[..])),
+        request.file_comment.as_ref().map(|file_comment| ("file_comment", &file_comment[..])),
+        request.channel.as_ref().map(|channel| ("channel", &channel[..])),
+        request.timestamp.as_ref().map(|timestamp| ("timestamp", &timestamp[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("stars.remove");
@@ -542,13 +559,60 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct RemoveRequest<'a> {
     /// File to remove star from.
-    pub file: Option<&'a str>,
+    pub file: Option<Cow<'a, str>>,
     /// File comment to remove star from.
-    pub file_comment: Option<&'a str>,
+    pub file_comment: Option<Cow<'a, str>>,
     /// Channel to remove star from, or channel where the message to remove star from was posted (used with timestamp).
-    pub channel: Option<&'a str>,
+    pub channel: Option<Cow<'a, str>>,
     /// Timestamp of the message to remove star from.
-    pub timestamp: Option<&'a str>,
+    pub timestamp: Option<Cow<'a, str>>,
+}
+
+impl<'a> RemoveRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> RemoveRequestBuilder<'a> {
+        RemoveRequestBuilder {
+            request: RemoveRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`RemoveRequest`](struct.RemoveRequest.html), created by `RemoveRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct RemoveRequestBuilder<'a> {
+    request: RemoveRequest<'a>,
+}
+
+impl<'a> RemoveRequestBuilder<'a> {
+    /// File to remove star from.
+    pub fn file(mut self, file: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file = Some(file.into());
+        self
+    }
+
+    /// File comment to remove star from.
+    pub fn file_comment(mut self, file_comment: impl Into<Cow<'a, str>>) -> Self {
+        self.request.file_comment = Some(file_comment.into());
+        self
+    }
+
+    /// Channel to remove star from, or channel where the message to remove star from was posted (used with timestamp).
+    pub fn channel(mut self, channel: impl Into<Cow<'a, str>>) -> Self {
+        self.request.channel = Some(channel.into());
+        self
+    }
+
+    /// Timestamp of the message to remove star from.
+    pub fn timestamp(mut self, timestamp: impl Into<Cow<'a, str>>) -> Self {
+        self.request.timestamp = Some(timestamp.into());
+        self
+    }
+
+    pub fn build(self) -> RemoveRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -556,6 +620,8 @@ pub struct RemoveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -53,6 +55,65 @@ pub struct AccessLogsRequest {
     pub before: Option<u32>,
 }
 
+impl AccessLogsRequest {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> AccessLogsRequestBuilder {
+        AccessLogsRequestBuilder {
+            request: AccessLogsRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`AccessLogsRequest`](struct.AccessLogsRequest.html), created by `AccessLogsRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct AccessLogsRequestBuilder {
+    request: AccessLogsRequest,
+}
+
+impl AccessLogsRequestBuilder {
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    /// End of time range of logs to include in results (inclusive).
+    pub fn before(mut self, before: u32) -> Self {
+        self.request.before = Some(before);
+        self
+    }
+
+    pub fn build(self) -> AccessLogsRequest {
+        self.request
+    }
+}
+
+impl ::pagination::PageRequest for AccessLogsRequest {
+    fn set_page(&mut self, page: u32) {
+        self.page = Some(page);
+    }
+}
+
+impl ::pagination::PageResponse for AccessLogsResponse {
+    type Item = AccessLogsResponseLogin;
+
+    fn paging(&self) -> Option<&::Paging> {
+        self.paging.as_ref()
+    }
+
+    fn into_items(self) -> Vec<AccessLogsResponseLogin> {
+        self.logins.unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct AccessLogsResponse {
     error: Option<String>,
@@ -60,6 +121,8 @@ pub struct AccessLogsResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -74,6 +137,8 @@ pub struct AccessLogsResponseLogin {
     pub user_agent: Option<String>,
     pub user_id: Option<String>,
     pub username: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -221,7 +286,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("team.billableInfo");
@@ -238,7 +303,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct BillableInfoRequest<'a> {
     /// A user to retrieve the billable information for. Defaults to all users.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
+}
+
+impl<'a> BillableInfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> BillableInfoRequestBuilder<'a> {
+        BillableInfoRequestBuilder {
+            request: BillableInfoRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`BillableInfoRequest`](struct.BillableInfoRequest.html), created by `BillableInfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct BillableInfoRequestBuilder<'a> {
+    request: BillableInfoRequest<'a>,
+}
+
+impl<'a> BillableInfoRequestBuilder<'a> {
+    /// A user to retrieve the billable information for. Defaults to all users.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
+
+    pub fn build(self) -> BillableInfoRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -247,6 +341,8 @@ pub struct BillableInfoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -398,6 +494,8 @@ pub struct InfoResponse {
     #[serde(default)]
     ok: bool,
     pub team: Option<::Team>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -530,14 +628,10 @@ where
     let page = request.page.map(|page| page.to_string());
     let params = vec![
         Some(("token", token)),
-        request.service_id.map(
-            |service_id| ("service_id", service_id)
-        ),
-        request.app_id.map(|app_id| ("app_id", app_id)),
-        request.user.map(|user| ("user", user)),
-        request.change_type.map(|change_type| {
-            ("change_type", change_type)
-        }),
+        request.service_id.as_ref().map(|service_id| ("service_id", &service_id[..])),
+        request.app_id.as_ref().map(|app_id| ("app_id", &app_id[..])),
+        request.user.as_ref().map(|user| ("user", &user[..])),
+        request.change_type.as_ref().map(|change_type| ("change_type", &change_type[..])),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
@@ -556,19 +650,78 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct IntegrationLogsRequest<'a> {
     /// Filter logs to this service. Defaults to all logs.
-    pub service_id: Option<&'a str>,
+    pub service_id: Option<Cow<'a, str>>,
     /// Filter logs to this Slack app. Defaults to all logs.
-    pub app_id: Option<&'a str>,
+    pub app_id: Option<Cow<'a, str>>,
     /// Filter logs generated by this user’s actions. Defaults to all logs.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// Filter logs with this change type. Defaults to all logs.
-    pub change_type: Option<&'a str>,
+    pub change_type: Option<Cow<'a, str>>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
 }
 
+impl<'a> IntegrationLogsRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> IntegrationLogsRequestBuilder<'a> {
+        IntegrationLogsRequestBuilder {
+            request: IntegrationLogsRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`IntegrationLogsRequest`](struct.IntegrationLogsRequest.html), created by `IntegrationLogsRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct IntegrationLogsRequestBuilder<'a> {
+    request: IntegrationLogsRequest<'a>,
+}
+
+impl<'a> IntegrationLogsRequestBuilder<'a> {
+    /// Filter logs to this service. Defaults to all logs.
+    pub fn service_id(mut self, service_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.service_id = Some(service_id.into());
+        self
+    }
+
+    /// Filter logs to this Slack app. Defaults to all logs.
+    pub fn app_id(mut self, app_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.app_id = Some(app_id.into());
+        self
+    }
+
+    /// Filter logs generated by this user’s actions. Defaults to all logs.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
+
+    /// Filter logs with this change type. Defaults to all logs.
+    pub fn change_type(mut self, change_type: impl Into<Cow<'a, str>>) -> Self {
+        self.request.change_type = Some(change_type.into());
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.request.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.request.page = Some(page);
+        self
+    }
+
+    pub fn build(self) -> IntegrationLogsRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct IntegrationLogsResponse {
     error: Option<String>,
@@ -576,6 +729,8 @@ pub struct IntegrationLogsResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<::Paging>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -591,6 +746,8 @@ pub struct IntegrationLogsResponseLog {
     pub service_type: Option<String>,
     pub user_id: Option<String>,
     pub user_name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
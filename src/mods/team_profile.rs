@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,9 +27,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.visibility.map(
-            |visibility| ("visibility", visibility)
-        ),
+        request.visibility.as_ref().map(|visibility| ("visibility", &visibility[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("team.profile.get");
@@ -43,7 +43,36 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct GetRequest<'a> {
     /// Filter by visibility.
-    pub visibility: Option<&'a str>,
+    pub visibility: Option<Cow<'a, str>>,
+}
+
+impl<'a> GetRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> GetRequestBuilder<'a> {
+        GetRequestBuilder {
+            request: GetRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`GetRequest`](struct.GetRequest.html), created by `GetRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct GetRequestBuilder<'a> {
+    request: GetRequest<'a>,
+}
+
+impl<'a> GetRequestBuilder<'a> {
+    /// Filter by visibility.
+    pub fn visibility(mut self, visibility: impl Into<Cow<'a, str>>) -> Self {
+        self.request.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn build(self) -> GetRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -52,11 +81,15 @@ pub struct GetResponse {
     #[serde(default)]
     ok: bool,
     pub profile: Option<GetResponseProfile>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct GetResponseProfile {
     pub fields: Option<Vec<GetResponseProfileField>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -70,6 +103,8 @@ pub struct GetResponseProfileField {
     pub possible_values: Option<Vec<String>>,
     #[serde(rename = "type")]
     pub ty: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,6 +1,8 @@
 //! Get info on your team's User Groups.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -23,15 +25,13 @@ pub fn create<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let channels = request.channels.as_ref().map(|channels| channels.join(","));
     let params = vec![
         Some(("token", token)),
-        Some(("name", request.name)),
-        request.handle.map(|handle| ("handle", handle)),
-        request.description.map(|description| {
-            ("description", description)
-        }),
-        request.channels.map(|channels| ("channels", channels)),
+        Some(("name", &request.name[..])),
+        request.handle.as_ref().map(|handle| ("handle", &handle[..])),
+        request.description.as_ref().map(|description| ("description", &description[..])),
+        channels.as_ref().map(|channels| ("channels", &channels[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -50,23 +50,73 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct CreateRequest<'a> {
     /// A name for the User Group. Must be unique among User Groups.
-    pub name: &'a str,
+    pub name: Cow<'a, str>,
     /// A mention handle. Must be unique among channels, users and User Groups.
-    pub handle: Option<&'a str>,
+    pub handle: Option<Cow<'a, str>>,
     /// A short description of the User Group.
-    pub description: Option<&'a str>,
-    /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
-    pub channels: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
+    /// The encoded IDs of the channels that the User Group uses as a default.
+    pub channels: Option<Vec<Cow<'a, str>>>,
     /// Include the number of users in each User Group.
     pub include_count: Option<bool>,
 }
 
+impl<'a> CreateRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(name: impl Into<Cow<'a, str>>) -> CreateRequestBuilder<'a> {
+        CreateRequestBuilder {
+            request: CreateRequest {
+                name: name.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`CreateRequest`](struct.CreateRequest.html), created by `CreateRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct CreateRequestBuilder<'a> {
+    request: CreateRequest<'a>,
+}
+
+impl<'a> CreateRequestBuilder<'a> {
+    /// A mention handle. Must be unique among channels, users and User Groups.
+    pub fn handle(mut self, handle: impl Into<Cow<'a, str>>) -> Self {
+        self.request.handle = Some(handle.into());
+        self
+    }
+
+    /// A short description of the User Group.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.request.description = Some(description.into());
+        self
+    }
+
+    /// The encoded IDs of the channels that the User Group uses as a default.
+    pub fn channels(mut self, channels: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> Self {
+        self.request.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Include the number of users in each User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.request.include_count = Some(include_count);
+        self
+    }
+
+    pub fn build(self) -> CreateRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct CreateResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub usergroup: Option<::Usergroup>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -208,7 +258,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", &request.usergroup[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -229,17 +279,49 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct DisableRequest<'a> {
     /// The encoded ID of the User Group to disable.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
+impl<'a> DisableRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(usergroup: impl Into<Cow<'a, str>>) -> DisableRequestBuilder<'a> {
+        DisableRequestBuilder {
+            request: DisableRequest {
+                usergroup: usergroup.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`DisableRequest`](struct.DisableRequest.html), created by `DisableRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct DisableRequestBuilder<'a> {
+    request: DisableRequest<'a>,
+}
+
+impl<'a> DisableRequestBuilder<'a> {
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.request.include_count = Some(include_count);
+        self
+    }
+
+    pub fn build(self) -> DisableRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct DisableResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub usergroup: Option<::Usergroup>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -381,7 +463,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", &request.usergroup[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -400,17 +482,49 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct EnableRequest<'a> {
     /// The encoded ID of the User Group to enable.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
+impl<'a> EnableRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(usergroup: impl Into<Cow<'a, str>>) -> EnableRequestBuilder<'a> {
+        EnableRequestBuilder {
+            request: EnableRequest {
+                usergroup: usergroup.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`EnableRequest`](struct.EnableRequest.html), created by `EnableRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct EnableRequestBuilder<'a> {
+    request: EnableRequest<'a>,
+}
+
+impl<'a> EnableRequestBuilder<'a> {
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.request.include_count = Some(include_count);
+        self
+    }
+
+    pub fn build(self) -> EnableRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct EnableResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub usergroup: Option<::Usergroup>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -561,6 +675,7 @@ where
         request.include_users.map(|include_users| {
             ("include_users", if include_users { "1" } else { "0" })
         }),
+        request.team_id.as_ref().map(|team_id| ("team_id", &team_id[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("usergroups.list");
@@ -574,13 +689,62 @@ where
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+pub struct ListRequest<'a> {
     /// Include disabled User Groups.
     pub include_disabled: Option<bool>,
     /// Include the number of users in each User Group.
     pub include_count: Option<bool>,
     /// Include the list of users for each User Group.
     pub include_users: Option<bool>,
+    /// Encoded team ID to list User Groups in. Required when using an org-level token on Enterprise Grid.
+    pub team_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// Include disabled User Groups.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.request.include_disabled = Some(include_disabled);
+        self
+    }
+
+    /// Include the number of users in each User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.request.include_count = Some(include_count);
+        self
+    }
+
+    /// Include the list of users for each User Group.
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.request.include_users = Some(include_users);
+        self
+    }
+
+    /// Encoded team ID to list User Groups in. Required when using an org-level token on Enterprise Grid.
+    pub fn team_id(mut self, team_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.team_id = Some(team_id.into());
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -589,6 +753,8 @@ pub struct ListResponse {
     #[serde(default)]
     ok: bool,
     pub usergroups: Option<Vec<::Usergroup>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -727,16 +893,14 @@ pub fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let channels = request.channels.as_ref().map(|channels| channels.join(","));
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
-        request.name.map(|name| ("name", name)),
-        request.handle.map(|handle| ("handle", handle)),
-        request.description.map(|description| {
-            ("description", description)
-        }),
-        request.channels.map(|channels| ("channels", channels)),
+        Some(("usergroup", &request.usergroup[..])),
+        request.name.as_ref().map(|name| ("name", &name[..])),
+        request.handle.as_ref().map(|handle| ("handle", &handle[..])),
+        request.description.as_ref().map(|description| ("description", &description[..])),
+        channels.as_ref().map(|channels| ("channels", &channels[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -755,25 +919,81 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct UpdateRequest<'a> {
     /// The encoded ID of the User Group to update.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// A name for the User Group. Must be unique among User Groups.
-    pub name: Option<&'a str>,
+    pub name: Option<Cow<'a, str>>,
     /// A mention handle. Must be unique among channels, users and User Groups.
-    pub handle: Option<&'a str>,
+    pub handle: Option<Cow<'a, str>>,
     /// A short description of the User Group.
-    pub description: Option<&'a str>,
-    /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
-    pub channels: Option<&'a str>,
+    pub description: Option<Cow<'a, str>>,
+    /// The encoded IDs of the channels that the User Group uses as a default.
+    pub channels: Option<Vec<Cow<'a, str>>>,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
+impl<'a> UpdateRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(usergroup: impl Into<Cow<'a, str>>) -> UpdateRequestBuilder<'a> {
+        UpdateRequestBuilder {
+            request: UpdateRequest {
+                usergroup: usergroup.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`UpdateRequest`](struct.UpdateRequest.html), created by `UpdateRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct UpdateRequestBuilder<'a> {
+    request: UpdateRequest<'a>,
+}
+
+impl<'a> UpdateRequestBuilder<'a> {
+    /// A name for the User Group. Must be unique among User Groups.
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.request.name = Some(name.into());
+        self
+    }
+
+    /// A mention handle. Must be unique among channels, users and User Groups.
+    pub fn handle(mut self, handle: impl Into<Cow<'a, str>>) -> Self {
+        self.request.handle = Some(handle.into());
+        self
+    }
+
+    /// A short description of the User Group.
+    pub fn description(mut self, description: impl Into<Cow<'a, str>>) -> Self {
+        self.request.description = Some(description.into());
+        self
+    }
+
+    /// The encoded IDs of the channels that the User Group uses as a default.
+    pub fn channels(mut self, channels: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> Self {
+        self.request.channels = Some(channels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.request.include_count = Some(include_count);
+        self
+    }
+
+    pub fn build(self) -> UpdateRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UpdateResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub usergroup: Option<::Usergroup>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,7 +27,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", &request.usergroup[..])),
         request.include_disabled.map(|include_disabled| {
             ("include_disabled", if include_disabled { "1" } else { "0" })
         }),
@@ -44,17 +46,49 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// The encoded ID of the User Group to update.
-    pub usergroup: &'a str,
+    pub usergroup: Cow<'a, str>,
     /// Allow results that involve disabled User Groups.
     pub include_disabled: Option<bool>,
 }
 
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(usergroup: impl Into<Cow<'a, str>>) -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                usergroup: usergroup.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// Allow results that involve disabled User Groups.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.request.include_disabled = Some(include_disabled);
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct ListResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub users: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -193,11 +227,11 @@ pub fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let users = request.users.join(",");
     let params = vec![
         Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
-        Some(("users", request.users)),
+        Some(("usergroup", &request.usergroup[..])),
+        Some(("users", &users[..])),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
@@ -216,19 +250,55 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct UpdateRequest<'a> {
     /// The encoded ID of the User Group to update.
-    pub usergroup: &'a str,
-    /// A comma separated string of encoded user IDs that represent the entire list of users for the User Group.
-    pub users: &'a str,
+    pub usergroup: Cow<'a, str>,
+    /// The encoded IDs of the users that make up the entire list of users for the User Group.
+    pub users: Vec<Cow<'a, str>>,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
+impl<'a> UpdateRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        usergroup: impl Into<Cow<'a, str>>,
+        users: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> UpdateRequestBuilder<'a> {
+        UpdateRequestBuilder {
+            request: UpdateRequest {
+                usergroup: usergroup.into(),
+                users: users.into_iter().map(Into::into).collect(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`UpdateRequest`](struct.UpdateRequest.html), created by `UpdateRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct UpdateRequestBuilder<'a> {
+    request: UpdateRequest<'a>,
+}
+
+impl<'a> UpdateRequestBuilder<'a> {
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.request.include_count = Some(include_count);
+        self
+    }
+
+    pub fn build(self) -> UpdateRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct UpdateResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub usergroup: Option<::Usergroup>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
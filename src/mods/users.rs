@@ -1,6 +1,8 @@
 //! Get info on members of your Slack team.
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -11,6 +13,299 @@ use serde_json;
 
 use requests::SlackWebRequestSender;
 
+/// The presence a user can set for themselves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Presence {
+    /// Let Slack determine presence from the user's activity.
+    Auto,
+    /// Always appear away.
+    Away,
+}
+
+impl Presence {
+    /// The value sent to Slack for this variant.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Presence::Auto => "auto",
+            Presence::Away => "away",
+        }
+    }
+}
+
+impl Default for Presence {
+    fn default() -> Self {
+        Presence::Auto
+    }
+}
+
+impl fmt::Display for Presence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// List conversations the calling user may access.
+///
+/// Wraps https://api.slack.com/methods/users.conversations
+
+pub fn conversations<R>(
+    client: &R,
+    token: &str,
+    request: &ConversationsRequest,
+) -> Result<ConversationsResponse, ConversationsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let types = request.types.as_ref().map(|types| {
+        types.iter().map(::conversations::ConversationType::as_str).collect::<Vec<_>>().join(",")
+    });
+    let params = vec![
+        Some(("token", token)),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        request.exclude_archived.map(|exclude_archived| {
+            ("exclude_archived", if exclude_archived { "1" } else { "0" })
+        }),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        types.as_ref().map(|types| ("types", &types[..])),
+        request.user.as_ref().map(|user| ("user", &user[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("users.conversations");
+    client
+        .send(&url, &params[..])
+        .map_err(ConversationsError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ConversationsResponse>(&result).map_err(
+                ConversationsError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ConversationsRequest<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// Set to true to exclude archived channels from the list
+    pub exclude_archived: Option<bool>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
+    pub limit: Option<u32>,
+    /// The kinds of conversation to include. Defaults to public channels.
+    pub types: Option<Cow<'a, [::conversations::ConversationType]>>,
+    /// Browse conversations by a specific user ID's membership. Non-public channels are restricted to those where the calling user shares membership.
+    pub user: Option<Cow<'a, str>>,
+}
+
+impl<'a> ConversationsRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ConversationsRequestBuilder<'a> {
+        ConversationsRequestBuilder {
+            request: ConversationsRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ConversationsRequest`](struct.ConversationsRequest.html), created by `ConversationsRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ConversationsRequestBuilder<'a> {
+    request: ConversationsRequest<'a>,
+}
+
+impl<'a> ConversationsRequestBuilder<'a> {
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Set to true to exclude archived channels from the list
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.request.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    /// The kinds of conversation to include. Defaults to public channels.
+    pub fn types(mut self, types: impl Into<Cow<'a, [::conversations::ConversationType]>>) -> Self {
+        self.request.types = Some(types.into());
+        self
+    }
+
+    /// Browse conversations by a specific user ID's membership. Non-public channels are restricted to those where the calling user shares membership.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
+
+    pub fn build(self) -> ConversationsRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for ConversationsRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for ConversationsResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConversationsResponse {
+    pub channels: Option<Vec<::Conversation>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<ConversationsResponse, ConversationsError<E>>>
+    for ConversationsResponse {
+    fn into(self) -> Result<ConversationsResponse, ConversationsError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ConversationsError<E: Error> {
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Value passed for limit is not understood.
+    InvalidLimit,
+    /// Value passed for type could not be used based on the method's capabilities or the permission scopes granted to the used token.
+    InvalidTypes,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ConversationsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_cursor" => ConversationsError::InvalidCursor,
+            "invalid_limit" => ConversationsError::InvalidLimit,
+            "invalid_types" => ConversationsError::InvalidTypes,
+            "not_authed" => ConversationsError::NotAuthed,
+            "invalid_auth" => ConversationsError::InvalidAuth,
+            "account_inactive" => ConversationsError::AccountInactive,
+            "invalid_arg_name" => ConversationsError::InvalidArgName,
+            "invalid_array_arg" => ConversationsError::InvalidArrayArg,
+            "invalid_charset" => ConversationsError::InvalidCharset,
+            "invalid_form_data" => ConversationsError::InvalidFormData,
+            "invalid_post_type" => ConversationsError::InvalidPostType,
+            "missing_post_type" => ConversationsError::MissingPostType,
+            "team_added_to_org" => ConversationsError::TeamAddedToOrg,
+            "request_timeout" => ConversationsError::RequestTimeout,
+            _ => ConversationsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ConversationsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ConversationsError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ConversationsError::InvalidCursor => {
+                "invalid_cursor: Value passed for cursor was not valid or is no longer valid."
+            }
+            ConversationsError::InvalidLimit => {
+                "invalid_limit: Value passed for limit is not understood."
+            }
+            ConversationsError::InvalidTypes => {
+                "invalid_types: Value passed for type could not be used based on the method's capabilities or the permission scopes granted to the used token."
+            }
+            ConversationsError::NotAuthed => "not_authed: No authentication token provided.",
+            ConversationsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            ConversationsError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            ConversationsError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            ConversationsError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            ConversationsError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            ConversationsError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            ConversationsError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            ConversationsError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            ConversationsError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            ConversationsError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            ConversationsError::MalformedResponse(ref e) => e.description(),
+            ConversationsError::Unknown(ref s) => s,
+            ConversationsError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            ConversationsError::MalformedResponse(ref e) => Some(e),
+            ConversationsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 /// Delete the user profile photo
 ///
 /// Wraps https://api.slack.com/methods/users.deletePhoto
@@ -39,6 +334,8 @@ pub struct DeletePhotoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -174,7 +471,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
+    let params = vec![Some(("token", token)), Some(("user", &request.user[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.getPresence");
     client
@@ -190,7 +487,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct GetPresenceRequest<'a> {
     /// User to get presence info on. Defaults to the authed user.
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> GetPresenceRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(user: impl Into<Cow<'a, str>>) -> GetPresenceRequestBuilder<'a> {
+        GetPresenceRequestBuilder {
+            request: GetPresenceRequest {
+                user: user.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`GetPresenceRequest`](struct.GetPresenceRequest.html), created by `GetPresenceRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct GetPresenceRequestBuilder<'a> {
+    request: GetPresenceRequest<'a>,
+}
+
+impl<'a> GetPresenceRequestBuilder<'a> {
+    pub fn build(self) -> GetPresenceRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -199,6 +519,8 @@ pub struct GetPresenceResponse {
     #[serde(default)]
     ok: bool,
     pub presence: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -343,6 +665,8 @@ pub struct IdentityResponse {
     ok: bool,
     pub team: Option<::Team>,
     pub user: Option<::User>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -476,7 +800,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
+    let params = vec![Some(("token", token)), Some(("user", &request.user[..]))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.info");
     client
@@ -491,7 +815,30 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// User to get info on
-    pub user: &'a str,
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(user: impl Into<Cow<'a, str>>) -> InfoRequestBuilder<'a> {
+        InfoRequestBuilder {
+            request: InfoRequest {
+                user: user.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`InfoRequest`](struct.InfoRequest.html), created by `InfoRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct InfoRequestBuilder<'a> {
+    request: InfoRequest<'a>,
+}
+
+impl<'a> InfoRequestBuilder<'a> {
+    pub fn build(self) -> InfoRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -500,6 +847,8 @@ pub struct InfoResponse {
     #[serde(default)]
     ok: bool,
     pub user: Option<::User>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -638,12 +987,15 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
         Some(("token", token)),
         request.presence.map(|presence| {
             ("presence", if presence { "1" } else { "0" })
         }),
+        request.team_id.as_ref().map(|team_id| ("team_id", &team_id[..])),
+        request.cursor.as_ref().map(|cursor| ("cursor", &cursor[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.list");
@@ -657,9 +1009,74 @@ where
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+pub struct ListRequest<'a> {
     /// Whether to include presence data in the output
     pub presence: Option<bool>,
+    /// Encoded team ID to list users in. Required when using an org-level token on Enterprise Grid.
+    pub team_id: Option<Cow<'a, str>>,
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub cursor: Option<Cow<'a, str>>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+impl<'a> ListRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> ListRequestBuilder<'a> {
+        ListRequestBuilder {
+            request: ListRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`ListRequest`](struct.ListRequest.html), created by `ListRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct ListRequestBuilder<'a> {
+    request: ListRequest<'a>,
+}
+
+impl<'a> ListRequestBuilder<'a> {
+    /// Whether to include presence data in the output
+    pub fn presence(mut self, presence: bool) -> Self {
+        self.request.presence = Some(presence);
+        self
+    }
+
+    /// Encoded team ID to list users in. Required when using an org-level token on Enterprise Grid.
+    pub fn team_id(mut self, team_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.team_id = Some(team_id.into());
+        self
+    }
+
+    /// Paginate through collections of data by setting the cursor parameter to a next_cursor attribute returned by a previous request's response_metadata. Default value fetches the first "page" of the collection.
+    pub fn cursor(mut self, cursor: impl Into<Cow<'a, str>>) -> Self {
+        self.request.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.request.limit = Some(limit);
+        self
+    }
+
+    pub fn build(self) -> ListRequest<'a> {
+        self.request
+    }
+}
+
+impl<'a> ::pagination::CursorRequest for ListRequest<'a> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+impl ::pagination::CursorResponse for ListResponse {
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata.as_ref().and_then(|m| m.next_cursor.as_ref()).map(String::as_ref)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -668,6 +1085,9 @@ pub struct ListResponse {
     pub members: Option<Vec<::User>>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<::ResponseMetadata>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -784,6 +1204,193 @@ impl<E: Error> Error for ListError<E> {
     }
 }
 
+/// Find a user with an email address.
+///
+/// Wraps https://api.slack.com/methods/users.lookupByEmail
+
+pub fn lookup_by_email<R>(
+    client: &R,
+    token: &str,
+    request: &LookupByEmailRequest,
+) -> Result<LookupByEmailResponse, LookupByEmailError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+
+    let params = vec![Some(("token", token)), Some(("email", &request.email[..]))];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("users.lookupByEmail");
+    client
+        .send(&url, &params[..])
+        .map_err(LookupByEmailError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<LookupByEmailResponse>(&result).map_err(
+                LookupByEmailError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct LookupByEmailRequest<'a> {
+    /// An email address belonging to a user in the workspace
+    pub email: Cow<'a, str>,
+}
+
+impl<'a> LookupByEmailRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(email: impl Into<Cow<'a, str>>) -> LookupByEmailRequestBuilder<'a> {
+        LookupByEmailRequestBuilder {
+            request: LookupByEmailRequest {
+                email: email.into(),
+            },
+        }
+    }
+}
+
+/// Builder for [`LookupByEmailRequest`](struct.LookupByEmailRequest.html), created by `LookupByEmailRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct LookupByEmailRequestBuilder<'a> {
+    request: LookupByEmailRequest<'a>,
+}
+
+impl<'a> LookupByEmailRequestBuilder<'a> {
+    pub fn build(self) -> LookupByEmailRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct LookupByEmailResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub user: Option<::User>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<LookupByEmailResponse, LookupByEmailError<E>>>
+    for LookupByEmailResponse {
+    fn into(self) -> Result<LookupByEmailResponse, LookupByEmailError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum LookupByEmailError<E: Error> {
+    /// Value passed for email was invalid.
+    UsersNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for LookupByEmailError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "users_not_found" => LookupByEmailError::UsersNotFound,
+            "not_authed" => LookupByEmailError::NotAuthed,
+            "invalid_auth" => LookupByEmailError::InvalidAuth,
+            "account_inactive" => LookupByEmailError::AccountInactive,
+            "invalid_arg_name" => LookupByEmailError::InvalidArgName,
+            "invalid_array_arg" => LookupByEmailError::InvalidArrayArg,
+            "invalid_charset" => LookupByEmailError::InvalidCharset,
+            "invalid_form_data" => LookupByEmailError::InvalidFormData,
+            "invalid_post_type" => LookupByEmailError::InvalidPostType,
+            "missing_post_type" => LookupByEmailError::MissingPostType,
+            "team_added_to_org" => LookupByEmailError::TeamAddedToOrg,
+            "request_timeout" => LookupByEmailError::RequestTimeout,
+            _ => LookupByEmailError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for LookupByEmailError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for LookupByEmailError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            LookupByEmailError::UsersNotFound => {
+                "users_not_found: Value passed for email was invalid."
+            }
+            LookupByEmailError::NotAuthed => "not_authed: No authentication token provided.",
+            LookupByEmailError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            LookupByEmailError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            LookupByEmailError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            LookupByEmailError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            LookupByEmailError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            LookupByEmailError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            LookupByEmailError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            LookupByEmailError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            LookupByEmailError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            LookupByEmailError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            LookupByEmailError::MalformedResponse(ref e) => e.description(),
+            LookupByEmailError::Unknown(ref s) => s,
+            LookupByEmailError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            LookupByEmailError::MalformedResponse(ref e) => Some(e),
+            LookupByEmailError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 /// Marks a user as active.
 ///
 /// Wraps https://api.slack.com/methods/users.setActive
@@ -810,6 +1417,8 @@ pub struct SetActiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -940,7 +1549,7 @@ where
     R: SlackWebRequestSender,
 {
 
-    let params = vec![Some(("token", token)), Some(("presence", request.presence))];
+    let params = vec![Some(("token", token)), Some(("presence", request.presence.as_str()))];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.setPresence");
     client
@@ -954,9 +1563,32 @@ where
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct SetPresenceRequest<'a> {
+pub struct SetPresenceRequest {
     /// Either auto or away
-    pub presence: &'a str,
+    pub presence: Presence,
+}
+
+impl SetPresenceRequest {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(presence: Presence) -> SetPresenceRequestBuilder {
+        SetPresenceRequestBuilder {
+            request: SetPresenceRequest {
+                presence,
+            },
+        }
+    }
+}
+
+/// Builder for [`SetPresenceRequest`](struct.SetPresenceRequest.html), created by `SetPresenceRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct SetPresenceRequestBuilder {
+    request: SetPresenceRequest,
+}
+
+impl SetPresenceRequestBuilder {
+    pub fn build(self) -> SetPresenceRequest {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -964,6 +1596,8 @@ pub struct SetPresenceResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -1,5 +1,7 @@
 
 
+#[allow(unused_imports)]
+use std::borrow::Cow;
 #[allow(unused_imports)]
 use std::collections::HashMap;
 use std::convert::From;
@@ -25,7 +27,7 @@ where
 
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
         request.include_labels.map(|include_labels| {
             ("include_labels", if include_labels { "1" } else { "0" })
         }),
@@ -44,17 +46,54 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct GetRequest<'a> {
     /// User to retrieve profile info for
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
     /// Include labels for each ID in custom profile fields
     pub include_labels: Option<bool>,
 }
 
+impl<'a> GetRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> GetRequestBuilder<'a> {
+        GetRequestBuilder {
+            request: GetRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`GetRequest`](struct.GetRequest.html), created by `GetRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct GetRequestBuilder<'a> {
+    request: GetRequest<'a>,
+}
+
+impl<'a> GetRequestBuilder<'a> {
+    /// User to retrieve profile info for
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
+
+    /// Include labels for each ID in custom profile fields
+    pub fn include_labels(mut self, include_labels: bool) -> Self {
+        self.request.include_labels = Some(include_labels);
+        self
+    }
+
+    pub fn build(self) -> GetRequest<'a> {
+        self.request
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct GetResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     pub profile: Option<::UserProfile>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -191,13 +230,15 @@ pub fn set<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let profile = request.profile.as_ref().map(|profile| {
+        serde_json::to_string(profile).expect("profile is always serializable to JSON")
+    });
     let params = vec![
         Some(("token", token)),
-        request.user.map(|user| ("user", user)),
-        request.profile.map(|profile| ("profile", profile)),
-        request.name.map(|name| ("name", name)),
-        request.value.map(|value| ("value", value)),
+        request.user.as_ref().map(|user| ("user", &user[..])),
+        profile.as_ref().map(|profile| ("profile", &profile[..])),
+        request.name.as_ref().map(|name| ("name", &name[..])),
+        request.value.as_ref().map(|value| ("value", &value[..])),
     ];
     let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = ::get_slack_url_for_method("users.profile.set");
@@ -213,13 +254,60 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct SetRequest<'a> {
     /// ID of user to change. This argument may only be specified by team admins on paid teams.
-    pub user: Option<&'a str>,
+    pub user: Option<Cow<'a, str>>,
+    /// Collection of key:value pairs presented as a URL-encoded JSON hash.
+    pub profile: Option<::profile::ProfileUpdate>,
+    /// Name of a single key to set. Usable only if profile is not passed.
+    pub name: Option<Cow<'a, str>>,
+    /// Value to set a single key to. Usable only if profile is not passed.
+    pub value: Option<Cow<'a, str>>,
+}
+
+impl<'a> SetRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder() -> SetRequestBuilder<'a> {
+        SetRequestBuilder {
+            request: SetRequest {
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`SetRequest`](struct.SetRequest.html), created by `SetRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct SetRequestBuilder<'a> {
+    request: SetRequest<'a>,
+}
+
+impl<'a> SetRequestBuilder<'a> {
+    /// ID of user to change. This argument may only be specified by team admins on paid teams.
+    pub fn user(mut self, user: impl Into<Cow<'a, str>>) -> Self {
+        self.request.user = Some(user.into());
+        self
+    }
+
     /// Collection of key:value pairs presented as a URL-encoded JSON hash.
-    pub profile: Option<&'a str>,
+    pub fn profile(mut self, profile: ::profile::ProfileUpdate) -> Self {
+        self.request.profile = Some(profile);
+        self
+    }
+
     /// Name of a single key to set. Usable only if profile is not passed.
-    pub name: Option<&'a str>,
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self {
+        self.request.name = Some(name.into());
+        self
+    }
+
     /// Value to set a single key to. Usable only if profile is not passed.
-    pub value: Option<&'a str>,
+    pub fn value(mut self, value: impl Into<Cow<'a, str>>) -> Self {
+        self.request.value = Some(value.into());
+        self
+    }
+
+    pub fn build(self) -> SetRequest<'a> {
+        self.request
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -228,6 +316,8 @@ pub struct SetResponse {
     #[serde(default)]
     ok: bool,
     pub profile: Option<::UserProfile>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -0,0 +1,913 @@
+//! Open, update and publish modals and App Home views.
+
+
+#[allow(unused_imports)]
+use std::borrow::Cow;
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+use serde_json;
+
+use requests::SlackWebRequestSender;
+
+/// Open a view for a user.
+///
+/// Wraps https://api.slack.com/methods/views.open
+
+pub fn open<R>(
+    client: &R,
+    token: &str,
+    request: &OpenRequest,
+) -> Result<OpenResponse, OpenError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let view = serde_json::to_string(&request.view).expect("view is always serializable to JSON");
+    let params = vec![
+        Some(("token", token)),
+        Some(("trigger_id", &request.trigger_id[..])),
+        Some(("view", &view[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("views.open");
+    client
+        .send(&url, &params[..])
+        .map_err(OpenError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<OpenResponse>(&result).map_err(OpenError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct OpenRequest<'a> {
+    /// Exchange a trigger to post to the user.
+    pub trigger_id: Cow<'a, str>,
+    /// A view payload.
+    pub view: ::view::View,
+}
+
+impl<'a> OpenRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        trigger_id: impl Into<Cow<'a, str>>,
+        view: ::view::View,
+    ) -> OpenRequestBuilder<'a> {
+        OpenRequestBuilder {
+            request: OpenRequest {
+                trigger_id: trigger_id.into(),
+                view,
+            },
+        }
+    }
+}
+
+/// Builder for [`OpenRequest`](struct.OpenRequest.html), created by `OpenRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct OpenRequestBuilder<'a> {
+    request: OpenRequest<'a>,
+}
+
+impl<'a> OpenRequestBuilder<'a> {
+    pub fn build(self) -> OpenRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OpenResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub view: Option<::view::View>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
+    fn into(self) -> Result<OpenResponse, OpenError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum OpenError<E: Error> {
+    /// Error returned when the given external_id has already be used.
+    DuplicateExternalId,
+    /// Error returned when the trigger_id has expired.
+    ExpiredTriggerId,
+    /// Error returned when the trigger_id has already been exchanged in a previous call.
+    ExchangedTriggerId,
+    /// Error returned when the trigger_id is invalid.
+    InvalidTriggerId,
+    /// Error returned if the provided view is greater than 250kb.
+    ViewTooLarge,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for OpenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "duplicate_external_id" => OpenError::DuplicateExternalId,
+            "expired_trigger_id" => OpenError::ExpiredTriggerId,
+            "exchanged_trigger_id" => OpenError::ExchangedTriggerId,
+            "invalid_trigger_id" => OpenError::InvalidTriggerId,
+            "view_too_large" => OpenError::ViewTooLarge,
+            "not_authed" => OpenError::NotAuthed,
+            "invalid_auth" => OpenError::InvalidAuth,
+            "account_inactive" => OpenError::AccountInactive,
+            "invalid_arg_name" => OpenError::InvalidArgName,
+            "invalid_array_arg" => OpenError::InvalidArrayArg,
+            "invalid_charset" => OpenError::InvalidCharset,
+            "invalid_form_data" => OpenError::InvalidFormData,
+            "invalid_post_type" => OpenError::InvalidPostType,
+            "missing_post_type" => OpenError::MissingPostType,
+            "team_added_to_org" => OpenError::TeamAddedToOrg,
+            "request_timeout" => OpenError::RequestTimeout,
+            _ => OpenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for OpenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for OpenError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            OpenError::DuplicateExternalId => {
+                "duplicate_external_id: Error returned when the given external_id has already be used."
+            }
+            OpenError::ExpiredTriggerId => {
+                "expired_trigger_id: Error returned when the trigger_id has expired."
+            }
+            OpenError::ExchangedTriggerId => {
+                "exchanged_trigger_id: Error returned when the trigger_id has already been exchanged in a previous call."
+            }
+            OpenError::InvalidTriggerId => {
+                "invalid_trigger_id: Error returned when the trigger_id is invalid."
+            }
+            OpenError::ViewTooLarge => {
+                "view_too_large: Error returned if the provided view is greater than 250kb."
+            }
+            OpenError::NotAuthed => "not_authed: No authentication token provided.",
+            OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            OpenError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            OpenError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            OpenError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            OpenError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            OpenError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            OpenError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            OpenError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            OpenError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            OpenError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            OpenError::MalformedResponse(ref e) => e.description(),
+            OpenError::Unknown(ref s) => s,
+            OpenError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            OpenError::MalformedResponse(ref e) => Some(e),
+            OpenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Publish a static view for a User.
+///
+/// Wraps https://api.slack.com/methods/views.publish
+
+pub fn publish<R>(
+    client: &R,
+    token: &str,
+    request: &PublishRequest,
+) -> Result<PublishResponse, PublishError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let view = serde_json::to_string(&request.view).expect("view is always serializable to JSON");
+    let params = vec![
+        Some(("token", token)),
+        Some(("user_id", &request.user_id[..])),
+        Some(("view", &view[..])),
+        request.hash.as_ref().map(|hash| ("hash", &hash[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("views.publish");
+    client
+        .send(&url, &params[..])
+        .map_err(PublishError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<PublishResponse>(&result).map_err(
+                PublishError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct PublishRequest<'a> {
+    /// id of the user you want publish a view to.
+    pub user_id: Cow<'a, str>,
+    /// A view payload.
+    pub view: ::view::View,
+    /// A string that represents view state to protect against possible race conditions.
+    pub hash: Option<Cow<'a, str>>,
+}
+
+impl<'a> PublishRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        user_id: impl Into<Cow<'a, str>>,
+        view: ::view::View,
+    ) -> PublishRequestBuilder<'a> {
+        PublishRequestBuilder {
+            request: PublishRequest {
+                user_id: user_id.into(),
+                view,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`PublishRequest`](struct.PublishRequest.html), created by `PublishRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct PublishRequestBuilder<'a> {
+    request: PublishRequest<'a>,
+}
+
+impl<'a> PublishRequestBuilder<'a> {
+    /// A string that represents view state to protect against possible race conditions.
+    pub fn hash(mut self, hash: impl Into<Cow<'a, str>>) -> Self {
+        self.request.hash = Some(hash.into());
+        self
+    }
+
+    pub fn build(self) -> PublishRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PublishResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub view: Option<::view::View>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<PublishResponse, PublishError<E>>> for PublishResponse {
+    fn into(self) -> Result<PublishResponse, PublishError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PublishError<E: Error> {
+    /// The App Home tab is not enabled for this app.
+    NotEnabled,
+    /// Error returned when the given external_id has already be used.
+    DuplicateExternalId,
+    /// Error returned when the provided hash doesn't match the current stored value.
+    HashConflict,
+    /// Error returned if the provided view is greater than 250kb.
+    ViewTooLarge,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PublishError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "not_enabled" => PublishError::NotEnabled,
+            "duplicate_external_id" => PublishError::DuplicateExternalId,
+            "hash_conflict" => PublishError::HashConflict,
+            "view_too_large" => PublishError::ViewTooLarge,
+            "not_authed" => PublishError::NotAuthed,
+            "invalid_auth" => PublishError::InvalidAuth,
+            "account_inactive" => PublishError::AccountInactive,
+            "invalid_arg_name" => PublishError::InvalidArgName,
+            "invalid_array_arg" => PublishError::InvalidArrayArg,
+            "invalid_charset" => PublishError::InvalidCharset,
+            "invalid_form_data" => PublishError::InvalidFormData,
+            "invalid_post_type" => PublishError::InvalidPostType,
+            "missing_post_type" => PublishError::MissingPostType,
+            "team_added_to_org" => PublishError::TeamAddedToOrg,
+            "request_timeout" => PublishError::RequestTimeout,
+            _ => PublishError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for PublishError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for PublishError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            PublishError::NotEnabled => {
+                "not_enabled: The App Home tab is not enabled for this app."
+            }
+            PublishError::DuplicateExternalId => {
+                "duplicate_external_id: Error returned when the given external_id has already be used."
+            }
+            PublishError::HashConflict => {
+                "hash_conflict: Error returned when the provided hash doesn't match the current stored value."
+            }
+            PublishError::ViewTooLarge => {
+                "view_too_large: Error returned if the provided view is greater than 250kb."
+            }
+            PublishError::NotAuthed => "not_authed: No authentication token provided.",
+            PublishError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            PublishError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            PublishError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            PublishError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            PublishError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            PublishError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            PublishError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            PublishError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            PublishError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            PublishError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            PublishError::MalformedResponse(ref e) => e.description(),
+            PublishError::Unknown(ref s) => s,
+            PublishError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            PublishError::MalformedResponse(ref e) => Some(e),
+            PublishError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Push a view onto the stack of a root view.
+///
+/// Wraps https://api.slack.com/methods/views.push
+
+pub fn push<R>(
+    client: &R,
+    token: &str,
+    request: &PushRequest,
+) -> Result<PushResponse, PushError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let view = serde_json::to_string(&request.view).expect("view is always serializable to JSON");
+    let params = vec![
+        Some(("token", token)),
+        Some(("trigger_id", &request.trigger_id[..])),
+        Some(("view", &view[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("views.push");
+    client
+        .send(&url, &params[..])
+        .map_err(PushError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<PushResponse>(&result).map_err(PushError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct PushRequest<'a> {
+    /// Exchange a trigger to post to the user.
+    pub trigger_id: Cow<'a, str>,
+    /// A view payload.
+    pub view: ::view::View,
+}
+
+impl<'a> PushRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(
+        trigger_id: impl Into<Cow<'a, str>>,
+        view: ::view::View,
+    ) -> PushRequestBuilder<'a> {
+        PushRequestBuilder {
+            request: PushRequest {
+                trigger_id: trigger_id.into(),
+                view,
+            },
+        }
+    }
+}
+
+/// Builder for [`PushRequest`](struct.PushRequest.html), created by `PushRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct PushRequestBuilder<'a> {
+    request: PushRequest<'a>,
+}
+
+impl<'a> PushRequestBuilder<'a> {
+    pub fn build(self) -> PushRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct PushResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub view: Option<::view::View>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<PushResponse, PushError<E>>> for PushResponse {
+    fn into(self) -> Result<PushResponse, PushError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PushError<E: Error> {
+    /// Error returned when the given external_id has already be used.
+    DuplicateExternalId,
+    /// Error returned when the trigger_id has expired.
+    ExpiredTriggerId,
+    /// Error returned when the trigger_id has already been exchanged in a previous call.
+    ExchangedTriggerId,
+    /// Error returned when the trigger_id is invalid.
+    InvalidTriggerId,
+    /// Error returned when the max push limit has been reached for views. Currently the limit is 3.
+    PushLimitReached,
+    /// Error returned if the provided view is greater than 250kb.
+    ViewTooLarge,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PushError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "duplicate_external_id" => PushError::DuplicateExternalId,
+            "expired_trigger_id" => PushError::ExpiredTriggerId,
+            "exchanged_trigger_id" => PushError::ExchangedTriggerId,
+            "invalid_trigger_id" => PushError::InvalidTriggerId,
+            "push_limit_reached" => PushError::PushLimitReached,
+            "view_too_large" => PushError::ViewTooLarge,
+            "not_authed" => PushError::NotAuthed,
+            "invalid_auth" => PushError::InvalidAuth,
+            "account_inactive" => PushError::AccountInactive,
+            "invalid_arg_name" => PushError::InvalidArgName,
+            "invalid_array_arg" => PushError::InvalidArrayArg,
+            "invalid_charset" => PushError::InvalidCharset,
+            "invalid_form_data" => PushError::InvalidFormData,
+            "invalid_post_type" => PushError::InvalidPostType,
+            "missing_post_type" => PushError::MissingPostType,
+            "team_added_to_org" => PushError::TeamAddedToOrg,
+            "request_timeout" => PushError::RequestTimeout,
+            _ => PushError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for PushError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for PushError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            PushError::DuplicateExternalId => {
+                "duplicate_external_id: Error returned when the given external_id has already be used."
+            }
+            PushError::ExpiredTriggerId => {
+                "expired_trigger_id: Error returned when the trigger_id has expired."
+            }
+            PushError::ExchangedTriggerId => {
+                "exchanged_trigger_id: Error returned when the trigger_id has already been exchanged in a previous call."
+            }
+            PushError::InvalidTriggerId => {
+                "invalid_trigger_id: Error returned when the trigger_id is invalid."
+            }
+            PushError::PushLimitReached => {
+                "push_limit_reached: Error returned when the max push limit has been reached for views. Currently the limit is 3."
+            }
+            PushError::ViewTooLarge => {
+                "view_too_large: Error returned if the provided view is greater than 250kb."
+            }
+            PushError::NotAuthed => "not_authed: No authentication token provided.",
+            PushError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            PushError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            PushError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            PushError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            PushError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            PushError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            PushError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            PushError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            PushError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            PushError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            PushError::MalformedResponse(ref e) => e.description(),
+            PushError::Unknown(ref s) => s,
+            PushError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            PushError::MalformedResponse(ref e) => Some(e),
+            PushError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Update an existing view.
+///
+/// Wraps https://api.slack.com/methods/views.update
+
+pub fn update<R>(
+    client: &R,
+    token: &str,
+    request: &UpdateRequest,
+) -> Result<UpdateResponse, UpdateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let view = serde_json::to_string(&request.view).expect("view is always serializable to JSON");
+    let params = vec![
+        Some(("token", token)),
+        Some(("view", &view[..])),
+        request.external_id.as_ref().map(|external_id| ("external_id", &external_id[..])),
+        request.hash.as_ref().map(|hash| ("hash", &hash[..])),
+        request.view_id.as_ref().map(|view_id| ("view_id", &view_id[..])),
+    ];
+    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = ::get_slack_url_for_method("views.update");
+    client
+        .send(&url, &params[..])
+        .map_err(UpdateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UpdateResponse>(&result).map_err(UpdateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UpdateRequest<'a> {
+    /// A view object.
+    pub view: ::view::View,
+    /// A unique identifier of the view set by the developer. Must be unique for all views on a team. Either view_id or external_id is required.
+    pub external_id: Option<Cow<'a, str>>,
+    /// A string that represents view state to protect against possible race conditions.
+    pub hash: Option<Cow<'a, str>>,
+    /// A unique identifier of the view to be updated. Either view_id or external_id is required.
+    pub view_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> UpdateRequest<'a> {
+    /// Starts building a request, taking the parameters that Slack requires.
+    pub fn builder(view: ::view::View) -> UpdateRequestBuilder<'a> {
+        UpdateRequestBuilder {
+            request: UpdateRequest {
+                view,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Builder for [`UpdateRequest`](struct.UpdateRequest.html), created by `UpdateRequest::builder`.
+#[derive(Clone, Debug)]
+pub struct UpdateRequestBuilder<'a> {
+    request: UpdateRequest<'a>,
+}
+
+impl<'a> UpdateRequestBuilder<'a> {
+    /// A unique identifier of the view set by the developer. Must be unique for all views on a team. Either view_id or external_id is required.
+    pub fn external_id(mut self, external_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.external_id = Some(external_id.into());
+        self
+    }
+
+    /// A string that represents view state to protect against possible race conditions.
+    pub fn hash(mut self, hash: impl Into<Cow<'a, str>>) -> Self {
+        self.request.hash = Some(hash.into());
+        self
+    }
+
+    /// A unique identifier of the view to be updated. Either view_id or external_id is required.
+    pub fn view_id(mut self, view_id: impl Into<Cow<'a, str>>) -> Self {
+        self.request.view_id = Some(view_id.into());
+        self
+    }
+
+    pub fn build(self) -> UpdateRequest<'a> {
+        self.request
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub view: Option<::view::View>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
+    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UpdateError<E: Error> {
+    /// Error returned when the given view_id or external_id doesn't exist.
+    ViewNotFound,
+    /// Error returned when the given external_id has already be used.
+    DuplicateExternalId,
+    /// Error returned when the provided hash doesn't match the current stored value.
+    HashConflict,
+    /// Error returned if the provided view is greater than 250kb.
+    ViewTooLarge,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UpdateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "view_not_found" => UpdateError::ViewNotFound,
+            "duplicate_external_id" => UpdateError::DuplicateExternalId,
+            "hash_conflict" => UpdateError::HashConflict,
+            "view_too_large" => UpdateError::ViewTooLarge,
+            "not_authed" => UpdateError::NotAuthed,
+            "invalid_auth" => UpdateError::InvalidAuth,
+            "account_inactive" => UpdateError::AccountInactive,
+            "invalid_arg_name" => UpdateError::InvalidArgName,
+            "invalid_array_arg" => UpdateError::InvalidArrayArg,
+            "invalid_charset" => UpdateError::InvalidCharset,
+            "invalid_form_data" => UpdateError::InvalidFormData,
+            "invalid_post_type" => UpdateError::InvalidPostType,
+            "missing_post_type" => UpdateError::MissingPostType,
+            "team_added_to_org" => UpdateError::TeamAddedToOrg,
+            "request_timeout" => UpdateError::RequestTimeout,
+            _ => UpdateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for UpdateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for UpdateError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateError::ViewNotFound => {
+                "view_not_found: Error returned when the given view_id or external_id doesn't exist."
+            }
+            UpdateError::DuplicateExternalId => {
+                "duplicate_external_id: Error returned when the given external_id has already be used."
+            }
+            UpdateError::HashConflict => {
+                "hash_conflict: Error returned when the provided hash doesn't match the current stored value."
+            }
+            UpdateError::ViewTooLarge => {
+                "view_too_large: Error returned if the provided view is greater than 250kb."
+            }
+            UpdateError::NotAuthed => "not_authed: No authentication token provided.",
+            UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+            UpdateError::AccountInactive => {
+                "account_inactive: Authentication token is for a deleted user or team."
+            }
+            UpdateError::InvalidArgName => {
+                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
+            }
+            UpdateError::InvalidArrayArg => {
+                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
+            }
+            UpdateError::InvalidCharset => {
+                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
+            }
+            UpdateError::InvalidFormData => {
+                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
+            }
+            UpdateError::InvalidPostType => {
+                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
+            }
+            UpdateError::MissingPostType => {
+                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
+            }
+            UpdateError::TeamAddedToOrg => {
+                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
+            }
+            UpdateError::RequestTimeout => {
+                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
+            }
+            UpdateError::MalformedResponse(ref e) => e.description(),
+            UpdateError::Unknown(ref s) => s,
+            UpdateError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            UpdateError::MalformedResponse(ref e) => Some(e),
+            UpdateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,554 @@
+//! Walking through every page of a paginated method.
+//!
+//! Methods that page with a cursor return a `response_metadata.next_cursor`, which is passed back
+//! as the `cursor` of the next request. `cursor_pages` does this for you.
+//!
+//! Older methods page by number instead, responding with a `Paging` that says how many pages
+//! there are. `page_items` asks for each page in turn and yields the items one by one.
+//!
+//! The `history` methods of `channels`, `groups`, `im` and `mpim` return the newest messages
+//! first, and say whether there are older ones with `has_more`. `history_messages` pages back
+//! through them by moving `latest` to the oldest message seen so far.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R, token: &str) {
+//! use slack_api::conversations;
+//! use slack_api::pagination::cursor_pages;
+//!
+//! let request = conversations::ListRequest::builder().limit(200).build();
+//! for page in cursor_pages(client, token, request, conversations::list) {
+//!     match page {
+//!         Ok(page) => println!("{} channels", page.channels.map_or(0, |c| c.len())),
+//!         Err(err) => println!("failed to list channels: {}", err),
+//!     }
+//! }
+//! # }
+//! ```
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R, token: &str) {
+//! use slack_api::pagination::page_items;
+//! use slack_api::search;
+//!
+//! let request = search::MessagesRequest::builder("has:link").count(100).build();
+//! for message in page_items(client, token, request, search::messages).max_items(500) {
+//!     match message {
+//!         Ok(message) => println!("{:?}", message),
+//!         Err(err) => println!("failed to search: {}", err),
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+use std::vec;
+
+use types::Message;
+
+/// A request to a method that pages through its results with a cursor.
+pub trait CursorRequest {
+    /// Sets the cursor that selects the page to fetch.
+    fn set_cursor(&mut self, cursor: String);
+}
+
+/// A response from a method that pages through its results with a cursor.
+pub trait CursorResponse {
+    /// The cursor of the next page, or `None` on the last page.
+    fn next_cursor(&self) -> Option<&str>;
+}
+
+/// An iterator over the pages of a cursor-paginated method, created by `cursor_pages`.
+///
+/// Each request is sent when the next page is asked for. Iteration stops after the last page or
+/// after the first error, which is yielded.
+pub struct CursorPages<'c, R: 'c, Q, F> {
+    client: &'c R,
+    token: &'c str,
+    request: Option<Q>,
+    method: F,
+}
+
+/// Walks every page of a cursor-paginated method, starting from `request`.
+///
+/// `method` is the function wrapping the Slack method, such as `conversations::list`.
+pub fn cursor_pages<'c, R, Q, F>(
+    client: &'c R,
+    token: &'c str,
+    request: Q,
+    method: F,
+) -> CursorPages<'c, R, Q, F> {
+    CursorPages {
+        client,
+        token,
+        request: Some(request),
+        method,
+    }
+}
+
+impl<'c, R, Q, F, P, E> Iterator for CursorPages<'c, R, Q, F>
+where
+    Q: CursorRequest,
+    F: FnMut(&R, &str, &Q) -> Result<P, E>,
+    P: CursorResponse,
+{
+    type Item = Result<P, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut request = self.request.take()?;
+        let page = match (self.method)(self.client, self.token, &request) {
+            Ok(page) => page,
+            Err(err) => return Some(Err(err)),
+        };
+        // Slack signals the last page with an empty cursor rather than by leaving it out.
+        match page.next_cursor() {
+            Some(cursor) if !cursor.is_empty() => {
+                request.set_cursor(cursor.to_owned());
+                self.request = Some(request);
+            }
+            _ => {}
+        }
+        Some(Ok(page))
+    }
+}
+
+/// A request to a method that pages through its results by page number.
+pub trait PageRequest {
+    /// Sets the number of the page to fetch, starting from 1.
+    fn set_page(&mut self, page: u32);
+}
+
+/// A response from a method that pages through its results by page number.
+pub trait PageResponse {
+    type Item;
+
+    /// Which page this is, and how many there are.
+    fn paging(&self) -> Option<&::Paging>;
+
+    /// The items on this page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// An iterator over the items of a method paged by page number, created by `page_items`.
+///
+/// Each page is fetched once the items of the one before have been yielded. Iteration stops after
+/// the last page, after the first error, which is yielded, or once `max_items` items have been
+/// yielded.
+pub struct PageItems<'c, R: 'c, Q, F, P: PageResponse> {
+    client: &'c R,
+    token: &'c str,
+    request: Option<Q>,
+    method: F,
+    items: vec::IntoIter<P::Item>,
+    max_items: Option<usize>,
+}
+
+/// Walks the items on every page of a method paged by page number, starting from `request`.
+///
+/// `method` is the function wrapping the Slack method, such as `search::messages`. The page size
+/// is whatever `request` asks for.
+pub fn page_items<'c, R, Q, F, P, E>(
+    client: &'c R,
+    token: &'c str,
+    request: Q,
+    method: F,
+) -> PageItems<'c, R, Q, F, P>
+where
+    F: FnMut(&R, &str, &Q) -> Result<P, E>,
+    P: PageResponse,
+{
+    PageItems {
+        client,
+        token,
+        request: Some(request),
+        method,
+        items: Vec::new().into_iter(),
+        max_items: None,
+    }
+}
+
+impl<'c, R, Q, F, P: PageResponse> PageItems<'c, R, Q, F, P> {
+    /// Stops after yielding `max_items` items, without fetching any more pages.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+impl<'c, R, Q, F, P, E> Iterator for PageItems<'c, R, Q, F, P>
+where
+    Q: PageRequest,
+    F: FnMut(&R, &str, &Q) -> Result<P, E>,
+    P: PageResponse,
+{
+    type Item = Result<P::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.max_items == Some(0) {
+                self.request = None;
+                return None;
+            }
+            if let Some(item) = self.items.next() {
+                self.max_items = self.max_items.map(|max_items| max_items - 1);
+                return Some(Ok(item));
+            }
+
+            let mut request = self.request.take()?;
+            let page = match (self.method)(self.client, self.token, &request) {
+                Ok(page) => page,
+                Err(err) => return Some(Err(err)),
+            };
+            if let Some(next_page) = page.paging().and_then(next_page) {
+                request.set_page(next_page);
+                self.request = Some(request);
+            }
+            self.items = page.into_items().into_iter();
+        }
+    }
+}
+
+/// The number of the page after `paging`, unless it is the last one.
+fn next_page(paging: &::Paging) -> Option<u32> {
+    let page = paging.page.unwrap_or(1);
+    match paging.pages {
+        Some(pages) if page < pages => Some(page as u32 + 1),
+        _ => None,
+    }
+}
+
+/// A request for the history of a conversation, paged back through by moving `latest`.
+pub trait HistoryRequest {
+    /// Sets the timestamp of the newest message to fetch.
+    fn set_latest(&mut self, latest: String);
+}
+
+/// A page of the history of a conversation, newest message first.
+pub trait HistoryResponse {
+    /// Whether there are messages older than the ones on this page.
+    fn has_more(&self) -> bool;
+
+    /// The messages on this page.
+    fn into_messages(self) -> Vec<Message>;
+}
+
+/// An iterator over the messages in the history of a conversation, newest first, created by
+/// `history_messages`.
+///
+/// Each page is fetched once the messages of the one before have been yielded. Iteration stops
+/// once Slack has no more messages, or after the first error, which is yielded.
+pub struct HistoryMessages<'c, R: 'c, Q, F> {
+    client: &'c R,
+    token: &'c str,
+    request: Option<Q>,
+    method: F,
+    messages: vec::IntoIter<Message>,
+    /// The timestamp of the oldest message yielded so far, whose repeats are skipped.
+    latest: Option<String>,
+}
+
+/// Walks back through every message between the `oldest` and `latest` of `request`.
+///
+/// `method` is one of `channels::history`, `groups::history`, `im::history` or `mpim::history`.
+/// `inclusive` is kept on every request so that messages at `oldest` are included as asked;
+/// messages repeated at the `latest` of later pages are skipped, and a page of nothing but repeats
+/// moves `latest` back past them.
+pub fn history_messages<'c, R, Q, F>(
+    client: &'c R,
+    token: &'c str,
+    request: Q,
+    method: F,
+) -> HistoryMessages<'c, R, Q, F> {
+    HistoryMessages {
+        client,
+        token,
+        request: Some(request),
+        method,
+        messages: Vec::new().into_iter(),
+        latest: None,
+    }
+}
+
+impl<'c, R, Q, F, P, E> Iterator for HistoryMessages<'c, R, Q, F>
+where
+    Q: HistoryRequest,
+    F: FnMut(&R, &str, &Q) -> Result<P, E>,
+    P: HistoryResponse,
+{
+    type Item = Result<Message, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(message) = self.messages.next() {
+                return Some(Ok(message));
+            }
+
+            let mut request = self.request.take()?;
+            let page = match (self.method)(self.client, self.token, &request) {
+                Ok(page) => page,
+                Err(err) => return Some(Err(err)),
+            };
+            let has_more = page.has_more();
+            let mut messages = page.into_messages();
+            let previous = self.latest.take();
+            if let Some(ref previous) = previous {
+                messages.retain(|m| match m.ts() {
+                    Some(ts) => compare_ts(ts, previous) == Ordering::Less,
+                    None => true,
+                });
+            }
+
+            let oldest = messages.iter().filter_map(Message::ts).min_by(|a, b| compare_ts(a, b));
+            match (has_more, oldest, previous) {
+                (true, Some(oldest), _) => {
+                    request.set_latest(oldest.to_owned());
+                    self.latest = Some(oldest.to_owned());
+                    self.request = Some(request);
+                }
+                // Timestamps are unique within a conversation, so nothing is skipped by asking
+                // for the messages before the repeated one.
+                (true, None, Some(previous)) => {
+                    request.set_latest(ts_before(&previous));
+                    self.latest = Some(previous);
+                    self.request = Some(request);
+                }
+                _ => {}
+            }
+            self.messages = messages.into_iter();
+        }
+    }
+}
+
+/// Orders message timestamps, which are seconds and microseconds since the Unix epoch, e.g.
+/// `"1512085950.000216"`.
+fn compare_ts(a: &str, b: &str) -> Ordering {
+    parse_ts(a).cmp(&parse_ts(b))
+}
+
+/// The timestamp one microsecond before `ts`.
+fn ts_before(ts: &str) -> String {
+    match parse_ts(ts) {
+        (seconds, 0) => format!("{}.999999", seconds.saturating_sub(1)),
+        (seconds, micros) => format!("{}.{:06}", seconds, micros - 1),
+    }
+}
+
+fn parse_ts(ts: &str) -> (u64, u64) {
+    let mut parts = ts.splitn(2, '.');
+    let seconds = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    let micros = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    (seconds, micros)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+    use channels;
+    use search;
+    use users::{ListRequest, ListResponse};
+
+    fn page(next_cursor: &str) -> ListResponse {
+        serde_json::from_value(json!({
+            "ok": true,
+            "members": [],
+            "response_metadata": {"next_cursor": next_cursor}
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_cursor_pages_follow_next_cursor() {
+        let mut cursors = vec![];
+        {
+            let request = ListRequest::builder().limit(2).build();
+            let pages = cursor_pages(&(), "xoxp-token", request, |_: &(), _: &str, request: &ListRequest| {
+                cursors.push(request.cursor.as_ref().map(|cursor| cursor.to_string()));
+                Ok::<_, ()>(page(["dXNlcjpVMDYx", "dXNlcjpVMDcz", ""][cursors.len() - 1]))
+            });
+            assert_eq!(3, pages.count());
+        }
+
+        assert_eq!(
+            vec![None, Some("dXNlcjpVMDYx".to_owned()), Some("dXNlcjpVMDcz".to_owned())],
+            cursors
+        );
+    }
+
+    #[test]
+    fn test_cursor_pages_stop_after_an_error() {
+        let mut calls = 0;
+        let request = ListRequest::builder().build();
+        let pages = cursor_pages(&(), "xoxp-token", request, |_: &(), _: &str, _: &ListRequest| {
+            calls += 1;
+            if calls == 1 {
+                Ok(page("dXNlcjpVMDYx"))
+            } else {
+                Err("invalid_cursor")
+            }
+        }).collect::<Vec<_>>();
+
+        assert_eq!(2, pages.len());
+        assert_eq!(Some(&"invalid_cursor"), pages[1].as_ref().err());
+    }
+
+    fn search_page(page: i32, pages: i32) -> search::MessagesResponse {
+        serde_json::from_value(json!({
+            "ok": true,
+            "messages": {
+                "matches": [{"type": "message", "ts": "1"}, {"type": "message", "ts": "2"}],
+                "paging": {"count": 2, "page": page, "pages": pages, "total": 2 * pages}
+            }
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_page_items_fetch_every_page() {
+        let mut pages = vec![];
+        {
+            let request = search::MessagesRequest::builder("has:link").count(2).build();
+            let items = page_items(&(), "xoxp-token", request, |_: &(), _: &str, request: &search::MessagesRequest| {
+                pages.push(request.page);
+                Ok::<_, ()>(search_page(request.page.unwrap_or(1) as i32, 3))
+            });
+            assert_eq!(6, items.count());
+        }
+
+        assert_eq!(vec![None, Some(2), Some(3)], pages);
+    }
+
+    #[test]
+    fn test_page_items_stop_at_max_items() {
+        let mut calls = 0;
+        {
+            let request = search::MessagesRequest::builder("has:link").count(2).build();
+            let items = page_items(&(), "xoxp-token", request, |_: &(), _: &str, request: &search::MessagesRequest| {
+                calls += 1;
+                Ok::<_, ()>(search_page(request.page.unwrap_or(1) as i32, 10))
+            });
+            assert_eq!(3, items.max_items(3).count());
+        }
+
+        assert_eq!(2, calls);
+    }
+
+    #[test]
+    fn test_page_items_stop_after_an_error() {
+        let request = search::MessagesRequest::builder("has:link").build();
+        let items = page_items(&(), "xoxp-token", request, |_: &(), _: &str, request: &search::MessagesRequest| {
+            match request.page {
+                None => Ok(search_page(1, 3)),
+                Some(_) => Err("ratelimited"),
+            }
+        }).collect::<Vec<_>>();
+
+        assert_eq!(3, items.len());
+        assert_eq!(Some(&"ratelimited"), items[2].as_ref().err());
+    }
+
+    fn history_page(timestamps: &[&str], has_more: bool) -> channels::HistoryResponse {
+        let messages = timestamps.iter()
+            .map(|ts| json!({"type": "message", "user": "U2147483697", "text": "Hello", "ts": ts}))
+            .collect::<Vec<_>>();
+        serde_json::from_value(json!({"ok": true, "messages": messages, "has_more": has_more}))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_history_messages_page_back_without_repeats() {
+        let mut latest = vec![];
+        let timestamps = {
+            let request = channels::HistoryRequest::builder("C1H9RESGL")
+                .oldest("1512085950.000100")
+                .inclusive(true)
+                .count(3)
+                .build();
+            let messages = history_messages(&(), "xoxp-token", request, |_: &(), _: &str, request: &channels::HistoryRequest| {
+                latest.push(request.latest.as_ref().map(|latest| latest.to_string()));
+                Ok::<_, ()>(match latest.len() {
+                    1 => history_page(&["1512085950.000900", "1512085950.000800", "1512085950.000700"], true),
+                    2 => history_page(&["1512085950.000700", "1512085950.000600", "1512085950.000500"], true),
+                    _ => history_page(&["1512085950.000500", "1512085950.000100"], false),
+                })
+            });
+            messages.map(|m| m.unwrap().ts().unwrap().to_owned()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![
+                "1512085950.000900",
+                "1512085950.000800",
+                "1512085950.000700",
+                "1512085950.000600",
+                "1512085950.000500",
+                "1512085950.000100",
+            ],
+            timestamps
+        );
+        assert_eq!(
+            vec![None, Some("1512085950.000700".to_owned()), Some("1512085950.000500".to_owned())],
+            latest
+        );
+    }
+
+    #[test]
+    fn test_history_messages_step_past_pages_of_repeats() {
+        let mut latest = vec![];
+        let timestamps = {
+            let request = channels::HistoryRequest::builder("C1H9RESGL")
+                .inclusive(true)
+                .count(1)
+                .build();
+            let messages = history_messages(&(), "xoxp-token", request, |_: &(), _: &str, request: &channels::HistoryRequest| {
+                latest.push(request.latest.as_ref().map(|latest| latest.to_string()));
+                Ok::<_, ()>(match request.latest.as_ref().map(|latest| &latest[..]) {
+                    None | Some("1512085950.000900") => history_page(&["1512085950.000900"], true),
+                    Some("1512085950.000899") | Some("1512085950.000800") => {
+                        history_page(&["1512085950.000800"], true)
+                    }
+                    _ => history_page(&["1512085950.000100"], false),
+                })
+            });
+            messages.map(|m| m.unwrap().ts().unwrap().to_owned()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["1512085950.000900", "1512085950.000800", "1512085950.000100"], timestamps);
+        assert_eq!(
+            vec![
+                None,
+                Some("1512085950.000900".to_owned()),
+                Some("1512085950.000899".to_owned()),
+                Some("1512085950.000800".to_owned()),
+                Some("1512085950.000799".to_owned()),
+            ],
+            latest
+        );
+    }
+
+    #[test]
+    fn test_history_messages_stop_after_an_error() {
+        let request = channels::HistoryRequest::builder("C1H9RESGL").build();
+        let messages = history_messages(&(), "xoxp-token", request, |_: &(), _: &str, request: &channels::HistoryRequest| {
+            match request.latest {
+                None => Ok(history_page(&["1512085950.000900"], true)),
+                Some(_) => Err("ratelimited"),
+            }
+        }).collect::<Vec<_>>();
+
+        assert_eq!(2, messages.len());
+        assert_eq!(Some(&"ratelimited"), messages[1].as_ref().err());
+    }
+
+    #[test]
+    fn test_ts_before() {
+        assert_eq!("1512085950.000099", ts_before("1512085950.000100"));
+        assert_eq!("1512085949.999999", ts_before("1512085950.000000"));
+    }
+
+    #[test]
+    fn test_compare_ts() {
+        assert_eq!(Ordering::Less, compare_ts("999999999.999999", "1512085950.000100"));
+        assert_eq!(Ordering::Equal, compare_ts("1512085950.000100", "1512085950.000100"));
+        assert_eq!(Ordering::Greater, compare_ts("1512085950.000101", "1512085950.000100"));
+    }
+}
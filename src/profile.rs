@@ -0,0 +1,145 @@
+//! Changes to a user's profile, passed to `users_profile::set`.
+//!
+//! Only the fields that are set are sent to Slack. Set a field to an empty string to clear it.
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_api::profile::ProfileUpdate;
+//!
+//! let profile = ProfileUpdate::new()
+//!     .status("Riding a train", ":mountain_railway:")
+//!     .status_expiration(1532627506)
+//!     .field("Xf06054BBB", "Platform 9¾");
+//! ```
+
+use std::collections::HashMap;
+
+/// The profile fields to change for a user.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProfileUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_expiration: Option<i64>,
+    /// Custom profile fields, keyed by the field IDs returned from `team_profile::get`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub fields: HashMap<String, ProfileFieldUpdate>,
+}
+
+impl ProfileUpdate {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn first_name<S: Into<String>>(mut self, first_name: S) -> Self {
+        self.first_name = Some(first_name.into());
+        self
+    }
+
+    pub fn last_name<S: Into<String>>(mut self, last_name: S) -> Self {
+        self.last_name = Some(last_name.into());
+        self
+    }
+
+    pub fn display_name<S: Into<String>>(mut self, display_name: S) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    pub fn email<S: Into<String>>(mut self, email: S) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn phone<S: Into<String>>(mut self, phone: S) -> Self {
+        self.phone = Some(phone.into());
+        self
+    }
+
+    /// Sets the status text and emoji together. Pass empty strings to clear the status.
+    pub fn status<T: Into<String>, E: Into<String>>(mut self, text: T, emoji: E) -> Self {
+        self.status_text = Some(text.into());
+        self.status_emoji = Some(emoji.into());
+        self
+    }
+
+    /// Sets when the status is cleared, in seconds since the Unix epoch. `0` means never.
+    pub fn status_expiration(mut self, status_expiration: i64) -> Self {
+        self.status_expiration = Some(status_expiration);
+        self
+    }
+
+    /// Sets the value of the custom profile field with the given ID.
+    pub fn field<I: Into<String>, V: Into<String>>(mut self, id: I, value: V) -> Self {
+        self.fields.insert(id.into(), ProfileFieldUpdate::new(value));
+        self
+    }
+}
+
+/// The new value of a custom profile field.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProfileFieldUpdate {
+    pub value: String,
+    /// Text shown instead of the value, e.g. a name for a link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+}
+
+impl ProfileFieldUpdate {
+    pub fn new<S: Into<String>>(value: S) -> Self {
+        ProfileFieldUpdate {
+            value: value.into(),
+            alt: None,
+        }
+    }
+
+    pub fn alt<S: Into<String>>(mut self, alt: S) -> Self {
+        self.alt = Some(alt.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+
+    #[test]
+    fn test_profile_update_only_sends_set_fields() {
+        let mut profile = ProfileUpdate::new().status("", "").display_name("spengler");
+        profile.fields.insert(
+            "Xf06054BBB".into(),
+            ProfileFieldUpdate::new("https://example.com").alt("Homepage"),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&profile).unwrap(),
+            json!({
+                "display_name": "spengler",
+                "status_text": "",
+                "status_emoji": "",
+                "fields": {"Xf06054BBB": {"value": "https://example.com", "alt": "Homepage"}}
+            })
+        );
+    }
+}
@@ -0,0 +1,465 @@
+//! Rotating access tokens for apps with [token rotation](https://api.slack.com/authentication/rotation)
+//! turned on.
+//!
+//! Such apps get access tokens that expire after a few hours, along with a refresh token that
+//! `oauth.v2.access` exchanges for a new pair. A `TokenProvider` hands out the current access
+//! token, refreshing it when it is about to expire and saving the new pair through a `TokenStore`.
+//! Wrapping a client in a `RotatingClient` has the provider consulted before every call.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn run<R, S>(client: R, store: S)
+//! # where R: slack_api::requests::SlackWebRequestSender, S: slack_api::rotation::TokenStore {
+//! use slack_api::auth;
+//! use slack_api::rotation::{RotatingClient, TokenProvider};
+//!
+//! let provider = TokenProvider::new("4b39e9-752c4", "33fea0113f5b1", store);
+//! let client = RotatingClient::new(client, provider);
+//!
+//! // The token passed here is replaced by the provider's, so it can be left empty.
+//! let response = auth::test(&client, "").unwrap();
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde_json;
+
+use oauth_v2::{self, GrantType};
+use requests::SlackWebRequestSender;
+
+/// How long before they expire tokens are refreshed by default.
+const DEFAULT_REFRESH_MARGIN: u64 = 5 * 60;
+
+/// An access token and the refresh token to replace it with.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tokens {
+    pub access_token: String,
+    pub refresh_token: String,
+    /// When the access token expires, in seconds since the Unix epoch.
+    pub expires_at: u64,
+}
+
+impl Tokens {
+    /// Takes the tokens from an `oauth.v2.access` response, whether it exchanged a code or a
+    /// refresh token.
+    ///
+    /// Returns `None` if the response is missing the access token, the refresh token or the
+    /// expiry, as it is for apps without token rotation.
+    pub fn from_response(response: &oauth_v2::AccessResponse) -> Option<Tokens> {
+        let access_token = response.access_token.as_ref();
+        let refresh_token = response.refresh_token.as_ref();
+        match (access_token, refresh_token, response.expires_in) {
+            (Some(access_token), Some(refresh_token), Some(expires_in)) => Some(Tokens {
+                access_token: access_token.clone(),
+                refresh_token: refresh_token.clone(),
+                expires_at: now() + expires_in.max(0) as u64,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Whether the access token has expired or will within `margin`.
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        now() + margin.as_secs() >= self.expires_at
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Where the tokens of a `TokenProvider` are kept between runs.
+///
+/// A refresh token can only be used once, so every new pair must be saved as soon as it arrives.
+/// If saving fails, the provider keeps using the new pair from memory and returns the error so
+/// that it can be reported.
+pub trait TokenStore {
+    type Error: Error;
+
+    /// Loads the tokens last saved, if there are any.
+    fn load(&self) -> Result<Option<Tokens>, Self::Error>;
+
+    /// Saves tokens that have just been refreshed.
+    fn save(&self, tokens: &Tokens) -> Result<(), Self::Error>;
+}
+
+/// Hands out an access token, refreshing it when it is about to expire.
+///
+/// The tokens are loaded from the store on first use and kept in memory after that. Only one
+/// refresh happens at a time; calls that need a token meanwhile wait for it.
+pub struct TokenProvider<S> {
+    client_id: String,
+    client_secret: String,
+    store: S,
+    refresh_margin: Duration,
+    tokens: Mutex<Option<Tokens>>,
+}
+
+impl<S: TokenStore> TokenProvider<S> {
+    pub fn new<I: Into<String>, C: Into<String>>(client_id: I, client_secret: C, store: S) -> Self {
+        TokenProvider {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            store,
+            refresh_margin: Duration::from_secs(DEFAULT_REFRESH_MARGIN),
+            tokens: Mutex::new(None),
+        }
+    }
+
+    /// Sets how long before they expire tokens are refreshed. Defaults to five minutes.
+    pub fn refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns an access token that is not about to expire, refreshing it first if needed.
+    pub fn access_token<R>(&self, client: &R) -> Result<String, TokenError<R::Error, S::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        self.current(client, None)
+    }
+
+    /// Refreshes `expired_token` whether or not it is about to expire, e.g. after Slack responded
+    /// with `token_expired`.
+    ///
+    /// If another call has replaced it already, the current access token is returned instead, so
+    /// that calls which failed with the same token only refresh it once.
+    pub fn refresh<R>(
+        &self,
+        client: &R,
+        expired_token: &str,
+    ) -> Result<String, TokenError<R::Error, S::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        self.current(client, Some(expired_token))
+    }
+
+    fn current<R>(
+        &self,
+        client: &R,
+        expired_token: Option<&str>,
+    ) -> Result<String, TokenError<R::Error, S::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        let mut cached = self.lock();
+        if cached.is_none() {
+            *cached = self.store.load().map_err(TokenError::Store)?;
+        }
+        let tokens = match *cached {
+            None => return Err(TokenError::NoTokens),
+            Some(ref tokens) => {
+                let stale = match expired_token {
+                    Some(expired_token) => tokens.access_token == expired_token,
+                    None => tokens.expires_within(self.refresh_margin),
+                };
+                if !stale {
+                    return Ok(tokens.access_token.clone());
+                }
+                self.exchange(client, &tokens.refresh_token)?
+            }
+        };
+        let access_token = tokens.access_token.clone();
+        // the old refresh token is spent, so the new pair is kept even if saving it fails
+        let saved = self.store.save(&tokens);
+        *cached = Some(tokens);
+        saved.map_err(TokenError::Store)?;
+        Ok(access_token)
+    }
+
+    fn exchange<R>(
+        &self,
+        client: &R,
+        refresh_token: &str,
+    ) -> Result<Tokens, TokenError<R::Error, S::Error>>
+    where
+        R: SlackWebRequestSender,
+    {
+        let request =
+            oauth_v2::AccessRequest::builder(&self.client_id[..], &self.client_secret[..])
+                .grant_type(GrantType::RefreshToken)
+                .refresh_token(refresh_token)
+                .build();
+        let response = oauth_v2::access(client, &request).map_err(TokenError::Refresh)?;
+        Tokens::from_response(&response).ok_or(TokenError::IncompleteResponse)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<Tokens>> {
+        // the cached tokens are only replaced whole, so they are never left half updated
+        self.tokens.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A client that sends every call with the access token of a `TokenProvider`.
+///
+/// The `token` param of each call is replaced, so the token given to the API functions is
+/// ignored. If Slack still responds with `token_expired`, the token is refreshed and the call
+/// sent once more.
+pub struct RotatingClient<R, S> {
+    client: R,
+    provider: TokenProvider<S>,
+}
+
+impl<R, S> RotatingClient<R, S>
+where
+    R: SlackWebRequestSender,
+    S: TokenStore,
+{
+    pub fn new(client: R, provider: TokenProvider<S>) -> Self {
+        RotatingClient {
+            client,
+            provider,
+        }
+    }
+
+    /// The wrapped client, which sends calls as they are.
+    pub fn client(&self) -> &R {
+        &self.client
+    }
+
+    pub fn provider(&self) -> &TokenProvider<S> {
+        &self.provider
+    }
+
+    fn send_with_token(
+        &self,
+        method: &str,
+        params: &[(&str, &str)],
+        token: &str,
+    ) -> Result<String, TokenError<R::Error, S::Error>> {
+        let params = params
+            .iter()
+            .map(|&(k, v)| if k == "token" { (k, token) } else { (k, v) })
+            .collect::<Vec<_>>();
+        self.client.send(method, &params[..]).map_err(TokenError::Client)
+    }
+}
+
+impl<R, S> SlackWebRequestSender for RotatingClient<R, S>
+where
+    R: SlackWebRequestSender,
+    S: TokenStore,
+{
+    type Error = TokenError<R::Error, S::Error>;
+
+    fn send(&self, method: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
+        if !params.iter().any(|&(k, _)| k == "token") {
+            return self.client.send(method, params).map_err(TokenError::Client);
+        }
+
+        let token = self.provider.access_token(&self.client)?;
+        let response = self.send_with_token(method, params, &token)?;
+        if !is_token_expired(&response) {
+            return Ok(response);
+        }
+        // the token expired sooner than our clock expected
+        let token = self.provider.refresh(&self.client, &token)?;
+        self.send_with_token(method, params, &token)
+    }
+}
+
+fn is_token_expired(response: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(response)
+        .map(|response| response["error"] == "token_expired")
+        .unwrap_or(false)
+}
+
+#[derive(Debug)]
+pub enum TokenError<E: Error, S: Error> {
+    /// The store has no tokens, so the app has to be installed first.
+    NoTokens,
+    /// Exchanging the refresh token failed.
+    Refresh(oauth_v2::AccessError<E>),
+    /// The refresh response is missing the access token, the refresh token or the expiry.
+    IncompleteResponse,
+    /// Loading or saving the tokens failed.
+    Store(S),
+    /// Sending the call failed.
+    Client(E),
+}
+
+impl<E: Error, S: Error> fmt::Display for TokenError<E, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error, S: Error> Error for TokenError<E, S> {
+    fn description(&self) -> &str {
+        match *self {
+            TokenError::NoTokens => "no_tokens: The store has no tokens to use or refresh.",
+            TokenError::Refresh(ref inner) => inner.description(),
+            TokenError::IncompleteResponse => {
+                "incomplete_response: The refresh response is missing the access token, the refresh \
+                 token or the expiry."
+            }
+            TokenError::Store(ref inner) => inner.description(),
+            TokenError::Client(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            TokenError::Refresh(ref inner) => Some(inner),
+            TokenError::Store(ref inner) => Some(inner),
+            TokenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io;
+
+    use super::*;
+    use auth;
+    use mock::MockSender;
+
+    #[test]
+    fn test_tokens_expire_within_margin() {
+        let tokens = Tokens {
+            access_token: "xoxe.xoxb-1".into(),
+            refresh_token: "xoxe-1".into(),
+            expires_at: now() + 60,
+        };
+        assert!(!tokens.expires_within(Duration::from_secs(30)));
+        assert!(tokens.expires_within(Duration::from_secs(60)));
+    }
+
+    struct MemoryStore(RefCell<Option<Tokens>>);
+
+    impl TokenStore for MemoryStore {
+        type Error = io::Error;
+
+        fn load(&self) -> Result<Option<Tokens>, io::Error> {
+            Ok(self.0.borrow().clone())
+        }
+
+        fn save(&self, tokens: &Tokens) -> Result<(), io::Error> {
+            *self.0.borrow_mut() = Some(tokens.clone());
+            Ok(())
+        }
+    }
+
+    /// Loads the tokens it was made with, but fails to save new ones.
+    struct ReadOnlyStore(Tokens);
+
+    impl TokenStore for ReadOnlyStore {
+        type Error = io::Error;
+
+        fn load(&self) -> Result<Option<Tokens>, io::Error> {
+            Ok(Some(self.0.clone()))
+        }
+
+        fn save(&self, _: &Tokens) -> Result<(), io::Error> {
+            Err(io::Error::new(io::ErrorKind::PermissionDenied, "read-only store"))
+        }
+    }
+
+    fn old_tokens(expires_at: u64) -> Tokens {
+        Tokens {
+            access_token: "xoxe.xoxb-old".into(),
+            refresh_token: "xoxe-1-old".into(),
+            expires_at,
+        }
+    }
+
+    fn rotating_client(
+        client: MockSender,
+        expires_at: u64,
+    ) -> RotatingClient<MockSender, MemoryStore> {
+        let store = MemoryStore(RefCell::new(Some(old_tokens(expires_at))));
+        RotatingClient::new(client, TokenProvider::new("4b39e9-752c4", "33fea0113f5b1", store))
+    }
+
+    const REFRESH_RESPONSE: &'static str = r#"{
+        "ok": true,
+        "access_token": "xoxe.xoxb-new",
+        "refresh_token": "xoxe-1-new",
+        "expires_in": 43200,
+        "token_type": "bot"
+    }"#;
+
+    #[test]
+    fn test_rotating_client_refreshes_expiring_token() {
+        let client = rotating_client(
+            MockSender::new(REFRESH_RESPONSE).then(r#"{"ok": true, "user_id": "U1234"}"#),
+            0,
+        );
+
+        auth::test(&client, "").unwrap();
+        auth::test(&client, "").unwrap();
+
+        let urls = client.client().urls.borrow();
+        assert_eq!(3, urls.len());
+        assert!(urls[0].ends_with("/oauth.v2.access"));
+        assert!(urls[1].ends_with("/auth.test"));
+        let refresh_params = &client.client().params.borrow()[0];
+        assert!(refresh_params.contains(&("grant_type".into(), "refresh_token".into())));
+        assert!(refresh_params.contains(&("refresh_token".into(), "xoxe-1-old".into())));
+        assert!(!refresh_params.iter().any(|param| param.0 == "token"));
+        assert_eq!(Some("xoxe.xoxb-new".to_owned()), client.client().sent_param("token"));
+
+        let saved = client.provider().store().0.borrow().clone().unwrap();
+        assert_eq!("xoxe-1-new", saved.refresh_token);
+        assert!(!saved.expires_within(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_rotating_client_retries_expired_token() {
+        let client = rotating_client(
+            MockSender::new(r#"{"ok": false, "error": "token_expired"}"#)
+                .then(REFRESH_RESPONSE)
+                .then(r#"{"ok": true, "user_id": "U1234"}"#),
+            now() + 60 * 60,
+        );
+
+        let response = auth::test(&client, "").unwrap();
+        assert_eq!(Some("U1234"), response.user_id.as_ref().map(String::as_ref));
+
+        let params = client.client().params.borrow();
+        assert!(params[0].contains(&("token".into(), "xoxe.xoxb-old".into())));
+        assert!(params[2].contains(&("token".into(), "xoxe.xoxb-new".into())));
+    }
+
+    #[test]
+    fn test_new_tokens_are_kept_when_saving_fails() {
+        let client = MockSender::new(REFRESH_RESPONSE);
+        let store = ReadOnlyStore(old_tokens(0));
+        let provider = TokenProvider::new("4b39e9-752c4", "33fea0113f5b1", store);
+
+        match provider.access_token(&client) {
+            Err(TokenError::Store(_)) => {}
+            other => panic!("expected a store error, got {:?}", other),
+        }
+        assert_eq!("xoxe.xoxb-new", provider.access_token(&client).unwrap());
+        assert_eq!(1, client.urls.borrow().len());
+    }
+
+    #[test]
+    fn test_expired_token_is_refreshed_once() {
+        let client = MockSender::new(REFRESH_RESPONSE);
+        let store = MemoryStore(RefCell::new(Some(old_tokens(now() + 60 * 60))));
+        let provider = TokenProvider::new("4b39e9-752c4", "33fea0113f5b1", store);
+
+        assert_eq!("xoxe.xoxb-new", provider.refresh(&client, "xoxe.xoxb-old").unwrap());
+        assert_eq!("xoxe.xoxb-new", provider.refresh(&client, "xoxe.xoxb-old").unwrap());
+        assert_eq!(1, client.urls.borrow().len());
+    }
+}
@@ -0,0 +1,292 @@
+//! Reading a whole message thread, whatever kind of conversation it is in.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn run<R: slack_api::requests::SlackWebRequestSender>(client: &R, token: &str) {
+//! use slack_api::thread;
+//!
+//! let thread = thread::get(client, token, "C1H9RESGL", "1482960137.003543").unwrap();
+//! println!("{} replies from {:?}", thread.replies.len(), thread.reply_users);
+//! # }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use channels;
+use conversations;
+use dispatch::ConversationKind;
+use groups;
+use im;
+use pagination::cursor_pages;
+use requests::SlackWebRequestSender;
+use types::Message;
+
+/// A thread of messages: the message that started it and the replies to it.
+#[derive(Clone, Debug)]
+pub struct Thread {
+    /// The message the thread hangs off, if Slack returned it.
+    pub parent: Option<Message>,
+    /// The replies, oldest first.
+    pub replies: Vec<Message>,
+    /// The IDs of the users who replied, in the order of their first reply.
+    pub reply_users: Vec<String>,
+}
+
+impl Thread {
+    /// Splits the messages of a thread, as Slack returns them, into the parent and the replies.
+    pub fn from_messages(thread_ts: &str, messages: Vec<Message>) -> Thread {
+        let (parents, replies): (Vec<_>, Vec<_>) = messages
+            .into_iter()
+            .partition(|m| m.ts() == Some(thread_ts));
+        let mut reply_users: Vec<String> = vec![];
+        for user in replies.iter().filter_map(Message::user) {
+            if !reply_users.iter().any(|u| u == user) {
+                reply_users.push(user.to_owned());
+            }
+        }
+        Thread {
+            parent: parents.into_iter().next(),
+            replies,
+            reply_users,
+        }
+    }
+}
+
+/// Fetches the thread started by the message at `thread_ts` in `channel`.
+///
+/// The kind of conversation is worked out from the prefix of the channel ID: `C` for a public
+/// channel, `G` for a private channel or multi-person direct message and `D` for a direct
+/// message. Threads that Slack cannot return in one go are paged through with
+/// `conversations::replies`.
+pub fn get<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    thread_ts: &str,
+) -> Result<Thread, ThreadError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let (messages, thread_info) = match ConversationKind::from_id(channel, false) {
+        Some(ConversationKind::Channel) => {
+            let request = channels::RepliesRequest::builder(channel, thread_ts).build();
+            let response =
+                channels::replies(client, token, &request).map_err(ThreadError::Channels)?;
+            (response.messages, response.thread_info)
+        }
+        Some(ConversationKind::Group) => {
+            let request = groups::RepliesRequest::builder(channel, thread_ts).build();
+            let response = groups::replies(client, token, &request).map_err(ThreadError::Groups)?;
+            (response.messages, response.thread_info)
+        }
+        Some(ConversationKind::Im) => {
+            let request = im::RepliesRequest::builder(channel, thread_ts).build();
+            let response = im::replies(client, token, &request).map_err(ThreadError::Im)?;
+            (response.messages, response.thread_info)
+        }
+        _ => return Err(ThreadError::UnknownChannelKind),
+    };
+
+    if thread_info.and_then(|info| info.complete) == Some(false) {
+        return get_paged(client, token, channel, thread_ts);
+    }
+    Ok(Thread::from_messages(
+        thread_ts,
+        messages.unwrap_or_default(),
+    ))
+}
+
+/// Fetches the thread that `message` is part of, whether it is the parent, a reply or a
+/// `message_replied` event.
+pub fn get_for_message<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    message: &Message,
+) -> Result<Thread, ThreadError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let thread_ts = match *message {
+        // the event only carries the thread's ts on the reply it wraps
+        Message::MessageReplied(ref replied) => replied
+            .message
+            .as_ref()
+            .and_then(|reply| reply.thread_ts.as_ref())
+            .map(String::as_ref),
+        _ => message.thread_ts(),
+    };
+    match thread_ts {
+        Some(thread_ts) => get(client, token, channel, thread_ts),
+        None => Err(ThreadError::NotInThread),
+    }
+}
+
+fn get_paged<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    thread_ts: &str,
+) -> Result<Thread, ThreadError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let request = conversations::RepliesRequest::builder(channel, thread_ts).build();
+    let mut messages = vec![];
+    for page in cursor_pages(client, token, request, conversations::replies) {
+        let page = page.map_err(ThreadError::Conversations)?;
+        messages.extend(page.messages.unwrap_or_default());
+    }
+    Ok(Thread::from_messages(thread_ts, messages))
+}
+
+#[derive(Debug)]
+pub enum ThreadError<E: Error> {
+    /// The channel ID is not one of a public channel, private channel or direct message.
+    UnknownChannelKind,
+    /// The message is not part of a thread.
+    NotInThread,
+    /// Fetching the thread from a public channel failed.
+    Channels(channels::RepliesError<E>),
+    /// Fetching the thread from a private channel or multi-person direct message failed.
+    Groups(groups::RepliesError<E>),
+    /// Fetching the thread from a direct message failed.
+    Im(im::RepliesError<E>),
+    /// Paging through a long thread failed.
+    Conversations(conversations::RepliesError<E>),
+}
+
+impl<E: Error> fmt::Display for ThreadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl<E: Error> Error for ThreadError<E> {
+    fn description(&self) -> &str {
+        match *self {
+            ThreadError::UnknownChannelKind => {
+                "unknown_channel_kind: The channel ID does not start with C, G or D."
+            }
+            ThreadError::NotInThread => "not_in_thread: The message is not part of a thread.",
+            ThreadError::Channels(ref inner) => inner.description(),
+            ThreadError::Groups(ref inner) => inner.description(),
+            ThreadError::Im(ref inner) => inner.description(),
+            ThreadError::Conversations(ref inner) => inner.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&Error> {
+        match *self {
+            ThreadError::Channels(ref inner) => Some(inner),
+            ThreadError::Groups(ref inner) => Some(inner),
+            ThreadError::Im(ref inner) => Some(inner),
+            ThreadError::Conversations(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use super::*;
+    use mock::MockSender;
+
+    #[test]
+    fn test_thread_is_read_from_the_kind_of_channel() {
+        let client = MockSender::new(r#"{
+            "ok": true,
+            "messages": [
+                {"type": "message", "user": "U061F7AUR", "text": "island", "thread_ts": "1482960137.003543", "reply_count": 3, "ts": "1482960137.003543"},
+                {"type": "message", "user": "U061F7AUR", "text": "one island", "thread_ts": "1482960137.003543", "parent_user_id": "U061F7AUR", "ts": "1483037603.017503"},
+                {"type": "message", "user": "U0JD3BPNC", "text": "two island", "thread_ts": "1482960137.003543", "parent_user_id": "U061F7AUR", "ts": "1483051909.018632"},
+                {"type": "message", "user": "U061F7AUR", "text": "three for the land", "thread_ts": "1482960137.003543", "parent_user_id": "U061F7AUR", "ts": "1483125339.020269"}
+            ],
+            "thread_info": {"complete": true, "count": 3}
+        }"#);
+
+        let thread = get(&client, "xoxp-token", "G0AKFJBEU", "1482960137.003543").unwrap();
+        assert!(client.urls.borrow()[0].ends_with("/groups.replies"));
+        assert_eq!(Some("island"), thread.parent.as_ref().and_then(Message::text));
+        assert_eq!(3, thread.replies.len());
+        assert_eq!(vec!["U061F7AUR", "U0JD3BPNC"], thread.reply_users);
+
+        let reply = thread.replies[1].clone();
+        get_for_message(&client, "xoxp-token", "D024BE91L", &reply).unwrap();
+        assert!(client.urls.borrow()[1].ends_with("/im.replies"));
+        assert_eq!(Some("1482960137.003543".to_owned()), client.sent_param("thread_ts"));
+
+        let replied: Message = serde_json::from_str(r#"{
+            "type": "message",
+            "message": {
+                "type": "message",
+                "user": "U061F7AUR",
+                "text": "island",
+                "thread_ts": "1482960137.003543",
+                "reply_count": 1,
+                "replies": [{"user": "U061F7AUR", "ts": "1483037603.017503"}],
+                "ts": "1482960137.003543"
+            },
+            "subtype": "message_replied",
+            "hidden": true,
+            "channel": "C061EG9SL",
+            "event_ts": "1483037604.017506",
+            "ts": "1483037604.017506"
+        }"#).unwrap();
+        get_for_message(&client, "xoxp-token", "C061EG9SL", &replied).unwrap();
+        assert!(client.urls.borrow()[2].ends_with("/channels.replies"));
+        assert_eq!(Some("1482960137.003543".to_owned()), client.sent_param("thread_ts"));
+
+        let bot_reply: Message = serde_json::from_str(r#"{
+            "type": "message",
+            "subtype": "bot_message",
+            "bot_id": "B1",
+            "text": "beep",
+            "thread_ts": "1482960137.003543",
+            "ts": "1483037605.017507"
+        }"#).unwrap();
+        get_for_message(&client, "xoxp-token", "C061EG9SL", &bot_reply).unwrap();
+        assert_eq!(Some("1482960137.003543".to_owned()), client.sent_param("thread_ts"));
+
+        match get(&client, "xoxp-token", "U061F7AUR", "1482960137.003543") {
+            Err(ThreadError::UnknownChannelKind) => {}
+            other => panic!("expected UnknownChannelKind, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_incomplete_thread_is_paged_through() {
+        let client = MockSender::new(r#"{
+            "ok": true,
+            "messages": [{"type": "message", "user": "U1", "text": "parent", "ts": "1.000001"}],
+            "thread_info": {"complete": false, "count": 3}
+        }"#).then(r#"{
+            "ok": true,
+            "messages": [
+                {"type": "message", "user": "U1", "text": "parent", "thread_ts": "1.000001", "ts": "1.000001"},
+                {"type": "message", "user": "U2", "text": "first", "thread_ts": "1.000001", "ts": "2.000001"}
+            ],
+            "has_more": true,
+            "response_metadata": {"next_cursor": "bmV4dF90czoy"}
+        }"#).then(r#"{
+            "ok": true,
+            "messages": [
+                {"type": "message", "user": "U3", "text": "second", "thread_ts": "1.000001", "ts": "3.000001"}
+            ],
+            "has_more": false,
+            "response_metadata": {"next_cursor": ""}
+        }"#);
+
+        let thread = get(&client, "xoxp-token", "C1H9RESGL", "1.000001").unwrap();
+        let urls = client.urls.borrow();
+        assert!(urls[0].ends_with("/channels.replies"));
+        assert!(urls[1].ends_with("/conversations.replies"));
+        assert_eq!(3, urls.len());
+        assert_eq!(Some("bmV4dF90czoy".to_owned()), client.sent_param("cursor"));
+        assert_eq!(Some("parent"), thread.parent.as_ref().and_then(Message::text));
+        assert_eq!(vec!["U2", "U3"], thread.reply_users);
+    }
+}
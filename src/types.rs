@@ -7,6 +7,8 @@ pub struct Bot {
     pub icons: Option<BotIcons>,
     pub id: Option<String>,
     pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -14,6 +16,8 @@ pub struct BotIcons {
     pub image_36: Option<String>,
     pub image_48: Option<String>,
     pub image_72: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -23,16 +27,25 @@ pub struct Channel {
     pub id: Option<String>,
     pub is_archived: Option<bool>,
     pub is_channel: Option<bool>,
+    pub is_ext_shared: Option<bool>,
     pub is_general: Option<bool>,
     pub is_member: Option<bool>,
+    pub is_org_shared: Option<bool>,
+    pub is_private: Option<bool>,
+    pub is_shared: Option<bool>,
     pub last_read: Option<String>,
     pub latest: Option<::Message>,
     pub members: Option<Vec<String>>,
     pub name: Option<String>,
+    pub num_members: Option<i32>,
+    pub previous_names: Option<Vec<String>>,
     pub purpose: Option<ChannelPurpose>,
+    pub shared_team_ids: Option<Vec<String>>,
     pub topic: Option<ChannelTopic>,
     pub unread_count: Option<i32>,
     pub unread_count_display: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -40,6 +53,8 @@ pub struct ChannelPurpose {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -48,6 +63,61 @@ pub struct ChannelTopic {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Conversation {
+    pub created: Option<i32>,
+    pub creator: Option<String>,
+    pub id: Option<String>,
+    pub is_archived: Option<bool>,
+    pub is_channel: Option<bool>,
+    pub is_ext_shared: Option<bool>,
+    pub is_general: Option<bool>,
+    pub is_group: Option<bool>,
+    pub is_im: Option<bool>,
+    pub is_member: Option<bool>,
+    pub is_mpim: Option<bool>,
+    pub is_open: Option<bool>,
+    pub is_org_shared: Option<bool>,
+    pub is_private: Option<bool>,
+    pub is_shared: Option<bool>,
+    pub is_user_deleted: Option<bool>,
+    pub last_read: Option<String>,
+    pub latest: Option<::Message>,
+    pub locale: Option<String>,
+    pub name: Option<String>,
+    pub name_normalized: Option<String>,
+    pub num_members: Option<i32>,
+    pub previous_names: Option<Vec<String>>,
+    pub purpose: Option<ConversationPurpose>,
+    pub shared_team_ids: Option<Vec<String>>,
+    pub topic: Option<ConversationTopic>,
+    pub unread_count: Option<i32>,
+    pub unread_count_display: Option<i32>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConversationPurpose {
+    pub creator: Option<String>,
+    pub last_set: Option<i32>,
+    pub value: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConversationTopic {
+    pub creator: Option<String>,
+    pub last_set: Option<i32>,
+    pub value: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -58,9 +128,12 @@ pub struct File {
     pub display_as_bot: Option<bool>,
     pub edit_link: Option<String>,
     pub editable: Option<bool>,
+    pub external_id: Option<String>,
     pub external_type: Option<String>,
+    pub external_url: Option<String>,
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
+    pub has_rich_preview: Option<bool>,
     pub id: Option<String>,
     pub ims: Option<Vec<String>>,
     pub initial_comment: Option<::FileComment>,
@@ -98,6 +171,8 @@ pub struct File {
     pub url_private_download: Option<String>,
     pub user: Option<String>,
     pub username: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -107,6 +182,8 @@ pub struct FileComment {
     pub reactions: Option<Vec<::Reaction>>,
     pub timestamp: Option<i32>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -125,6 +202,8 @@ pub struct Group {
     pub topic: Option<GroupTopic>,
     pub unread_count: Option<i32>,
     pub unread_count_display: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -132,6 +211,8 @@ pub struct GroupPurpose {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -140,6 +221,8 @@ pub struct GroupTopic {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -149,6 +232,148 @@ pub struct Im {
     pub is_im: Option<bool>,
     pub is_user_deleted: Option<bool>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug)]
+pub enum Item {
+    Message(ItemMessage),
+    File(ItemFile),
+    FileComment(ItemFileComment),
+    Channel(ItemChannel),
+    Im(ItemIm),
+    Group(ItemGroup),
+    /// A type not modelled by this library, kept as raw JSON.
+    Unknown(::serde_json::Value),
+}
+
+impl<'de> ::serde::Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use serde::de::Error as SerdeError;
+
+        let value = ::serde_json::Value::deserialize(deserializer)?;
+        if let Some(ty_val) = value.get("type") {
+            if let Some(ty) = ty_val.as_str() {
+                match ty {
+                    "message" => {
+                        ::serde_json::from_value::<ItemMessage>(value.clone())
+                            .map(Item::Message)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "file" => {
+                        ::serde_json::from_value::<ItemFile>(value.clone())
+                            .map(Item::File)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "file_comment" => {
+                        ::serde_json::from_value::<ItemFileComment>(value.clone())
+                            .map(Item::FileComment)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "channel" => {
+                        ::serde_json::from_value::<ItemChannel>(value.clone())
+                            .map(Item::Channel)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "im" => {
+                        ::serde_json::from_value::<ItemIm>(value.clone())
+                            .map(Item::Im)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "group" => {
+                        ::serde_json::from_value::<ItemGroup>(value.clone())
+                            .map(Item::Group)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    _ => Ok(Item::Unknown(value.clone())),
+                }
+            } else {
+                Err(D::Error::invalid_type(
+                    ::serde::de::Unexpected::Unit,
+                    &"a string",
+                ))
+            }
+        } else {
+            Err(D::Error::missing_field("type"))
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ItemChannel {
+    pub channel: String,
+    pub created: Option<f32>,
+    pub created_by: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ItemFile {
+    pub created: Option<f32>,
+    pub created_by: Option<String>,
+    pub file: ::File,
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ItemFileComment {
+    pub comment: ::FileComment,
+    pub created: Option<f32>,
+    pub created_by: Option<String>,
+    pub file: ::File,
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ItemGroup {
+    pub created: Option<f32>,
+    pub created_by: Option<String>,
+    pub group: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ItemIm {
+    pub channel: String,
+    pub created: Option<f32>,
+    pub created_by: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ItemMessage {
+    pub channel: String,
+    pub created: Option<f32>,
+    pub created_by: Option<String>,
+    pub message: ::Message,
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug)]
@@ -179,6 +404,17 @@ pub enum Message {
     PinnedItem(MessagePinnedItem),
     ReplyBroadcast(MessageReplyBroadcast),
     UnpinnedItem(MessageUnpinnedItem),
+    BotAdd(MessageBotAdd),
+    BotRemove(MessageBotRemove),
+    ChannelConvertToPrivate(MessageChannelConvertToPrivate),
+    ChannelPostingPermissions(MessageChannelPostingPermissions),
+    EkmAccessDenied(MessageEkmAccessDenied),
+    FileUpload(MessageFileUpload),
+    ReminderAdd(MessageReminderAdd),
+    ThreadBroadcast(MessageThreadBroadcast),
+    Tombstone(MessageTombstone),
+    /// A subtype not modelled by this library, kept as raw JSON.
+    Unknown(::serde_json::Value),
 }
 
 impl<'de> ::serde::Deserialize<'de> for Message {
@@ -188,35 +424,6 @@ impl<'de> ::serde::Deserialize<'de> for Message {
     {
         use serde::de::Error as SerdeError;
 
-        const VARIANTS: &'static [&'static str] = &[
-            "standard",
-            "bot_message",
-            "channel_archive",
-            "channel_join",
-            "channel_leave",
-            "channel_name",
-            "channel_purpose",
-            "channel_topic",
-            "channel_unarchive",
-            "file_comment",
-            "file_mention",
-            "file_share",
-            "group_archive",
-            "group_join",
-            "group_leave",
-            "group_name",
-            "group_purpose",
-            "group_topic",
-            "group_unarchive",
-            "me_message",
-            "message_changed",
-            "message_deleted",
-            "message_replied",
-            "pinned_item",
-            "reply_broadcast",
-            "unpinned_item",
-        ];
-
         let value = ::serde_json::Value::deserialize(deserializer)?;
         if let Some(ty_val) = value.get("subtype") {
             if let Some(ty) = ty_val.as_str() {
@@ -351,7 +558,52 @@ impl<'de> ::serde::Deserialize<'de> for Message {
                             .map(Message::UnpinnedItem)
                             .map_err(|e| D::Error::custom(&format!("{}", e)))
                     }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
+                    "bot_add" => {
+                        ::serde_json::from_value::<MessageBotAdd>(value.clone())
+                            .map(Message::BotAdd)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "bot_remove" => {
+                        ::serde_json::from_value::<MessageBotRemove>(value.clone())
+                            .map(Message::BotRemove)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "channel_convert_to_private" => {
+                        ::serde_json::from_value::<MessageChannelConvertToPrivate>(value.clone())
+                            .map(Message::ChannelConvertToPrivate)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "channel_posting_permissions" => {
+                        ::serde_json::from_value::<MessageChannelPostingPermissions>(value.clone())
+                            .map(Message::ChannelPostingPermissions)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "ekm_access_denied" => {
+                        ::serde_json::from_value::<MessageEkmAccessDenied>(value.clone())
+                            .map(Message::EkmAccessDenied)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "file_upload" => {
+                        ::serde_json::from_value::<MessageFileUpload>(value.clone())
+                            .map(Message::FileUpload)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "reminder_add" => {
+                        ::serde_json::from_value::<MessageReminderAdd>(value.clone())
+                            .map(Message::ReminderAdd)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "thread_broadcast" => {
+                        ::serde_json::from_value::<MessageThreadBroadcast>(value.clone())
+                            .map(Message::ThreadBroadcast)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    "tombstone" => {
+                        ::serde_json::from_value::<MessageTombstone>(value.clone())
+                            .map(Message::Tombstone)
+                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                    }
+                    _ => Ok(Message::Unknown(value.clone())),
                 }
             } else {
                 Err(D::Error::invalid_type(
@@ -367,16 +619,36 @@ impl<'de> ::serde::Deserialize<'de> for Message {
     }
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageBotAdd {
+    pub bot_id: Option<String>,
+    pub bot_link: Option<String>,
+    pub channel: Option<String>,
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageBotMessage {
+    pub blocks: Option<Vec<::blocks::Block>>,
     pub bot_id: Option<String>,
     pub icons: Option<MessageBotMessageIcons>,
     pub subtype: Option<String>,
     pub text: Option<String>,
+    pub thread_ts: Option<String>,
     pub ts: Option<String>,
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub username: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -384,6 +656,24 @@ pub struct MessageBotMessageIcons {
     pub image_36: Option<String>,
     pub image_48: Option<String>,
     pub image_72: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageBotRemove {
+    pub bot_id: Option<String>,
+    pub bot_link: Option<String>,
+    pub channel: Option<String>,
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -396,6 +686,21 @@ pub struct MessageChannelArchive {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageChannelConvertToPrivate {
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -407,6 +712,8 @@ pub struct MessageChannelJoin {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -418,6 +725,8 @@ pub struct MessageChannelLeave {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -431,6 +740,21 @@ pub struct MessageChannelName {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageChannelPostingPermissions {
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -443,6 +767,8 @@ pub struct MessageChannelPurpose {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -455,6 +781,8 @@ pub struct MessageChannelTopic {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -466,6 +794,21 @@ pub struct MessageChannelUnarchive {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageEkmAccessDenied {
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -478,6 +821,8 @@ pub struct MessageFileComment {
     pub ts: Option<String>,
     #[serde(rename = "type")]
     pub ty: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -490,6 +835,8 @@ pub struct MessageFileMention {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -498,11 +845,29 @@ pub struct MessageFileShare {
     pub file: Option<::File>,
     pub subtype: Option<String>,
     pub text: Option<String>,
+    pub thread_ts: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub upload: Option<bool>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageFileUpload {
+    pub files: Option<Vec<::File>>,
+    pub subtype: Option<String>,
+    pub text: Option<String>,
     pub ts: Option<String>,
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub upload: Option<bool>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -515,6 +880,8 @@ pub struct MessageGroupArchive {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -526,6 +893,8 @@ pub struct MessageGroupJoin {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -537,6 +906,8 @@ pub struct MessageGroupLeave {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -550,6 +921,8 @@ pub struct MessageGroupName {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -562,6 +935,8 @@ pub struct MessageGroupPurpose {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -574,6 +949,8 @@ pub struct MessageGroupTopic {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -585,6 +962,8 @@ pub struct MessageGroupUnarchive {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -593,10 +972,13 @@ pub struct MessageMeMessage {
     pub channel: Option<String>,
     pub subtype: Option<String>,
     pub text: Option<String>,
+    pub thread_ts: Option<String>,
     pub ts: Option<String>,
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -611,10 +993,13 @@ pub struct MessageMessageChanged {
     pub ts: Option<String>,
     #[serde(rename = "type")]
     pub ty: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageMessageChangedMessage {
+    pub blocks: Option<Vec<::blocks::Block>>,
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageChangedMessageEdited>,
     pub last_read: Option<String>,
@@ -629,12 +1014,16 @@ pub struct MessageMessageChangedMessage {
     pub ty: Option<String>,
     pub unread_count: Option<i32>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageMessageChangedMessageEdited {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -642,11 +1031,14 @@ pub struct MessageMessageChangedMessageEdited {
 pub struct MessageMessageChangedMessageReply {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageMessageChangedPreviousMessage {
+    pub blocks: Option<Vec<::blocks::Block>>,
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageChangedPreviousMessageEdited>,
     pub last_read: Option<String>,
@@ -661,12 +1053,16 @@ pub struct MessageMessageChangedPreviousMessage {
     pub ty: Option<String>,
     pub unread_count: Option<i32>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageMessageChangedPreviousMessageEdited {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -674,6 +1070,8 @@ pub struct MessageMessageChangedPreviousMessageEdited {
 pub struct MessageMessageChangedPreviousMessageReply {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -688,10 +1086,13 @@ pub struct MessageMessageDeleted {
     pub ts: Option<String>,
     #[serde(rename = "type")]
     pub ty: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageMessageDeletedPreviousMessage {
+    pub blocks: Option<Vec<::blocks::Block>>,
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageDeletedPreviousMessageEdited>,
     pub last_read: Option<String>,
@@ -706,12 +1107,16 @@ pub struct MessageMessageDeletedPreviousMessage {
     pub ty: Option<String>,
     pub unread_count: Option<i32>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageMessageDeletedPreviousMessageEdited {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -719,6 +1124,8 @@ pub struct MessageMessageDeletedPreviousMessageEdited {
 pub struct MessageMessageDeletedPreviousMessageReply {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -733,10 +1140,13 @@ pub struct MessageMessageReplied {
     pub ts: Option<String>,
     #[serde(rename = "type")]
     pub ty: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageMessageRepliedMessage {
+    pub blocks: Option<Vec<::blocks::Block>>,
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageRepliedMessageEdited>,
     pub last_read: Option<String>,
@@ -751,12 +1161,16 @@ pub struct MessageMessageRepliedMessage {
     pub ty: Option<String>,
     pub unread_count: Option<i32>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageMessageRepliedMessageEdited {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -764,6 +1178,8 @@ pub struct MessageMessageRepliedMessageEdited {
 pub struct MessageMessageRepliedMessageReply {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -778,10 +1194,38 @@ pub struct MessagePinnedItem {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct MessagePinnedItemItem {}
+pub struct MessagePinnedItemItem {
+    pub bot_id: Option<String>,
+    pub comment: Option<::FileComment>,
+    pub file: Option<::File>,
+    pub text: Option<String>,
+    pub thread_ts: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    pub username: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageReminderAdd {
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
 
 
 #[derive(Clone, Debug, Deserialize)]
@@ -794,6 +1238,8 @@ pub struct MessageReplyBroadcast {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -810,12 +1256,15 @@ pub struct MessageReplyBroadcastAttachment {
     pub mrkdwn_in: Option<Vec<String>>,
     pub text: Option<String>,
     pub ts: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct MessageStandard {
     pub attachments: Option<Vec<MessageStandardAttachment>>,
+    pub blocks: Option<Vec<::blocks::Block>>,
     pub bot_id: Option<String>,
     pub channel: Option<String>,
     pub edited: Option<MessageStandardEdited>,
@@ -829,6 +1278,8 @@ pub struct MessageStandard {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -848,6 +1299,8 @@ pub struct MessageStandardAttachment {
     pub title: Option<String>,
     pub title_link: Option<String>,
     pub ts: Option<f32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -855,6 +1308,8 @@ pub struct MessageStandardAttachmentField {
     pub short: Option<bool>,
     pub title: Option<String>,
     pub value: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -862,6 +1317,87 @@ pub struct MessageStandardAttachmentField {
 pub struct MessageStandardEdited {
     pub ts: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageThreadBroadcast {
+    pub attachments: Option<Vec<MessageThreadBroadcastAttachment>>,
+    pub blocks: Option<Vec<::blocks::Block>>,
+    pub channel: Option<String>,
+    pub event_ts: Option<String>,
+    pub root: Option<MessageThreadBroadcastRoot>,
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub thread_ts: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageThreadBroadcastAttachment {
+    pub author_icon: Option<String>,
+    pub author_link: Option<String>,
+    pub author_subname: Option<String>,
+    pub channel_id: Option<String>,
+    pub channel_name: Option<String>,
+    pub fallback: Option<String>,
+    pub footer: Option<String>,
+    pub from_url: Option<String>,
+    pub id: Option<i32>,
+    pub mrkdwn_in: Option<Vec<String>>,
+    pub text: Option<String>,
+    pub ts: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageThreadBroadcastRoot {
+    pub bot_id: Option<String>,
+    pub latest_reply: Option<String>,
+    pub reply_count: Option<i32>,
+    pub reply_users: Option<Vec<String>>,
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub thread_ts: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageTombstone {
+    pub hidden: Option<bool>,
+    pub replies: Option<Vec<MessageTombstoneReply>>,
+    pub reply_count: Option<i32>,
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub thread_ts: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageTombstoneReply {
+    pub ts: Option<String>,
+    pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 
@@ -876,10 +1412,25 @@ pub struct MessageUnpinnedItem {
     #[serde(rename = "type")]
     pub ty: Option<String>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct MessageUnpinnedItemItem {}
+pub struct MessageUnpinnedItemItem {
+    pub bot_id: Option<String>,
+    pub comment: Option<::FileComment>,
+    pub file: Option<::File>,
+    pub text: Option<String>,
+    pub thread_ts: Option<String>,
+    pub ts: Option<String>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    pub user: Option<String>,
+    pub username: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Mpim {
@@ -894,6 +1445,8 @@ pub struct Mpim {
     pub name: Option<String>,
     pub unread_count: Option<i32>,
     pub unread_count_display: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -902,6 +1455,8 @@ pub struct Paging {
     pub page: Option<i32>,
     pub pages: Option<i32>,
     pub total: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -909,6 +1464,8 @@ pub struct Reaction {
     pub count: Option<i32>,
     pub name: Option<String>,
     pub users: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -920,15 +1477,28 @@ pub struct Reminder {
     pub text: Option<String>,
     pub time: Option<f32>,
     pub user: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResponseMetadata {
+    pub next_cursor: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Team {
     pub domain: Option<String>,
     pub email_domain: Option<String>,
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
     pub icon: Option<TeamIcon>,
     pub id: Option<String>,
     pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -940,58 +1510,59 @@ pub struct TeamIcon {
     pub image_68: Option<String>,
     pub image_88: Option<String>,
     pub image_default: Option<bool>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct ThreadInfo {
     pub complete: Option<bool>,
     pub count: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct User {
     pub color: Option<String>,
     pub deleted: Option<bool>,
+    pub enterprise_user: Option<UserEnterpriseUser>,
     pub has_2fa: Option<bool>,
     pub id: Option<String>,
     pub is_admin: Option<bool>,
+    pub is_app_user: Option<bool>,
+    pub is_bot: Option<bool>,
     pub is_owner: Option<bool>,
     pub is_primary_owner: Option<bool>,
     pub is_restricted: Option<bool>,
     pub is_ultra_restricted: Option<bool>,
     pub name: Option<String>,
     pub profile: Option<::UserProfile>,
+    pub real_name: Option<String>,
+    pub team_id: Option<String>,
     pub two_factor_type: Option<String>,
+    pub tz: Option<String>,
+    pub tz_offset: Option<i32>,
+    pub updated: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
-pub struct Usergroup {
-    pub auto_type: Option<String>,
-    pub created_by: Option<String>,
-    pub date_create: Option<i32>,
-    pub date_delete: Option<i32>,
-    pub date_update: Option<i32>,
-    pub deleted_by: Option<String>,
-    pub description: Option<String>,
-    pub handle: Option<String>,
+pub struct UserEnterpriseUser {
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
     pub id: Option<String>,
-    pub is_external: Option<bool>,
-    pub is_usergroup: Option<bool>,
-    pub name: Option<String>,
-    pub prefs: Option<UsergroupPrefs>,
-    pub team_id: Option<String>,
-    pub updated_by: Option<String>,
-    pub user_count: Option<String>,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct UsergroupPrefs {
-    pub channels: Option<Vec<String>>,
-    pub groups: Option<Vec<String>>,
+    pub is_admin: Option<bool>,
+    pub is_owner: Option<bool>,
+    pub teams: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct UserProfile {
+    pub display_name: Option<String>,
     pub email: Option<String>,
     #[serde(deserialize_with = "::optional_struct_or_empty_array")]
     #[serde(default)]
@@ -1008,6 +1579,12 @@ pub struct UserProfile {
     pub last_name: Option<String>,
     pub phone: Option<String>,
     pub skype: Option<String>,
+    pub status_emoji: Option<String>,
+    pub status_expiration: Option<i32>,
+    pub status_text: Option<String>,
+    pub title: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -1015,4 +1592,36 @@ pub struct UserProfileFields {
     pub alt: Option<String>,
     pub label: Option<String>,
     pub value: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Usergroup {
+    pub auto_type: Option<String>,
+    pub created_by: Option<String>,
+    pub date_create: Option<i32>,
+    pub date_delete: Option<i32>,
+    pub date_update: Option<i32>,
+    pub deleted_by: Option<String>,
+    pub description: Option<String>,
+    pub handle: Option<String>,
+    pub id: Option<String>,
+    pub is_external: Option<bool>,
+    pub is_usergroup: Option<bool>,
+    pub name: Option<String>,
+    pub prefs: Option<UsergroupPrefs>,
+    pub team_id: Option<String>,
+    pub updated_by: Option<String>,
+    pub user_count: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UsergroupPrefs {
+    pub channels: Option<Vec<String>>,
+    pub groups: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, ::serde_json::Value>,
 }
@@ -0,0 +1,224 @@
+//! Typed model of [views](https://api.slack.com/surfaces), the modals and App Home tabs shown with
+//! the `views` methods.
+//!
+//! The fields Slack fills in on the views it returns, such as `id`, `hash` and `state`, are never
+//! sent. Pass the `hash` of the view you last saw to `views::update` or `views::publish` so that
+//! Slack rejects the change with `hash_conflict` if the view has changed since.
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_api::blocks::{SectionBlock, TextObject};
+//! use slack_api::view::View;
+//!
+//! let blocks = vec![SectionBlock::new(TextObject::plain("Pick a time")).into()];
+//! let view = View::modal("Book a room", blocks)
+//!     .submit("Book")
+//!     .close("Cancel")
+//!     .callback_id("book_room")
+//!     .private_metadata("C1H9RESGL");
+//! ```
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use blocks::{Block, TextObject};
+
+/// The surface a view is shown on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewType {
+    /// A modal dialog, opened from an interaction.
+    Modal,
+    /// The Home tab of an app.
+    Home,
+}
+
+impl Default for ViewType {
+    fn default() -> Self {
+        ViewType::Modal
+    }
+}
+
+/// A modal or Home tab view.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct View {
+    #[serde(rename = "type")]
+    pub view_type: ViewType,
+    pub blocks: Vec<Block>,
+    /// The title at the top of a modal. Required for modals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<TextObject>,
+    /// The label of the button that submits a modal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<TextObject>,
+    /// The label of the button that closes a modal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<TextObject>,
+    /// A string of up to 3000 characters that is sent back with interactions on the view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    /// An identifier for the view, sent back with interactions on it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    /// Whether closing the modal closes every view in the stack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_on_close: Option<bool>,
+    /// Whether a `view_closed` event is sent when the modal is closed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_close: Option<bool>,
+    /// An identifier, unique within the team, that the view can be updated by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub team_id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub app_id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub bot_id: Option<String>,
+    /// Identifies the version of the view, for detecting concurrent updates.
+    #[serde(default, skip_serializing)]
+    pub hash: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub root_view_id: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub previous_view_id: Option<String>,
+    /// The values entered into the view's input blocks.
+    #[serde(default, skip_serializing)]
+    pub state: Option<ViewState>,
+}
+
+impl View {
+    /// Creates a modal with the given plain text title.
+    pub fn modal<S: Into<String>>(title: S, blocks: Vec<Block>) -> Self {
+        let mut view = View::new(ViewType::Modal, blocks);
+        view.title = Some(TextObject::plain(title));
+        view
+    }
+
+    /// Creates a Home tab view.
+    pub fn home(blocks: Vec<Block>) -> Self {
+        View::new(ViewType::Home, blocks)
+    }
+
+    fn new(view_type: ViewType, blocks: Vec<Block>) -> Self {
+        View {
+            view_type,
+            blocks,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the plain text label of the submit button.
+    pub fn submit<S: Into<String>>(mut self, submit: S) -> Self {
+        self.submit = Some(TextObject::plain(submit));
+        self
+    }
+
+    /// Sets the plain text label of the close button.
+    pub fn close<S: Into<String>>(mut self, close: S) -> Self {
+        self.close = Some(TextObject::plain(close));
+        self
+    }
+
+    pub fn private_metadata<S: Into<String>>(mut self, private_metadata: S) -> Self {
+        self.private_metadata = Some(private_metadata.into());
+        self
+    }
+
+    pub fn callback_id<S: Into<String>>(mut self, callback_id: S) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    pub fn clear_on_close(mut self, clear_on_close: bool) -> Self {
+        self.clear_on_close = Some(clear_on_close);
+        self
+    }
+
+    pub fn notify_on_close(mut self, notify_on_close: bool) -> Self {
+        self.notify_on_close = Some(notify_on_close);
+        self
+    }
+
+    pub fn external_id<S: Into<String>>(mut self, external_id: S) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
+/// The values of a view's input blocks when it was returned or submitted.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ViewState {
+    /// The value of each element, keyed by block ID and then by action ID.
+    #[serde(default)]
+    pub values: HashMap<String, HashMap<String, Value>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use blocks::DividerBlock;
+    use super::*;
+    use mock::MockSender;
+    use views;
+
+    #[test]
+    fn test_view_serializes_only_sent_fields() {
+        let mut view = View::modal("Title", vec![DividerBlock::default().into()]).submit("Go");
+        view.hash = Some("156772938.1827394".into());
+
+        assert_eq!(
+            serde_json::to_value(&view).unwrap(),
+            json!({
+                "type": "modal",
+                "blocks": [{"type": "divider"}],
+                "title": {"type": "plain_text", "text": "Title"},
+                "submit": {"type": "plain_text", "text": "Go"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_returned_view_deserializes() {
+        let view: View = serde_json::from_str(r#"{
+            "id": "VMHU10V25",
+            "type": "home",
+            "blocks": [],
+            "hash": "156772938.1827394",
+            "state": {"values": {"b1": {"a1": {"type": "plain_text_input", "value": "hi"}}}}
+        }"#).unwrap();
+
+        assert_eq!(ViewType::Home, view.view_type);
+        assert_eq!(Some("156772938.1827394"), view.hash.as_ref().map(String::as_ref));
+        let state = view.state.unwrap();
+        assert_eq!("hi", state.values["b1"]["a1"]["value"]);
+    }
+
+    #[test]
+    fn test_views_update_sends_hash() {
+        let client = MockSender::new(r#"{
+            "ok": true,
+            "view": {"id": "VMHU10V25", "type": "modal", "blocks": [], "hash": "156772938.1827394"}
+        }"#).then(r#"{"ok": false, "error": "hash_conflict"}"#);
+
+        let view = View::modal("Title", vec![]).callback_id("edit");
+        let request = views::OpenRequest::builder("12345.98765.abcd2358fdea", view).build();
+        let opened = views::open(&client, "xoxb-token", &request).unwrap().view.unwrap();
+        let sent: serde_json::Value = serde_json::from_str(&client.sent_param("view").unwrap()).unwrap();
+        assert_eq!("edit", sent["callback_id"]);
+
+        let request = views::UpdateRequest::builder(View::modal("Changed", vec![]))
+            .view_id(opened.id.unwrap())
+            .hash(opened.hash.unwrap())
+            .build();
+        match views::update(&client, "xoxb-token", &request) {
+            Err(views::UpdateError::HashConflict) => {}
+            other => panic!("expected HashConflict, got {:?}", other),
+        }
+        assert_eq!(Some("156772938.1827394".to_owned()), client.sent_param("hash"));
+    }
+}
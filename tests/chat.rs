@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate serde_json;
+extern crate slack_api;
+
+mod common;
+
+use std::collections::HashMap;
+use slack_api::attachments::Attachment;
+use slack_api::{chat, chat_scheduled_messages, Url};
+
+use common::MockSender;
+
+#[test]
+fn test_request_builder_is_owned() {
+    let request = chat::PostMessageRequest::builder(String::from("C1"), "hello")
+        .thread_ts(String::from("1.0"))
+        .reply_broadcast(true)
+        .build();
+
+    let request = ::std::thread::spawn(move || request).join().unwrap();
+    assert_eq!("C1", request.channel);
+    assert_eq!("hello", request.text);
+    assert_eq!(Some("1.0"), request.thread_ts.as_ref().map(|ts| &ts[..]));
+    assert_eq!(Some(true), request.reply_broadcast);
+    assert!(request.parse.is_none());
+}
+
+#[test]
+fn test_unfurls_are_keyed_by_url() {
+    let client = MockSender::new(r#"{"ok": true}"#);
+
+    let mut unfurls = HashMap::new();
+    unfurls.insert(
+        Url::parse("https://example.com/issues/1").unwrap(),
+        Attachment::new("Issue #1").title("Issue #1"),
+    );
+    let request = chat::UnfurlRequest::builder("C1", "1.0", unfurls).build();
+    chat::unfurl(&client, "xoxp-token", &request).unwrap();
+
+    let sent: serde_json::Value = serde_json::from_str(&client.sent_param("unfurls").unwrap()).unwrap();
+    assert_eq!(
+        sent,
+        json!({"https://example.com/issues/1": {"fallback": "Issue #1", "title": "Issue #1"}})
+    );
+}
+
+#[test]
+fn test_scheduled_messages() {
+    let client = MockSender::new(r#"{
+        "ok": true,
+        "channel": "C1H9RESGL",
+        "scheduled_message_id": "Q1298393284",
+        "post_at": 1562180400,
+        "message": {"type": "delayed_message", "text": "Here's a message for you in the future", "bot_id": "B19LU7CSY"}
+    }"#).then(r#"{
+        "ok": true,
+        "scheduled_messages": [
+            {"id": "Q1298393284", "channel_id": "C1H9RESGL", "post_at": 1562180400, "date_created": 1562177812, "text": "Here's a message for you in the future"}
+        ],
+        "response_metadata": {"next_cursor": ""}
+    }"#).then(r#"{"ok": false, "error": "invalid_scheduled_message_id"}"#);
+
+    let text = "Here's a message for you in the future";
+    let request = chat::ScheduleMessageRequest::builder("C1H9RESGL", 1562180400, text)
+        .parse(chat::Parse::Full)
+        .build();
+    let response = chat::schedule_message(&client, "xoxp-token", &request).unwrap();
+    assert_eq!(Some("1562180400".to_owned()), client.sent_param("post_at"));
+    assert_eq!(Some("full".to_owned()), client.sent_param("parse"));
+    assert_eq!(Some("Q1298393284"), response.scheduled_message_id.as_ref().map(String::as_ref));
+
+    let request = chat_scheduled_messages::ListRequest::builder()
+        .channel("C1H9RESGL")
+        .build();
+    let response = chat_scheduled_messages::list(&client, "xoxp-token", &request).unwrap();
+    assert!(client.urls.borrow()[1].ends_with("/chat.scheduledMessages.list"));
+    let scheduled = response.scheduled_messages.unwrap();
+    assert_eq!(Some(1562180400), scheduled[0].post_at);
+
+    let request =
+        chat::DeleteScheduledMessageRequest::builder("C1H9RESGL", "Q1298393284").build();
+    match chat::delete_scheduled_message(&client, "xoxp-token", &request) {
+        Err(chat::DeleteScheduledMessageError::InvalidScheduledMessageId) => {}
+        other => panic!("expected InvalidScheduledMessageId, got {:?}", other),
+    }
+}
@@ -0,0 +1,37 @@
+extern crate slack_api;
+
+mod common;
+
+use slack_api::conversations;
+
+use common::MockSender;
+
+#[test]
+fn test_conversations_list() {
+    let client = MockSender::new(r#"{
+        "ok": true,
+        "channels": [
+            {"id": "C1", "name": "general", "is_channel": true, "is_general": true, "num_members": 4},
+            {"id": "D1", "is_im": true, "user": "U1", "is_user_deleted": false}
+        ],
+        "response_metadata": {"next_cursor": "dGVhbTpDMDYxRkE1UEI="}
+    }"#);
+
+    let types = vec![
+        conversations::ConversationType::PublicChannel,
+        conversations::ConversationType::Im,
+    ];
+    let request = conversations::ListRequest::builder()
+        .types(types)
+        .limit(2)
+        .build();
+    let response = conversations::list(&client, "xoxp-token", &request).unwrap();
+    assert_eq!(Some("public_channel,im".to_owned()), client.sent_param("types"));
+    assert_eq!(Some("2".to_owned()), client.sent_param("limit"));
+
+    let channels = response.channels.unwrap();
+    assert_eq!(Some(true), channels[0].is_general);
+    assert_eq!(Some("U1"), channels[1].user.as_ref().map(String::as_ref));
+    let next_cursor = response.response_metadata.unwrap().next_cursor;
+    assert_eq!(Some("dGVhbTpDMDYxRkE1UEI="), next_cursor.as_ref().map(String::as_ref));
+}
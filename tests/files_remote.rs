@@ -0,0 +1,41 @@
+extern crate slack_api;
+
+mod common;
+
+use slack_api::files_remote;
+
+use common::MockSender;
+
+#[test]
+fn test_files_remote() {
+    let client = MockSender::new(r#"{
+        "ok": true,
+        "file": {
+            "id": "F0GDJPMU7",
+            "is_external": true,
+            "external_id": "123456",
+            "external_type": "app",
+            "external_url": "https://example.com/docs/123456",
+            "has_rich_preview": false
+        }
+    }"#).then(r#"{"ok": false, "error": "file_not_found"}"#);
+
+    let url = "https://example.com/docs/123456";
+    let request = files_remote::AddRequest::builder("123456", url, "Quarterly report")
+        .indexable_file_contents("revenue forecast")
+        .build();
+    let file = files_remote::add(&client, "xoxb-token", &request).unwrap().file.unwrap();
+    let contents = client.sent_param("indexable_file_contents");
+    assert_eq!(Some("revenue forecast".to_owned()), contents);
+    assert_eq!(Some("123456"), file.external_id.as_ref().map(String::as_ref));
+    assert_eq!(Some(true), file.is_external);
+
+    let request = files_remote::ShareRequest::builder(vec!["C1", "C2"])
+        .external_id("123456")
+        .build();
+    match files_remote::share(&client, "xoxb-token", &request) {
+        Err(files_remote::ShareError::FileNotFound) => {}
+        other => panic!("expected FileNotFound, got {:?}", other),
+    }
+    assert_eq!(Some("C1,C2".to_owned()), client.sent_param("channels"));
+}
@@ -0,0 +1,44 @@
+extern crate slack_api;
+
+mod common;
+
+use slack_api::{files, mpim, search};
+
+use common::MockSender;
+
+#[test]
+fn test_enum_params_are_sent_as_strings() {
+    let client = MockSender::new(r#"{"ok": true}"#);
+
+    let request = files::ListRequest::builder()
+        .types(&[files::FileType::Images, files::FileType::Pdfs][..])
+        .build();
+    files::list(&client, "xoxp-token", &request).unwrap();
+    assert_eq!(Some("images,pdfs".to_owned()), client.sent_param("types"));
+
+    let request = search::MessagesRequest::builder("deploy")
+        .sort(search::SortBy::Timestamp)
+        .sort_dir(search::SortDirection::Asc)
+        .build();
+    search::messages(&client, "xoxp-token", &request).unwrap();
+    assert_eq!(Some("timestamp".to_owned()), client.sent_param("sort"));
+    assert_eq!(Some("asc".to_owned()), client.sent_param("sort_dir"));
+    assert_eq!(None, client.sent_param("highlight"));
+}
+
+#[test]
+fn test_list_params_are_joined() {
+    let client = MockSender::new(r#"{"ok": true}"#);
+
+    let request = mpim::OpenRequest::builder(vec!["U1", "U2", "U3"]).build();
+    mpim::open(&client, "xoxp-token", &request).unwrap();
+    assert_eq!(Some("U1,U2,U3".to_owned()), client.sent_param("users"));
+
+    let users = (0..9).map(|i| format!("U{}", i));
+    let request = mpim::OpenRequest::builder(users).build();
+    match mpim::open(&client, "xoxp-token", &request) {
+        Err(mpim::OpenError::TooManyUsers) => {}
+        other => panic!("expected too_many_users, got {:?}", other),
+    }
+    assert_eq!(1, client.params.borrow().len());
+}
@@ -0,0 +1,63 @@
+extern crate slack_api;
+
+mod common;
+
+use slack_api::{conversations, users};
+
+use common::MockSender;
+
+#[test]
+fn test_enterprise_grid_users() {
+    let client = MockSender::new(r#"{
+        "ok": true,
+        "members": [{
+            "id": "W1",
+            "team_id": "T1",
+            "enterprise_user": {
+                "id": "W1",
+                "enterprise_id": "E1",
+                "enterprise_name": "Umbrella",
+                "is_admin": false,
+                "is_owner": false,
+                "teams": ["T1", "T2"]
+            }
+        }]
+    }"#);
+
+    let request = users::ListRequest::builder().team_id("T1").build();
+    let response = users::list(&client, "xoxp-token", &request).unwrap();
+    assert_eq!(Some("T1".to_owned()), client.sent_param("team_id"));
+
+    let user = &response.members.unwrap()[0];
+    let enterprise_user = user.enterprise_user.as_ref().unwrap();
+    assert_eq!(Some("E1"), enterprise_user.enterprise_id.as_ref().map(String::as_ref));
+    assert_eq!(Some(2), enterprise_user.teams.as_ref().map(Vec::len));
+}
+
+#[test]
+fn test_users_lookup_by_email_and_conversations() {
+    let client = MockSender::new(r#"{
+        "ok": true,
+        "user": {"id": "W012A3CDE", "name": "spengler", "profile": {"email": "spengler@ghostbusters.example.com"}}
+    }"#).then(r#"{
+        "ok": true,
+        "channels": [{"id": "G0AKFJBEU", "name": "ghostbusters", "is_group": true, "is_private": true}],
+        "response_metadata": {"next_cursor": ""}
+    }"#);
+
+    let email = "spengler@ghostbusters.example.com";
+    let request = users::LookupByEmailRequest::builder(email).build();
+    let user = users::lookup_by_email(&client, "xoxb-token", &request).unwrap().user.unwrap();
+    assert_eq!(Some("W012A3CDE"), user.id.as_ref().map(String::as_ref));
+
+    let request = users::ConversationsRequest::builder()
+        .user("W012A3CDE")
+        .types(vec![conversations::ConversationType::PrivateChannel])
+        .exclude_archived(true)
+        .build();
+    let response = users::conversations(&client, "xoxb-token", &request).unwrap();
+    assert_eq!(Some("private_channel".to_owned()), client.sent_param("types"));
+    assert_eq!(Some("1".to_owned()), client.sent_param("exclude_archived"));
+    let channels = response.channels.unwrap();
+    assert_eq!(Some(true), channels[0].is_private);
+}